@@ -0,0 +1,52 @@
+//! Functions for hashing and verifying signatures using the host's implementations.
+//!
+//! These are much cheaper in both gas and code size than compiling the equivalent algorithms into
+//! the contract.  Gas is charged per byte of input at the rates of the protocol's cost table.
+
+use casperlabs_types::account::PublicKey;
+
+use crate::ext_ffi;
+
+/// The number of bytes in a digest returned by the hashing functions.
+pub const HASH_LENGTH: usize = 32;
+
+/// Returns the 32-byte BLAKE2b digest of `data`.
+pub fn blake2b<T: AsRef<[u8]>>(data: T) -> [u8; HASH_LENGTH] {
+    let data = data.as_ref();
+    let mut ret = [0u8; HASH_LENGTH];
+    unsafe { ext_ffi::blake2b(data.as_ptr(), data.len(), ret.as_mut_ptr()) };
+    ret
+}
+
+/// Returns the SHA-256 digest of `data`.
+pub fn sha256<T: AsRef<[u8]>>(data: T) -> [u8; HASH_LENGTH] {
+    let data = data.as_ref();
+    let mut ret = [0u8; HASH_LENGTH];
+    unsafe { ext_ffi::sha256(data.as_ptr(), data.len(), ret.as_mut_ptr()) };
+    ret
+}
+
+/// Returns the Keccak-256 digest of `data`, as used by Ethereum.
+pub fn keccak256<T: AsRef<[u8]>>(data: T) -> [u8; HASH_LENGTH] {
+    let data = data.as_ref();
+    let mut ret = [0u8; HASH_LENGTH];
+    unsafe { ext_ffi::keccak256(data.as_ptr(), data.len(), ret.as_mut_ptr()) };
+    ret
+}
+
+/// Returns `true` if `signature` is a valid Ed25519 signature of `message` made by the owner of
+/// `public_key`.  A malformed signature is reported as invalid rather than reverting.
+pub fn verify_ed25519(public_key: PublicKey, message: &[u8], signature: &[u8]) -> bool {
+    let public_key = public_key.as_bytes();
+    let ret = unsafe {
+        ext_ffi::verify_ed25519(
+            public_key.as_ptr(),
+            public_key.len(),
+            message.as_ptr(),
+            message.len(),
+            signature.as_ptr(),
+            signature.len(),
+        )
+    };
+    ret == 1
+}
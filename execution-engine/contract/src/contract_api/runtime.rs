@@ -7,15 +7,18 @@ use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use core::mem::MaybeUninit;
 
 use casperlabs_types::{
-    account::PublicKey,
+    account::{PublicKey, PUBLIC_KEY_SERIALIZED_MAX_LENGTH},
     api_error,
-    bytesrepr::{self, FromBytes},
+    bytesrepr::{self, FromBytes, U64_SERIALIZED_LENGTH},
     ApiError, BlockTime, CLTyped, CLValue, ContractRef, Key, Phase, URef,
     BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH,
 };
 
 use crate::{args_parser::ArgsParser, contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
 
+/// The number of bytes in a deploy hash.
+pub const DEPLOY_HASH_LENGTH: usize = 32;
+
 /// Returns the given [`CLValue`] to the host, terminating the currently running module.
 ///
 /// Note this function is only relevant to contracts stored on chain which are invoked via
@@ -66,6 +69,48 @@ pub fn call_contract<A: ArgsParser, T: CLTyped + FromBytes>(c_ptr: ContractRef,
         unsafe { bytes_written.assume_init() }
     };
 
+    deserialize_call_result(bytes_written)
+}
+
+/// Calls the given entry point of a stored contract, passing the given arguments to it.
+///
+/// The arguments must match the parameters the entry point was stored with; otherwise the call
+/// fails before running the contract.  The value is returned as per [`call_contract`].
+pub fn call_entry_point<A: ArgsParser, T: CLTyped + FromBytes>(
+    c_ptr: ContractRef,
+    entry_point: &str,
+    args: A,
+) -> T {
+    let contract_key: Key = c_ptr.into();
+    let (key_ptr, key_size, _bytes1) = contract_api::to_ptr(contract_key);
+    let (entry_point_ptr, entry_point_size, _bytes2) = contract_api::to_ptr(entry_point);
+    let (args_ptr, args_size, _bytes3) = ArgsParser::parse(args)
+        .map(contract_api::to_ptr)
+        .unwrap_or_revert();
+
+    let bytes_written = {
+        let mut bytes_written = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::call_entry_point(
+                key_ptr,
+                key_size,
+                entry_point_ptr,
+                entry_point_size,
+                args_ptr,
+                args_size,
+                bytes_written.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { bytes_written.assume_init() }
+    };
+
+    deserialize_call_result(bytes_written)
+}
+
+/// Reads and deserializes the `bytes_written` bytes a stored contract returned into the host
+/// buffer.
+fn deserialize_call_result<T: CLTyped + FromBytes>(bytes_written: usize) -> T {
     let serialized_result = if bytes_written == 0 {
         // If no bytes were written, the host buffer hasn't been set and hence shouldn't be read.
         vec![]
@@ -134,6 +179,44 @@ pub fn get_arg<T: FromBytes>(i: u32) -> Option<Result<T, bytesrepr::Error>> {
     Some(bytesrepr::deserialize(arg_bytes))
 }
 
+fn get_named_arg_size(name: &str) -> Option<usize> {
+    let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+    let mut arg_size: usize = 0;
+    let ret =
+        unsafe { ext_ffi::get_named_arg_size(name_ptr, name_size, &mut arg_size as *mut usize) };
+    match api_error::result_from(ret) {
+        Ok(_) => Some(arg_size),
+        Err(ApiError::MissingArgument) => None,
+        Err(e) => revert(e),
+    }
+}
+
+/// Returns the argument passed to the host under `name` for the current module invocation.
+///
+/// Named arguments are passed alongside the positional arguments read by [`get_arg`], so a deploy
+/// may use either or both.
+pub fn get_named_arg<T: FromBytes>(name: &str) -> Option<Result<T, bytesrepr::Error>> {
+    let arg_size = get_named_arg_size(name)?;
+    let arg_bytes = if arg_size > 0 {
+        let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+        let res = {
+            let data_non_null_ptr = contract_api::alloc_bytes(arg_size);
+            let ret = unsafe {
+                ext_ffi::get_named_arg(name_ptr, name_size, data_non_null_ptr.as_ptr(), arg_size)
+            };
+            let data =
+                unsafe { Vec::from_raw_parts(data_non_null_ptr.as_ptr(), arg_size, arg_size) };
+            api_error::result_from(ret).map(|_| data)
+        };
+        // Assumed to be safe as `get_named_arg_size` checks the argument already
+        res.unwrap_or_revert()
+    } else {
+        // Avoids allocation with 0 bytes and a call to get_named_arg
+        Vec::new()
+    };
+    Some(bytesrepr::deserialize(arg_bytes))
+}
+
 /// Returns the caller of the current context, i.e. the [`PublicKey`] of the account which made the
 /// deploy request.
 pub fn get_caller() -> PublicKey {
@@ -161,6 +244,58 @@ pub fn get_blocktime() -> BlockTime {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Returns the height of the current block.
+pub fn get_block_height() -> u64 {
+    let dest_non_null_ptr = contract_api::alloc_bytes(U64_SERIALIZED_LENGTH);
+    let bytes = unsafe {
+        ext_ffi::get_block_height(dest_non_null_ptr.as_ptr());
+        Vec::from_raw_parts(
+            dest_non_null_ptr.as_ptr(),
+            U64_SERIALIZED_LENGTH,
+            U64_SERIALIZED_LENGTH,
+        )
+    };
+    bytesrepr::deserialize(bytes).unwrap_or_revert()
+}
+
+/// Returns the [`PublicKey`] of the validator which proposed the current block, or `None` if the
+/// deploy is executed without one, e.g. by a request which doesn't name the proposer.
+pub fn get_proposer() -> Option<PublicKey> {
+    let dest_non_null_ptr = contract_api::alloc_bytes(PUBLIC_KEY_SERIALIZED_MAX_LENGTH);
+    let (ret, bytes) = unsafe {
+        let ret = ext_ffi::get_proposer(dest_non_null_ptr.as_ptr());
+        let bytes = Vec::from_raw_parts(
+            dest_non_null_ptr.as_ptr(),
+            PUBLIC_KEY_SERIALIZED_MAX_LENGTH,
+            PUBLIC_KEY_SERIALIZED_MAX_LENGTH,
+        );
+        (ret, bytes)
+    };
+    match api_error::result_from(ret) {
+        Ok(()) => Some(bytesrepr::deserialize(bytes).unwrap_or_revert()),
+        Err(ApiError::None) => None,
+        Err(error) => revert(error),
+    }
+}
+
+/// Returns the hash of the deploy currently being executed.
+pub fn get_deploy_hash() -> [u8; DEPLOY_HASH_LENGTH] {
+    let mut deploy_hash = [0u8; DEPLOY_HASH_LENGTH];
+    unsafe { ext_ffi::get_deploy_hash(deploy_hash.as_mut_ptr()) };
+    deploy_hash
+}
+
+/// Returns `n` random bytes.
+///
+/// The bytes are deterministic: they are drawn from a stream seeded from the current deploy hash,
+/// [`Phase`] and call depth, so every validator executing the deploy gets the same ones.  They must
+/// not be relied on where the deploy's sender shouldn't be able to predict them.
+pub fn random_bytes(n: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; n];
+    unsafe { ext_ffi::random_bytes(bytes.as_mut_ptr(), n) };
+    bytes
+}
+
 /// Returns the current [`Phase`].
 pub fn get_phase() -> Phase {
     let dest_non_null_ptr = contract_api::alloc_bytes(PHASE_SERIALIZED_LENGTH);
@@ -283,8 +418,20 @@ pub(crate) fn read_host_buffer(size: usize) -> Result<Vec<u8>, ApiError> {
     Ok(dest)
 }
 
-#[cfg(feature = "test-support")]
-/// Prints a debug message
+/// Emits an event with the given `topic` and `payload`.
+///
+/// Events are returned in order with the result of a successful deploy, so that they can be
+/// followed without inspecting global state.  Gas is charged for the size of the event.
+pub fn emit_event(topic: &str, payload: CLValue) {
+    let (topic_ptr, topic_size, _topic_bytes) = contract_api::to_ptr(topic);
+    let (payload_ptr, payload_size, _payload_bytes) = contract_api::to_ptr(payload);
+    unsafe { ext_ffi::emit_event(topic_ptr, topic_size, payload_ptr, payload_size) }
+}
+
+/// Prints a debug message.
+///
+/// The message is returned in the debug log of the deploy's result, unless the node discards
+/// debug logs.
 pub fn print(text: &str) {
     let (text_ptr, text_size, _bytes) = contract_api::to_ptr(text);
     unsafe { ext_ffi::print(text_ptr, text_size) }
@@ -6,7 +6,8 @@ use core::{convert::From, mem::MaybeUninit};
 use casperlabs_types::{
     api_error,
     bytesrepr::{self, FromBytes, ToBytes},
-    AccessRights, ApiError, CLTyped, CLValue, ContractRef, Key, URef, UREF_SERIALIZED_LENGTH,
+    AccessRights, ApiError, CLTyped, CLValue, ContractRef, EntryPoints, Key, URef,
+    UREF_SERIALIZED_LENGTH,
 };
 
 use crate::{
@@ -138,6 +139,48 @@ pub fn store_function_at_hash(name: &str, named_keys: BTreeMap<String, Key>) ->
     ContractRef::Hash(addr)
 }
 
+/// Stores the exported, non-mangled `extern "C"` functions named after `entry_points` as a new
+/// contract under a [`URef`] generated by the host.
+///
+/// The contract can only be called at these entry points, with arguments matching their
+/// parameters; see [`runtime::call_entry_point`].
+pub fn store_contract(entry_points: EntryPoints, named_keys: BTreeMap<String, Key>) -> ContractRef {
+    let (entry_points_ptr, entry_points_size, _bytes1) = contract_api::to_ptr(entry_points);
+    let (keys_ptr, keys_size, _bytes2) = contract_api::to_ptr(named_keys);
+    let mut addr = [0u8; 32];
+    unsafe {
+        ext_ffi::store_contract(
+            entry_points_ptr,
+            entry_points_size,
+            keys_ptr,
+            keys_size,
+            addr.as_mut_ptr(),
+        );
+    }
+    ContractRef::URef(URef::new(addr, AccessRights::READ_ADD_WRITE))
+}
+
+/// Stores the exported, non-mangled `extern "C"` functions named after `entry_points` as a new
+/// contract at an immutable address generated by the host.
+pub fn store_contract_at_hash(
+    entry_points: EntryPoints,
+    named_keys: BTreeMap<String, Key>,
+) -> ContractRef {
+    let (entry_points_ptr, entry_points_size, _bytes1) = contract_api::to_ptr(entry_points);
+    let (keys_ptr, keys_size, _bytes2) = contract_api::to_ptr(named_keys);
+    let mut addr = [0u8; 32];
+    unsafe {
+        ext_ffi::store_contract_at_hash(
+            entry_points_ptr,
+            entry_points_size,
+            keys_ptr,
+            keys_size,
+            addr.as_mut_ptr(),
+        );
+    }
+    ContractRef::Hash(addr)
+}
+
 /// Returns a new unforgeable pointer, where the value is initialized to `init`.
 pub fn new_uref<T: CLTyped + ToBytes>(init: T) -> URef {
     let uref_non_null_ptr = contract_api::alloc_bytes(UREF_SERIALIZED_LENGTH);
@@ -29,9 +29,30 @@ extern "C" {
         named_keys_size: usize,
         hash_ptr: *const u8,
     );
+    pub fn store_contract(
+        entry_points_ptr: *const u8,
+        entry_points_size: usize,
+        named_keys_ptr: *const u8,
+        named_keys_size: usize,
+        uref_addr_ptr: *const u8,
+    );
+    pub fn store_contract_at_hash(
+        entry_points_ptr: *const u8,
+        entry_points_size: usize,
+        named_keys_ptr: *const u8,
+        named_keys_size: usize,
+        hash_ptr: *const u8,
+    );
     pub fn load_named_keys(total_keys: *mut usize, result_size: *mut usize) -> i32;
     pub fn get_arg(index: usize, dest_ptr: *mut u8, dest_size: usize) -> i32;
     pub fn get_arg_size(index: usize, dest_size: *mut usize) -> i32;
+    pub fn get_named_arg(
+        name_ptr: *const u8,
+        name_size: usize,
+        dest_ptr: *mut u8,
+        dest_size: usize,
+    ) -> i32;
+    pub fn get_named_arg_size(name_ptr: *const u8, name_size: usize, dest_size: *mut usize) -> i32;
     pub fn ret(value_ptr: *const u8, value_size: usize) -> !;
     pub fn call_contract(
         key_ptr: *const u8,
@@ -40,6 +61,15 @@ extern "C" {
         args_size: usize,
         result_size: *mut usize,
     ) -> i32;
+    pub fn call_entry_point(
+        key_ptr: *const u8,
+        key_size: usize,
+        entry_point_ptr: *const u8,
+        entry_point_size: usize,
+        args_ptr: *const u8,
+        args_size: usize,
+        result_size: *mut usize,
+    ) -> i32;
     pub fn get_key(
         name_ptr: *const u8,
         name_size: usize,
@@ -66,6 +96,10 @@ extern "C" {
     pub fn set_action_threshold(permission_level: u32, threshold: i32) -> i32;
     pub fn get_caller(output_size: *mut usize) -> i32;
     pub fn get_blocktime(dest_ptr: *const u8);
+    pub fn get_block_height(dest_ptr: *const u8);
+    pub fn get_proposer(dest_ptr: *const u8) -> i32;
+    pub fn get_deploy_hash(dest_ptr: *const u8);
+    pub fn random_bytes(dest_ptr: *mut u8, dest_size: usize);
     pub fn create_purse(purse_ptr: *const u8, purse_size: usize) -> i32;
     pub fn transfer_to_account(
         target_ptr: *const u8,
@@ -104,6 +138,22 @@ extern "C" {
     ) -> i32;
     pub fn get_main_purse(dest_ptr: *mut u8);
     pub fn read_host_buffer(dest_ptr: *mut u8, dest_size: usize, bytes_written: *mut usize) -> i32;
-    #[cfg(feature = "test-support")]
+    pub fn emit_event(
+        topic_ptr: *const u8,
+        topic_size: usize,
+        payload_ptr: *const u8,
+        payload_size: usize,
+    );
+    pub fn blake2b(in_ptr: *const u8, in_size: usize, out_ptr: *mut u8);
+    pub fn sha256(in_ptr: *const u8, in_size: usize, out_ptr: *mut u8);
+    pub fn keccak256(in_ptr: *const u8, in_size: usize, out_ptr: *mut u8);
+    pub fn verify_ed25519(
+        public_key_ptr: *const u8,
+        public_key_size: usize,
+        message_ptr: *const u8,
+        message_size: usize,
+        signature_ptr: *const u8,
+        signature_size: usize,
+    ) -> i32;
     pub fn print(text_ptr: *const u8, text_size: usize);
 }
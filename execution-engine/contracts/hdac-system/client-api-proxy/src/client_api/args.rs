@@ -0,0 +1,35 @@
+use alloc::vec::Vec;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{bytesrepr::FromBytes, ApiError, CLTyped, CLValue};
+
+/// Where an [`Api`](super::Api) reads its positional arguments from.
+pub trait ArgSource {
+    /// Returns the argument at `index`, reverting if it is missing or has an unexpected type.
+    fn get<T: CLTyped + FromBytes>(&self, index: u32) -> T;
+}
+
+/// The arguments the proxy itself was called with.
+pub struct RuntimeArgs;
+
+impl ArgSource for RuntimeArgs {
+    fn get<T: CLTyped + FromBytes>(&self, index: u32) -> T {
+        runtime::get_arg(index)
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .unwrap_or_revert_with(ApiError::InvalidArgument)
+    }
+}
+
+/// The arguments of a single action inside a batch.
+pub struct BatchedArgs(pub Vec<CLValue>);
+
+impl ArgSource for BatchedArgs {
+    fn get<T: CLTyped + FromBytes>(&self, index: u32) -> T {
+        self.0
+            .get(index as usize)
+            .cloned()
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .into_t()
+            .unwrap_or_revert_with(ApiError::InvalidArgument)
+    }
+}
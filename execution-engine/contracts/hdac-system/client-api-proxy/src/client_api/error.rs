@@ -2,7 +2,10 @@ use types::ApiError;
 
 #[repr(u16)]
 pub enum Error {
-    UnknownProxyApi = 1, // 65537
+    UnknownProxyApi = 1,     // 65537
+    NestedBatch = 2,         // 65538
+    DuplicateTransferId = 3, // 65539
+    MemoTooLong = 4,         // 65540
 }
 
 impl From<Error> for ApiError {
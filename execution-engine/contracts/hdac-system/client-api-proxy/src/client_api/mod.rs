@@ -1,14 +1,20 @@
+mod args;
 mod error;
+mod transfer_record;
 
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
 use contract::{
     contract_api::{account, runtime, system},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use types::{account::PublicKey, ApiError, Key, URef, U512};
+use types::{account::PublicKey, bytesrepr, CLValue, Key, URef, U512};
 
+use args::{ArgSource, BatchedArgs, RuntimeArgs};
 use error::Error;
+use transfer_record::TransferRecord;
+
+const MAX_MEMO_LENGTH: usize = 256;
 
 mod method_names {
     pub mod proxy {
@@ -17,6 +23,7 @@ mod method_names {
         pub const UNBOND: &str = pos::UNBOND;
         pub const STANDARD_PAYMENT: &str = "standard_payment";
         pub const TRANSFER_TO_ACCOUNT: &str = "transfer_to_account";
+        pub const TRANSFER_TO_ACCOUNT_WITH_ID: &str = "transfer_to_account_with_id";
         pub const DELEGATE: &str = pos::DELEGATE;
         pub const UNDELEGATE: &str = pos::UNDELEGATE;
         pub const REDELEGATE: &str = pos::REDELEGATE;
@@ -24,6 +31,7 @@ mod method_names {
         pub const UNVOTE: &str = pos::UNVOTE;
         pub const CLAIM_COMMISSION: &str = pos::CLAIM_COMMISSION;
         pub const CLAIM_REWARD: &str = pos::CLAIM_REWARD;
+        pub const BATCH: &str = "batch";
     }
     pub mod pos {
         pub const BOND: &str = "bond";
@@ -44,6 +52,7 @@ pub enum Api {
     Unbond(Option<U512>),
     StandardPayment(U512),
     TransferToAccount(PublicKey, U512),
+    TransferToAccountWithId(PublicKey, U512, u64, Option<String>),
     Delegate(PublicKey, U512),
     Undelegate(PublicKey, Option<U512>),
     Redelegate(PublicKey, PublicKey, Option<U512>),
@@ -51,93 +60,89 @@ pub enum Api {
     Unvote(Key, Option<U512>),
     ClaimCommission(),
     ClaimReward(),
+    Batch(Vec<Api>),
 }
 
 impl Api {
     pub fn from_args() -> Self {
-        let method_name: String = runtime::get_arg(0)
-            .unwrap_or_revert_with(ApiError::MissingArgument)
-            .unwrap_or_revert_with(ApiError::InvalidArgument);
+        Self::parse(&RuntimeArgs)
+    }
+
+    fn parse<A: ArgSource>(args: &A) -> Self {
+        let method_name: String = args.get(0);
 
         match method_name.as_str() {
             method_names::proxy::BOND => {
-                let amount: U512 = runtime::get_arg(1)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let amount: U512 = args.get(1);
                 Api::Bond(amount)
             }
             method_names::proxy::UNBOND => {
-                let amount: Option<U512> = runtime::get_arg(1)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let amount: Option<U512> = args.get(1);
                 Api::Unbond(amount)
             }
             method_names::proxy::STANDARD_PAYMENT => {
-                let amount: U512 = runtime::get_arg(1)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let amount: U512 = args.get(1);
                 Api::StandardPayment(amount)
             }
             method_names::proxy::TRANSFER_TO_ACCOUNT => {
-                let public_key: PublicKey = runtime::get_arg(1)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
-                let transfer_amount: U512 = runtime::get_arg(2)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let public_key: PublicKey = args.get(1);
+                let transfer_amount: U512 = args.get(2);
 
                 Api::TransferToAccount(public_key, transfer_amount)
             }
+            method_names::proxy::TRANSFER_TO_ACCOUNT_WITH_ID => {
+                let public_key: PublicKey = args.get(1);
+                let transfer_amount: U512 = args.get(2);
+                let id: u64 = args.get(3);
+                let memo: Option<String> = args.get(4);
+
+                Api::TransferToAccountWithId(public_key, transfer_amount, id, memo)
+            }
             method_names::proxy::DELEGATE => {
-                let validator: PublicKey = runtime::get_arg(1)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
-                let amount: U512 = runtime::get_arg(2)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let validator: PublicKey = args.get(1);
+                let amount: U512 = args.get(2);
                 Api::Delegate(validator, amount)
             }
             method_names::proxy::UNDELEGATE => {
-                let validator: PublicKey = runtime::get_arg(1)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
-                let amount: Option<U512> = runtime::get_arg(2)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let validator: PublicKey = args.get(1);
+                let amount: Option<U512> = args.get(2);
                 Api::Undelegate(validator, amount)
             }
             method_names::proxy::REDELEGATE => {
-                let src_validator: PublicKey = runtime::get_arg(1)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
-                let dest_validator: PublicKey = runtime::get_arg(2)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
-                let amount: Option<U512> = runtime::get_arg(3)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let src_validator: PublicKey = args.get(1);
+                let dest_validator: PublicKey = args.get(2);
+                let amount: Option<U512> = args.get(3);
                 Api::Redelegate(src_validator, dest_validator, amount)
             }
             method_names::proxy::VOTE => {
-                let dapp: Key = runtime::get_arg(1)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
-                let amount: U512 = runtime::get_arg(2)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let dapp: Key = args.get(1);
+                let amount: U512 = args.get(2);
                 Api::Vote(dapp, amount)
             }
             method_names::proxy::UNVOTE => {
-                let dapp: Key = runtime::get_arg(1)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
-                let amount: Option<U512> = runtime::get_arg(2)
-                    .unwrap_or_revert_with(ApiError::MissingArgument)
-                    .unwrap_or_revert_with(ApiError::InvalidArgument);
+                let dapp: Key = args.get(1);
+                let amount: Option<U512> = args.get(2);
                 Api::Unvote(dapp, amount)
             }
             method_names::proxy::CLAIM_COMMISSION => Api::ClaimCommission(),
             method_names::proxy::CLAIM_REWARD => Api::ClaimReward(),
+            method_names::proxy::BATCH => {
+                // Each action is a serialized `Vec<CLValue>` holding the same arguments the
+                // proxy would take for that action on its own.
+                let actions: Vec<Vec<u8>> = args.get(1);
+                let apis = actions
+                    .into_iter()
+                    .map(|action| {
+                        let action_args: Vec<CLValue> =
+                            bytesrepr::deserialize(action).unwrap_or_revert();
+                        match Self::parse(&BatchedArgs(action_args)) {
+                            Api::Batch(_) => runtime::revert(Error::NestedBatch),
+                            api => api,
+                        }
+                    })
+                    .collect();
+                Api::Batch(apis)
+            }
             _ => runtime::revert(Error::UnknownProxyApi),
         }
     }
@@ -170,6 +175,26 @@ impl Api {
             Self::TransferToAccount(public_key, amount) => {
                 system::transfer_to_account(*public_key, *amount).unwrap_or_revert();
             }
+            Self::TransferToAccountWithId(public_key, amount, id, memo) => {
+                if memo.as_ref().map_or(0, String::len) > MAX_MEMO_LENGTH {
+                    runtime::revert(Error::MemoTooLong);
+                }
+                let source = runtime::get_caller();
+                if transfer_record::read_transfer_record(&source, *id).is_some() {
+                    runtime::revert(Error::DuplicateTransferId);
+                }
+
+                system::transfer_to_account(*public_key, *amount).unwrap_or_revert();
+
+                // Written to local storage so the record shows up in the deploy's effects.
+                transfer_record::write_transfer_record(TransferRecord {
+                    source,
+                    target: *public_key,
+                    amount: *amount,
+                    id: *id,
+                    memo: memo.clone(),
+                });
+            }
             Self::Delegate(validator, amount) => {
                 let pos_ref = system::get_proof_of_stake();
 
@@ -205,6 +230,8 @@ impl Api {
                 let pos_ref = system::get_proof_of_stake();
                 runtime::call_contract(pos_ref, (method_names::pos::CLAIM_REWARD,))
             }
+            // Every action reverts on failure, so a failing action reverts the whole batch.
+            Self::Batch(apis) => apis.iter().for_each(Api::invoke),
         }
     }
 }
@@ -0,0 +1,82 @@
+use alloc::{string::String, vec::Vec};
+use core::result;
+
+use contract::contract_api::storage;
+use types::{
+    account::PublicKey,
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U512,
+};
+
+/// A transfer made through `transfer_to_account_with_id`.
+///
+/// The proxy is called by hash as session code, so records are written to the local storage of the
+/// sending account, under the sender and the transfer id.  Indexers can pick them up from the
+/// effects of the deploy.
+pub struct TransferRecord {
+    pub source: PublicKey,
+    pub target: PublicKey,
+    pub amount: U512,
+    pub id: u64,
+    pub memo: Option<String>,
+}
+
+impl FromBytes for TransferRecord {
+    fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
+        let (source, bytes) = PublicKey::from_bytes(bytes)?;
+        let (target, bytes) = PublicKey::from_bytes(bytes)?;
+        let (amount, bytes) = U512::from_bytes(bytes)?;
+        let (id, bytes) = u64::from_bytes(bytes)?;
+        let (memo, bytes): (Option<String>, &[u8]) = FromBytes::from_bytes(bytes)?;
+        Ok((
+            TransferRecord {
+                source,
+                target,
+                amount,
+                id,
+                memo,
+            },
+            bytes,
+        ))
+    }
+}
+
+impl ToBytes for TransferRecord {
+    fn to_bytes(&self) -> result::Result<Vec<u8>, bytesrepr::Error> {
+        Ok((self.source.to_bytes()?.into_iter())
+            .chain(self.target.to_bytes()?)
+            .chain(self.amount.to_bytes()?)
+            .chain(self.id.to_bytes()?)
+            .chain(self.memo.to_bytes()?)
+            .collect())
+    }
+    fn serialized_length(&self) -> usize {
+        self.source.serialized_length()
+            + self.target.serialized_length()
+            + self.amount.serialized_length()
+            + self.id.serialized_length()
+            + self.memo.serialized_length()
+    }
+}
+
+impl CLTyped for TransferRecord {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+fn transfer_record_key(source: &PublicKey, id: u64) -> Vec<u8> {
+    let mut ret = Vec::with_capacity(source.as_bytes().len() + 8);
+    ret.extend(source.as_bytes());
+    ret.extend(&id.to_le_bytes());
+    ret
+}
+
+pub fn read_transfer_record(source: &PublicKey, id: u64) -> Option<TransferRecord> {
+    storage::read_local(&transfer_record_key(source, id)).unwrap_or_default()
+}
+
+pub fn write_transfer_record(record: TransferRecord) {
+    let key = transfer_record_key(&record.source, record.id);
+    storage::write_local(key, record);
+}
@@ -0,0 +1,16 @@
+use alloc::vec::Vec;
+
+use types::URef;
+
+// Purse balances are stored under the bare 32-byte purse address, so the prefixed keys below
+// never collide with them.
+pub const PREFIX_VESTING_SCHEDULE: u8 = 1;
+pub const PREFIX_BONDING_PURSE: u8 = 2;
+
+pub fn prefixed_key(prefix: u8, purse: &URef) -> Vec<u8> {
+    let addr = purse.addr();
+    let mut ret = Vec::with_capacity(1 + addr.len());
+    ret.push(prefix);
+    ret.extend(&addr);
+    ret
+}
@@ -2,6 +2,9 @@
 
 extern crate alloc;
 
+mod keys;
+mod vesting;
+
 use alloc::string::String;
 
 use contract::{
@@ -13,13 +16,19 @@ use types::{
     account::PublicKey,
     bytesrepr::{FromBytes, ToBytes},
     system_contract_errors::mint::Error,
-    ApiError, CLTyped, CLValue, Key, URef, U512,
+    ApiError, BlockTime, CLTyped, CLValue, Key, URef, U512,
 };
 
+use vesting::VestingSchedule;
+
 const METHOD_MINT: &str = "mint";
+const METHOD_MINT_VESTING: &str = "mint_vesting";
 const METHOD_CREATE: &str = "create";
 const METHOD_BALANCE: &str = "balance";
 const METHOD_TRANSFER: &str = "transfer";
+const METHOD_REGISTER_BONDING_PURSE: &str = "register_bonding_purse";
+
+const SYSTEM_ACCOUNT: PublicKey = PublicKey::ed25519_from([0; 32]);
 
 pub struct MintContract;
 
@@ -66,6 +75,43 @@ impl StorageProvider for MintContract {
 
 impl Mint for MintContract {}
 
+impl MintContract {
+    fn mint_vesting(
+        &mut self,
+        initial_balance: U512,
+        schedule: VestingSchedule,
+    ) -> Result<URef, Error> {
+        if self.get_caller() != SYSTEM_ACCOUNT {
+            return Err(Error::InvalidAccessRights);
+        }
+        let purse = self.mint(initial_balance)?;
+        vesting::write_vesting_schedule(&purse, schedule);
+        Ok(purse)
+    }
+
+    fn register_bonding_purse(&mut self, purse: URef) -> Result<(), Error> {
+        if self.get_caller() != SYSTEM_ACCOUNT {
+            return Err(Error::InvalidAccessRights);
+        }
+        vesting::write_bonding_purse(&purse);
+        Ok(())
+    }
+
+    // Funds still locked by the source's vesting schedule can only move into a bonding purse.
+    fn transfer_vested(&mut self, source: URef, target: URef, amount: U512) -> Result<(), Error> {
+        if !vesting::is_bonding_purse(&target) {
+            if let Some(schedule) = vesting::read_vesting_schedule(&source) {
+                let locked_amount = schedule.locked_amount_at(runtime::get_blocktime());
+                let balance = self.balance(source)?.unwrap_or_default();
+                if amount > balance.saturating_sub(locked_amount) {
+                    return Err(Error::VestingLocked);
+                }
+            }
+        }
+        self.transfer(source, target, amount)
+    }
+}
+
 pub fn delegate() {
     let mut mint_contract = MintContract;
 
@@ -83,6 +129,30 @@ pub fn delegate() {
             let ret = CLValue::from_t(result).unwrap_or_revert();
             runtime::ret(ret)
         }
+        // Type: `fn mint_vesting(amount: U512, locked_amount: U512, cliff_timestamp: u64,
+        // end_timestamp: u64) -> Result<URef, Error>`
+        METHOD_MINT_VESTING => {
+            let amount: U512 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let locked_amount: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let cliff_timestamp: u64 = runtime::get_arg(3)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let end_timestamp: u64 = runtime::get_arg(4)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let schedule = VestingSchedule {
+                locked_amount,
+                cliff_timestamp: BlockTime::new(cliff_timestamp),
+                end_timestamp: BlockTime::new(end_timestamp),
+            };
+            let result: Result<URef, Error> = mint_contract.mint_vesting(amount, schedule);
+            let ret = CLValue::from_t(result).unwrap_or_revert();
+            runtime::ret(ret)
+        }
         // Type: `fn create() -> URef`
         METHOD_CREATE => {
             let uref = mint_contract.mint(U512::zero()).unwrap_or_revert();
@@ -109,7 +179,16 @@ pub fn delegate() {
             let amount: U512 = runtime::get_arg(3)
                 .unwrap_or_revert_with(ApiError::MissingArgument)
                 .unwrap_or_revert_with(ApiError::InvalidArgument);
-            let result: Result<(), Error> = mint_contract.transfer(source, target, amount);
+            let result: Result<(), Error> = mint_contract.transfer_vested(source, target, amount);
+            let ret = CLValue::from_t(result).unwrap_or_revert();
+            runtime::ret(ret);
+        }
+        // Type: `fn register_bonding_purse(purse: URef) -> Result<(), Error>`
+        METHOD_REGISTER_BONDING_PURSE => {
+            let purse: URef = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let result: Result<(), Error> = mint_contract.register_bonding_purse(purse);
             let ret = CLValue::from_t(result).unwrap_or_revert();
             runtime::ret(ret);
         }
@@ -0,0 +1,95 @@
+use alloc::vec::Vec;
+use core::result;
+
+use contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    BlockTime, CLType, CLTyped, URef, U512,
+};
+
+use crate::keys::{self, PREFIX_BONDING_PURSE, PREFIX_VESTING_SCHEDULE};
+
+/// Locks `locked_amount` of a purse until `cliff_timestamp`, then releases it linearly until
+/// `end_timestamp`.
+#[derive(Clone, Copy)]
+pub struct VestingSchedule {
+    pub locked_amount: U512,
+    pub cliff_timestamp: BlockTime,
+    pub end_timestamp: BlockTime,
+}
+
+impl VestingSchedule {
+    /// Returns the amount which is still locked at `now`.
+    pub fn locked_amount_at(&self, now: BlockTime) -> U512 {
+        let now: u64 = now.into();
+        let cliff: u64 = self.cliff_timestamp.into();
+        let end: u64 = self.end_timestamp.into();
+
+        if now < cliff {
+            self.locked_amount
+        } else if now >= end {
+            U512::zero()
+        } else {
+            self.locked_amount * U512::from(end - now) / U512::from(end - cliff)
+        }
+    }
+}
+
+impl FromBytes for VestingSchedule {
+    fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
+        let (locked_amount, bytes) = U512::from_bytes(bytes)?;
+        let (cliff_timestamp, bytes) = BlockTime::from_bytes(bytes)?;
+        let (end_timestamp, bytes) = BlockTime::from_bytes(bytes)?;
+        Ok((
+            VestingSchedule {
+                locked_amount,
+                cliff_timestamp,
+                end_timestamp,
+            },
+            bytes,
+        ))
+    }
+}
+
+impl ToBytes for VestingSchedule {
+    fn to_bytes(&self) -> result::Result<Vec<u8>, bytesrepr::Error> {
+        Ok((self.locked_amount.to_bytes()?.into_iter())
+            .chain(self.cliff_timestamp.to_bytes()?)
+            .chain(self.end_timestamp.to_bytes()?)
+            .collect())
+    }
+    fn serialized_length(&self) -> usize {
+        self.locked_amount.serialized_length()
+            + self.cliff_timestamp.serialized_length()
+            + self.end_timestamp.serialized_length()
+    }
+}
+
+impl CLTyped for VestingSchedule {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+pub fn read_vesting_schedule(purse: &URef) -> Option<VestingSchedule> {
+    let key = keys::prefixed_key(PREFIX_VESTING_SCHEDULE, purse);
+    storage::read_local(&key).unwrap_or_revert()
+}
+
+pub fn write_vesting_schedule(purse: &URef, schedule: VestingSchedule) {
+    let key = keys::prefixed_key(PREFIX_VESTING_SCHEDULE, purse);
+    storage::write_local(key, schedule);
+}
+
+/// Bonding purses may receive funds which are still locked by a vesting schedule.
+pub fn is_bonding_purse(purse: &URef) -> bool {
+    let key = keys::prefixed_key(PREFIX_BONDING_PURSE, purse);
+    storage::read_local(&key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+pub fn write_bonding_purse(purse: &URef) {
+    let key = keys::prefixed_key(PREFIX_BONDING_PURSE, purse);
+    storage::write_local(key, true);
+}
@@ -86,6 +86,8 @@ fn build_pop_named_keys(
     let mut named_keys = BTreeMap::<String, Key>::default();
 
     let bonding_purse = mint_purse(&mint, total_bonds);
+    // funds still locked by a genesis vesting schedule may only leave a purse to be bonded
+    register_bonding_purse(&mint, bonding_purse);
     let payment_purse = mint_purse(&mint, U512::zero());
     let premint_purse = mint_purse(&mint, premint_amount);
     let rewards_purse = mint_purse(&mint, U512::zero());
@@ -112,3 +114,10 @@ fn mint_purse(mint: &ContractRef, amount: U512) -> URef {
 
     result.unwrap_or_revert()
 }
+
+fn register_bonding_purse(mint: &ContractRef, purse: URef) {
+    let result: Result<(), mint::Error> =
+        runtime::call_contract(mint.clone(), ("register_bonding_purse", purse));
+
+    result.unwrap_or_revert()
+}
@@ -9,7 +9,8 @@ use types::{
     U512,
 };
 
-use crate::{constants::sys_params, store};
+/// The number of validators elected from the delegations.
+pub const MAX_VALIDATORS: usize = 100;
 
 pub struct Delegations {
     table: BTreeMap<DelegationKey, U512>,
@@ -60,7 +61,7 @@ impl Delegations {
 
         // sort by descending order and truncate
         validators.sort_by(|a, b| b.1.cmp(&a.1));
-        validators.truncate(sys_params::MAX_VALIDATORS);
+        validators.truncate(MAX_VALIDATORS);
 
         validators
     }
@@ -100,11 +101,13 @@ impl Delegations {
             .fold(U512::zero(), |acc, x| acc + *x)
     }
 
+    /// Delegates `amount` of the `bonding_amount` bonded by `delegator` to `validator`.
     pub fn delegate(
         &mut self,
         delegator: &PublicKey,
         validator: &PublicKey,
         amount: U512,
+        bonding_amount: U512,
     ) -> Result<()> {
         let key = DelegationKey {
             delegator: *delegator,
@@ -116,12 +119,9 @@ impl Delegations {
         }
 
         // validate amount
-        {
-            let bonding_amount = store::read_bonding_amount(delegator);
-            let delegating_amount = self.delegating_amount(delegator);
-            if amount > bonding_amount.saturating_sub(delegating_amount) {
-                return Err(Error::DelegateTooLarge);
-            }
+        let delegating_amount = self.delegating_amount(delegator);
+        if amount > bonding_amount.saturating_sub(delegating_amount) {
+            return Err(Error::DelegateTooLarge);
         }
 
         // update table
@@ -221,14 +221,57 @@ impl Delegations {
     }
 }
 
+/// Parses a `d_{delegator}_{validator}_{balance}` named key written by the PoP contract.
+///
+/// Returns `None` if `name` isn't a delegation entry.
+pub fn parse_delegation(name: &str) -> Result<Option<(DelegationKey, U512)>> {
+    let mut split_name = name.split('_');
+    if Some("d") != split_name.next() {
+        return Ok(None);
+    }
+
+    let to_publickey = |hex_str: &str| -> Result<PublicKey> {
+        if hex_str.len() != 64 {
+            return Err(Error::DelegationsKeyDeserializationFailed);
+        }
+        let mut key_bytes = [0u8; 32];
+        let _bytes_written = base16::decode_slice(hex_str, &mut key_bytes)
+            .map_err(|_| Error::DelegationsKeyDeserializationFailed)?;
+        debug_assert!(_bytes_written == key_bytes.len());
+        Ok(PublicKey::ed25519_from(key_bytes))
+    };
+
+    let hex_key = split_name
+        .next()
+        .ok_or(Error::DelegationsKeyDeserializationFailed)?;
+    let delegator = to_publickey(hex_key)?;
+
+    let hex_key = split_name
+        .next()
+        .ok_or(Error::DelegationsKeyDeserializationFailed)?;
+    let validator = to_publickey(hex_key)?;
+
+    let balance = split_name
+        .next()
+        .and_then(|b| U512::from_dec_str(b).ok())
+        .ok_or(Error::DelegationsDeserializationFailed)?;
+
+    Ok(Some((
+        DelegationKey {
+            delegator,
+            validator,
+        },
+        balance,
+    )))
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::collections::BTreeMap;
 
     use types::{account::PublicKey, U512};
 
-    use super::{DelegationKey, Delegations};
-    use crate::constants::sys_params::MAX_VALIDATORS;
+    use super::{DelegationKey, Delegations, MAX_VALIDATORS};
 
     #[test]
     fn test_validators() {
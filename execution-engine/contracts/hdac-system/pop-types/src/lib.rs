@@ -0,0 +1,16 @@
+//! Types and storage layout of the proof of profession contract.
+//!
+//! Shared by the `pop` contract, which writes this state, and by the engine, which decodes it.
+
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
+
+mod delegations;
+mod duration_queue;
+pub mod keys;
+mod requests;
+
+pub use delegations::{parse_delegation, DelegationKey, Delegations, MAX_VALIDATORS};
+pub use duration_queue::{DurationQueue, DurationQueueEntry, DurationQueueItem};
+pub use requests::{RedelegateRequest, UnbondRequest, UndelegateRequest};
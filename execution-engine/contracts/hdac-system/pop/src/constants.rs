@@ -3,6 +3,7 @@ pub(crate) mod uref_names {
     pub const POS_REWARD_PURSE: &str = "pos_rewards_purse";
     pub const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
     pub const POS_PREMINT_PURSE: &str = "pos_premint_purse";
+    pub const POS_REFUND_PURSE: &str = "pos_refund_purse";
     pub const _POS_COMMUNITY_PURSE: &str = "pos_community_purse";
 }
 
@@ -12,6 +13,8 @@ pub(crate) mod methods {
     pub const METHOD_UNBOND: &str = "unbond";
     pub const METHOD_STEP: &str = "step";
     pub const METHOD_GET_PAYMENT_PURSE: &str = "get_payment_purse";
+    pub const METHOD_SET_REFUND_PURSE: &str = "set_refund_purse";
+    pub const METHOD_GET_REFUND_PURSE: &str = "get_refund_purse";
     pub const METHOD_FINALIZE_PAYMENT: &str = "finalize_payment";
 
     pub const METHOD_DELEGATE: &str = "delegate";
@@ -21,11 +24,27 @@ pub(crate) mod methods {
     pub const METHOD_UNVOTE: &str = "unvote";
     pub const METHOD_CLAIM_COMMISSION: &str = "claim_commission";
     pub const METHOD_CLAIM_REWARD: &str = "claim_reward";
+
+    pub const METHOD_GET_DELEGATIONS: &str = "get_delegations";
+    pub const METHOD_GET_VALIDATOR: &str = "get_validator";
+    pub const METHOD_GET_PENDING_REQUESTS: &str = "get_pending_requests";
+    pub const METHOD_GET_REWARD: &str = "get_reward";
+}
+
+/// Topics of the events emitted by the contract.
+pub(crate) mod events {
+    /// Payload: `(delegator: PublicKey, validator: PublicKey, amount: U512)`
+    pub const EVENT_DELEGATE: &str = "delegate";
+    /// Payload: `(delegator: PublicKey, validator: PublicKey, amount: Option<U512>)`
+    pub const EVENT_UNDELEGATE: &str = "undelegate";
+    /// Payload: `(validator: PublicKey, amount: U512)`
+    pub const EVENT_CLAIM_COMMISSION: &str = "claim_commission";
+    /// Payload: `(user: PublicKey, amount: U512)`
+    pub const EVENT_CLAIM_REWARD: &str = "claim_reward";
 }
 
 pub(crate) mod sys_params {
     pub const SYSTEM_ACCOUNT: [u8; 32] = [0u8; 32];
-    pub const MAX_VALIDATORS: usize = 100;
 
     #[cfg(test)]
     pub const UNBONDING_DELAY_IN_SEC: u64 = 0;
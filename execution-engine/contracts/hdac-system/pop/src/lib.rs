@@ -3,7 +3,6 @@
 extern crate alloc;
 
 mod constants;
-mod duration_queue;
 mod math;
 mod pop_contract;
 mod store;
@@ -69,7 +68,22 @@ pub fn delegate() {
             let return_value = CLValue::from_t(rights_controlled_purse).unwrap_or_revert();
             runtime::ret(return_value);
         }
-        // Type of this method: `fn finalize_payment()`
+        // Type of this method: `fn set_refund_purse(purse: URef)`
+        methods::METHOD_SET_REFUND_PURSE => {
+            let refund_purse: URef = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            pop_contract
+                .set_refund_purse(refund_purse)
+                .unwrap_or_revert();
+        }
+        // Type of this method: `fn get_refund_purse() -> Option<URef>`
+        methods::METHOD_GET_REFUND_PURSE => {
+            let maybe_refund_purse = pop_contract.get_refund_purse().unwrap_or_revert();
+            let return_value = CLValue::from_t(maybe_refund_purse).unwrap_or_revert();
+            runtime::ret(return_value);
+        }
+        // Type of this method: `fn finalize_payment(amount_spent: U512, account: PublicKey)`
         methods::METHOD_FINALIZE_PAYMENT => {
             let amount_spent: U512 = runtime::get_arg(1)
                 .unwrap_or_revert_with(ApiError::MissingArgument)
@@ -153,6 +167,40 @@ pub fn delegate() {
             let user: PublicKey = runtime::get_caller();
             pop_contract.claim_reward(&user).unwrap_or_revert();
         }
+        // Type of this method: `fn get_delegations(delegator: PublicKey) -> BTreeMap<PublicKey,
+        // U512>`
+        methods::METHOD_GET_DELEGATIONS => {
+            let delegator: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let delegations = pop_contract.get_delegations(&delegator).unwrap_or_revert();
+            runtime::ret(CLValue::from_t(delegations).unwrap_or_revert());
+        }
+        // Type of this method: `fn get_validator(validator: PublicKey) -> (U512, U512, U512)`
+        methods::METHOD_GET_VALIDATOR => {
+            let validator: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let validator_info = pop_contract.get_validator(&validator).unwrap_or_revert();
+            runtime::ret(CLValue::from_t(validator_info).unwrap_or_revert());
+        }
+        // Type of this method: `fn get_pending_requests(account: PublicKey) ->
+        // (Vec<PendingUnbond>, Vec<PendingUndelegation>, Vec<PendingRedelegation>)`
+        methods::METHOD_GET_PENDING_REQUESTS => {
+            let account: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let requests = pop_contract.get_pending_requests(&account);
+            runtime::ret(CLValue::from_t(requests).unwrap_or_revert());
+        }
+        // Type of this method: `fn get_reward(account: PublicKey) -> U512`
+        methods::METHOD_GET_REWARD => {
+            let account: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let reward = pop_contract.get_reward(&account);
+            runtime::ret(CLValue::from_t(reward).unwrap_or_revert());
+        }
         _ => {}
     }
 }
@@ -1,22 +1,26 @@
 mod economy;
 mod pop_actions;
 mod pop_actions_impl;
+mod query;
 
 pub use pop_actions::{Delegatable, Stakable, Votable};
-pub use pop_actions_impl::{DelegationKey, Delegations};
 
 use alloc::collections::BTreeMap;
-use contract::contract_api::{runtime, system};
-
+use contract::{
+    contract_api::{runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use pop_types::{DelegationKey, Delegations, RedelegateRequest, UnbondRequest, UndelegateRequest};
 use types::{
     account::PublicKey,
+    bytesrepr::ToBytes,
     system_contract_errors::pos::{Error, PurseLookupError, Result},
-    AccessRights, BlockTime, Key, URef, U512,
+    AccessRights, BlockTime, CLTyped, CLValue, Key, Phase, URef, U512,
 };
 
 use crate::{
-    constants::{sys_params, uref_names},
-    store::{self, RedelegateRequest, UnbondRequest, UndelegateRequest},
+    constants::{events, sys_params, uref_names},
+    store,
 };
 
 use economy::pop_score_calculation;
@@ -52,7 +56,12 @@ impl ProofOfProfessionContract {
         for (validator, amount) in &genesis_validators {
             // bond and write self-delegation
             stake::bond(validator, *amount);
-            delegations.delegate(&validator, &validator, *amount)?;
+            delegations.delegate(
+                &validator,
+                &validator,
+                *amount,
+                store::read_bonding_amount(validator),
+            )?;
         }
 
         store::write_delegations(&delegations);
@@ -81,6 +90,7 @@ impl ProofOfProfessionContract {
 
         // TODO: separate to another function
         let _ = self.distribute(&delegations);
+        store::write_last_distributed_block(runtime::get_block_height());
         //let _ = self.step_claim();
 
         Ok(())
@@ -95,6 +105,10 @@ impl ProofOfProfessionContract {
         system::transfer_from_purse_to_account(premint_purse, *validator, commission_amount)
             .map_err(|_| Error::FailedTransferFromPremintPurse)?;
         store::write_commission_amount(validator, U512::zero());
+        emit_event(
+            events::EVENT_CLAIM_COMMISSION,
+            (*validator, commission_amount),
+        );
         Ok(())
     }
 
@@ -107,6 +121,7 @@ impl ProofOfProfessionContract {
         system::transfer_from_purse_to_account(premint_purse, *user, reward_amount)
             .map_err(|_| Error::FailedTransferFromPremintPurse)?;
         store::write_reward_amount(user, U512::zero());
+        emit_event(events::EVENT_CLAIM_REWARD, (*user, reward_amount));
         Ok(())
     }
 
@@ -116,7 +131,29 @@ impl ProofOfProfessionContract {
         Ok(URef::new(purse.addr(), AccessRights::READ_ADD))
     }
 
-    pub fn finalize_payment(&mut self, amount_spent: U512, _account: PublicKey) -> Result<()> {
+    /// Sets the purse the unspent part of the payment is refunded to. It can only be set during
+    /// the payment phase; if it is not set, the refund goes to the main purse of the deploying
+    /// account.
+    pub fn set_refund_purse(&mut self, purse: URef) -> Result<()> {
+        if runtime::get_phase() != Phase::Payment {
+            return Err(Error::SetRefundPurseCalledOutsidePayment);
+        }
+        runtime::put_key(uref_names::POS_REFUND_PURSE, Key::URef(purse));
+        Ok(())
+    }
+
+    pub fn get_refund_purse(&self) -> Result<Option<URef>> {
+        match get_purse(uref_names::POS_REFUND_PURSE) {
+            Ok(uref) => Ok(Some(uref)),
+            Err(PurseLookupError::KeyNotFound) => Ok(None),
+            Err(PurseLookupError::KeyUnexpectedType) => Err(Error::RefundPurseKeyUnexpectedType),
+        }
+    }
+
+    /// Pays `amount_spent` from the payment purse to the rewards purse and refunds the rest of it
+    /// to the refund purse, or to `account` if no refund purse was set. The payment purse is
+    /// empty and the refund purse is unset at the end of each deploy.
+    pub fn finalize_payment(&mut self, amount_spent: U512, account: PublicKey) -> Result<()> {
         let caller = runtime::get_caller();
         if caller.value() != sys_params::SYSTEM_ACCOUNT {
             return Err(Error::SystemFunctionCalledByUserAccount);
@@ -124,21 +161,45 @@ impl ProofOfProfessionContract {
 
         let payment_purse =
             get_purse(uref_names::POS_PAYMENT_PURSE).map_err(PurseLookupError::payment)?;
-        let reward_amount = match system::get_balance(payment_purse) {
+        let total = match system::get_balance(payment_purse) {
             Some(balance) => balance,
             None => return Err(Error::PaymentPurseBalanceNotFound),
         };
-        if reward_amount < amount_spent {
+        if total < amount_spent {
             return Err(Error::InsufficientPaymentForAmountSpent);
         }
+        let refund_amount = total - amount_spent;
 
         // In the fare system, the fee is taken by the validator.
         let reward_purse =
             get_purse(uref_names::POS_REWARD_PURSE).map_err(PurseLookupError::rewards)?;
+        let refund_purse = self.get_refund_purse()?;
+        // Unset refund purse after reading it. The key is only removed when it was set, so that
+        // deploys which did not set it do not write to this contract.
+        if refund_purse.is_some() {
+            runtime::remove_key(uref_names::POS_REFUND_PURSE);
+        }
 
-        system::transfer_from_purse_to_purse(payment_purse, reward_purse, reward_amount)
+        system::transfer_from_purse_to_purse(payment_purse, reward_purse, amount_spent)
             .map_err(|_| Error::FailedTransferToRewardsPurse)?;
 
+        if refund_amount.is_zero() {
+            return Ok(());
+        }
+
+        // in case of failure to transfer to refund purse we fall back on the account's main purse
+        let refunded = match refund_purse {
+            Some(refund_purse) => {
+                system::transfer_from_purse_to_purse(payment_purse, refund_purse, refund_amount)
+                    .is_ok()
+            }
+            None => false,
+        };
+        if !refunded {
+            system::transfer_from_purse_to_account(payment_purse, account, refund_amount)
+                .map_err(|_| Error::FailedTransferToAccountPurse)?;
+        }
+
         Ok(())
     }
 
@@ -326,3 +387,9 @@ fn get_purse(name: &str) -> core::result::Result<URef, PurseLookupError> {
             _ => Err(PurseLookupError::KeyUnexpectedType),
         })
 }
+
+/// Emits an event under `topic` so that indexers can follow staking without reading global state.
+fn emit_event<T: CLTyped + ToBytes>(topic: &str, payload: T) {
+    let payload = CLValue::from_t(payload).unwrap_or_revert();
+    runtime::emit_event(topic, payload);
+}
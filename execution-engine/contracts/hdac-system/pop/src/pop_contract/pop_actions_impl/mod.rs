@@ -1,10 +1,8 @@
-mod delegation;
 pub mod stake;
 mod vote;
 
-pub use delegation::{DelegationKey, Delegations};
-
 use contract::contract_api::{runtime, system};
+use pop_types::{RedelegateRequest, UnbondRequest, UndelegateRequest};
 use types::{
     account::PublicKey,
     system_contract_errors::pos::{Error, PurseLookupError, Result},
@@ -12,12 +10,13 @@ use types::{
 };
 
 use super::{
+    emit_event,
     pop_actions::{Delegatable, ProofOfProfession, Stakable, Votable},
     ProofOfProfessionContract,
 };
 use crate::{
-    constants::uref_names,
-    store::{self, RedelegateRequest, UnbondRequest, UndelegateRequest},
+    constants::{events, uref_names},
+    store,
 };
 
 impl ProofOfProfession for ProofOfProfessionContract {}
@@ -71,8 +70,10 @@ impl Stakable for ProofOfProfessionContract {
 impl Delegatable for ProofOfProfessionContract {
     fn delegate(&mut self, delegator: PublicKey, validator: PublicKey, amount: U512) -> Result<()> {
         let mut delegations = store::read_delegations()?;
-        delegations.delegate(&delegator, &validator, amount)?;
+        let bonding_amount = store::read_bonding_amount(&delegator);
+        delegations.delegate(&delegator, &validator, amount, bonding_amount)?;
         store::write_delegations(&delegations);
+        emit_event(events::EVENT_DELEGATE, (delegator, validator, amount));
         Ok(())
     }
 
@@ -104,6 +105,10 @@ impl Delegatable for ProofOfProfessionContract {
             runtime::get_blocktime(),
         )?;
         store::write_undelegation_requests(queue);
+        emit_event(
+            events::EVENT_UNDELEGATE,
+            (delegator, validator, maybe_amount),
+        );
 
         Ok(())
     }
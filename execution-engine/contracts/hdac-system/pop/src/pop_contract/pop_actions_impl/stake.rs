@@ -1,10 +1,10 @@
+use pop_types::Delegations;
 use types::{
     account::PublicKey,
     system_contract_errors::pos::{Error, Result},
     U512,
 };
 
-use super::Delegations;
 use crate::store;
 
 pub fn bond(user: &PublicKey, amount: U512) {
@@ -0,0 +1,85 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use types::{account::PublicKey, system_contract_errors::pos::Result, U512};
+
+use super::ProofOfProfessionContract;
+use crate::store;
+
+/// `(maybe_amount, timestamp)`
+pub type PendingUnbond = (Option<U512>, u64);
+/// `(validator, maybe_amount, timestamp)`
+pub type PendingUndelegation = (PublicKey, Option<U512>, u64);
+/// `((src_validator, dest_validator), maybe_amount, timestamp)`
+pub type PendingRedelegation = ((PublicKey, PublicKey), Option<U512>, u64);
+
+// Read-only views over the PoP state. They return plain CLTyped values so that clients don't
+// depend on the local key layout in `store::local::keys`.
+impl ProofOfProfessionContract {
+    /// Returns the delegated amount of `delegator` for each validator.
+    pub fn get_delegations(&self, delegator: &PublicKey) -> Result<BTreeMap<PublicKey, U512>> {
+        let delegations = store::read_delegations()?;
+        Ok(delegations
+            .iter()
+            .filter(|(key, _)| key.delegator == *delegator)
+            .map(|(key, amount)| (key.validator, *amount))
+            .collect())
+    }
+
+    /// Returns `(bonded_amount, delegated_amount, commission_amount)` of `validator`.
+    pub fn get_validator(&self, validator: &PublicKey) -> Result<(U512, U512, U512)> {
+        let delegations = store::read_delegations()?;
+        Ok((
+            store::read_bonding_amount(validator),
+            delegations.delegated_amount(validator),
+            store::read_commission_amount(validator),
+        ))
+    }
+
+    /// Returns the unbond, undelegate and redelegate requests of `account` which are not
+    /// processed yet.
+    pub fn get_pending_requests(
+        &self,
+        account: &PublicKey,
+    ) -> (
+        Vec<PendingUnbond>,
+        Vec<PendingUndelegation>,
+        Vec<PendingRedelegation>,
+    ) {
+        let unbonds = store::read_unbond_requests()
+            .0
+            .into_iter()
+            .filter(|entry| entry.item.requester == *account)
+            .map(|entry| (entry.item.maybe_amount, entry.timestamp.into()))
+            .collect();
+        let undelegations = store::read_undelegation_requests()
+            .0
+            .into_iter()
+            .filter(|entry| entry.item.delegator == *account)
+            .map(|entry| {
+                (
+                    entry.item.validator,
+                    entry.item.maybe_amount,
+                    entry.timestamp.into(),
+                )
+            })
+            .collect();
+        let redelegations = store::read_redelegation_requests()
+            .0
+            .into_iter()
+            .filter(|entry| entry.item.delegator == *account)
+            .map(|entry| {
+                (
+                    (entry.item.src_validator, entry.item.dest_validator),
+                    entry.item.maybe_amount,
+                    entry.timestamp.into(),
+                )
+            })
+            .collect();
+        (unbonds, undelegations, redelegations)
+    }
+
+    /// Returns the reward `account` can claim.
+    pub fn get_reward(&self, account: &PublicKey) -> U512 {
+        store::read_reward_amount(account)
+    }
+}
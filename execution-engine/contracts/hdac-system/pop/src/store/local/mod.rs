@@ -1,12 +1,7 @@
-mod keys;
-
 use contract::contract_api::storage;
+use pop_types::{keys, DurationQueue, RedelegateRequest, UnbondRequest, UndelegateRequest};
 use types::{account::PublicKey, Key, U512};
 
-use super::requests::{RedelegateRequest, UnbondRequest, UndelegateRequest};
-
-use crate::duration_queue::DurationQueue;
-
 pub fn read_total_mint_supply() -> U512 {
     storage::read_local(&keys::TOTAL_MINT_SUPPLY)
         .unwrap_or_default()
@@ -1,6 +1,5 @@
 mod local;
 mod named_key;
-mod requests;
 
 // total mint supply
 pub use local::{read_total_mint_supply, write_total_mint_supply};
@@ -28,4 +27,3 @@ pub use local::{
     read_commission_amount, read_last_distributed_block, read_reward_amount,
     write_commission_amount, write_last_distributed_block, write_reward_amount,
 };
-pub use requests::{RedelegateRequest, UnbondRequest, UndelegateRequest};
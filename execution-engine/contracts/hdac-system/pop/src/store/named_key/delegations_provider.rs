@@ -6,55 +6,15 @@ use alloc::{
 use core::fmt::Write;
 
 use contract::contract_api::runtime;
-use types::{
-    account::PublicKey,
-    system_contract_errors::pos::{Error, Result},
-    Key, U512,
-};
-
-use crate::pop_contract::{DelegationKey, Delegations};
+use pop_types::{parse_delegation, Delegations};
+use types::{account::PublicKey, system_contract_errors::pos::Result, Key};
 
 pub fn read_delegations() -> Result<Delegations> {
     let mut delegations = BTreeMap::new();
     for (name, _) in runtime::list_named_keys() {
-        let mut split_name = name.split('_');
-        if Some("d") != split_name.next() {
-            continue;
+        if let Some((delegation_key, balance)) = parse_delegation(&name)? {
+            delegations.insert(delegation_key, balance);
         }
-
-        let to_publickey = |hex_str: &str| -> Result<PublicKey> {
-            if hex_str.len() != 64 {
-                return Err(Error::DelegationsKeyDeserializationFailed);
-            }
-            let mut key_bytes = [0u8; 32];
-            let _bytes_written = base16::decode_slice(hex_str, &mut key_bytes)
-                .map_err(|_| Error::DelegationsKeyDeserializationFailed)?;
-            debug_assert!(_bytes_written == key_bytes.len());
-            Ok(PublicKey::ed25519_from(key_bytes))
-        };
-
-        let hex_key = split_name
-            .next()
-            .ok_or(Error::DelegationsKeyDeserializationFailed)?;
-        let delegator = to_publickey(hex_key)?;
-
-        let hex_key = split_name
-            .next()
-            .ok_or(Error::DelegationsKeyDeserializationFailed)?;
-        let validator = to_publickey(hex_key)?;
-
-        let balance = split_name
-            .next()
-            .and_then(|b| U512::from_dec_str(b).ok())
-            .ok_or(Error::DelegationsDeserializationFailed)?;
-
-        delegations.insert(
-            DelegationKey {
-                delegator,
-                validator,
-            },
-            balance,
-        );
     }
     Ok(Delegations::new(delegations))
 }
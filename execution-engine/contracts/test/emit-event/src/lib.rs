@@ -0,0 +1,25 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{ApiError, CLValue, U512};
+
+const TOPIC_MESSAGE: &str = "message";
+const TOPIC_AMOUNT: &str = "amount";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let message: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    runtime::emit_event(TOPIC_MESSAGE, CLValue::from_t(message).unwrap_or_revert());
+
+    // Reverting here discards the event emitted above.
+    let amount: U512 = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    runtime::emit_event(TOPIC_AMOUNT, CLValue::from_t(amount).unwrap_or_revert());
+}
@@ -0,0 +1,47 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String, vec};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, CLType, EntryPoint, EntryPoints, Parameter};
+
+const CONTRACT_NAME: &str = "entry_points";
+const REVERT_WITH: &str = "revert_with";
+const CODE: &str = "code";
+
+enum Arg {
+    Code = 0,
+}
+
+enum StoreArg {
+    ExtraEntryPoint = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn revert_with() {
+    let code: u32 = runtime::get_arg(Arg::Code as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    runtime::revert(ApiError::User(code as u16));
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.insert(
+        String::from(REVERT_WITH),
+        EntryPoint::new(vec![Parameter::new(CODE, CLType::U32)]),
+    );
+    // Optionally declares an entry point the module doesn't export.
+    if let Some(name) = runtime::get_arg::<String>(StoreArg::ExtraEntryPoint as u32) {
+        let name = name.unwrap_or_revert_with(ApiError::InvalidArgument);
+        entry_points.insert(name, EntryPoint::new(vec![]));
+    }
+    let key = storage::store_contract_at_hash(entry_points, BTreeMap::new()).into();
+    runtime::put_key(CONTRACT_NAME, key);
+}
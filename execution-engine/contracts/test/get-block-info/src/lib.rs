@@ -0,0 +1,33 @@
+#![no_std]
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{account::PublicKey, ApiError};
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let known_block_height: u64 = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let known_proposer: Option<PublicKey> = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let known_deploy_hash: [u8; 32] = runtime::get_arg(2)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    assert_eq!(
+        runtime::get_block_height(),
+        known_block_height,
+        "actual block height not known block height"
+    );
+    assert_eq!(
+        runtime::get_proposer(),
+        known_proposer,
+        "actual proposer not known proposer"
+    );
+    assert_eq!(
+        runtime::get_deploy_hash(),
+        known_deploy_hash,
+        "actual deploy hash not known deploy hash"
+    );
+}
@@ -0,0 +1,49 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::String, vec};
+
+use contract::{contract_api::runtime, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
+use types::{
+    api_error,
+    bytesrepr::{self, ToBytes},
+    ApiError,
+};
+
+const ARG_VALUE: &str = "value";
+const EXPECTED_VALUE: &str = "Hello, world!";
+const EXTRA_BYTES: usize = 8;
+const FILL_BYTE: u8 = 0xff;
+
+#[repr(u16)]
+enum Error {
+    InvalidArgument = 0,
+    BufferOverwritten,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let name = ARG_VALUE.to_bytes().unwrap_or_revert();
+
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::get_named_arg_size(name.as_ptr(), name.len(), &mut arg_size as *mut usize)
+    };
+    api_error::result_from(ret).unwrap_or_revert();
+
+    // Passes a buffer larger than the argument, which the host should only fill up to its size.
+    let mut buffer = vec![FILL_BYTE; arg_size + EXTRA_BYTES];
+    let ret = unsafe {
+        ext_ffi::get_named_arg(name.as_ptr(), name.len(), buffer.as_mut_ptr(), buffer.len())
+    };
+    api_error::result_from(ret).unwrap_or_revert();
+
+    if buffer[arg_size..].iter().any(|byte| *byte != FILL_BYTE) {
+        runtime::revert(ApiError::User(Error::BufferOverwritten as u16));
+    }
+    buffer.truncate(arg_size);
+    let value: String = bytesrepr::deserialize(buffer)
+        .unwrap_or_revert_with(ApiError::User(Error::InvalidArgument as u16));
+    assert_eq!(value, EXPECTED_VALUE);
+}
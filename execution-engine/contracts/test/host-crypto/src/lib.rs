@@ -0,0 +1,44 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::{
+    contract_api::{crypto, runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{account::PublicKey, ApiError};
+
+const BLAKE2B_KEY: &str = "blake2b";
+const SHA256_KEY: &str = "sha256";
+const KECCAK256_KEY: &str = "keccak256";
+const SIGNATURE_VALID_KEY: &str = "signature_valid";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let data: Vec<u8> = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let public_key: PublicKey = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let signature: Vec<u8> = runtime::get_arg(2)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let blake2b = crypto::blake2b(&data).to_vec();
+    runtime::put_key(BLAKE2B_KEY, storage::new_uref(blake2b).into());
+
+    let sha256 = crypto::sha256(&data).to_vec();
+    runtime::put_key(SHA256_KEY, storage::new_uref(sha256).into());
+
+    let keccak256 = crypto::keccak256(&data).to_vec();
+    runtime::put_key(KECCAK256_KEY, storage::new_uref(keccak256).into());
+
+    let signature_valid = crypto::verify_ed25519(public_key, &data, &signature);
+    runtime::put_key(
+        SIGNATURE_VALID_KEY,
+        storage::new_uref(signature_valid).into(),
+    );
+}
@@ -0,0 +1,16 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::ApiError;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let message: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    runtime::print(&message);
+}
@@ -0,0 +1,33 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::ApiError;
+
+const RANDOM_BYTES_KEY: &str = "random_bytes";
+const UREF_KEY: &str = "uref";
+const RANDOM_BYTES_LENGTH: usize = 32;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let draw_random_bytes: bool = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    // Both branches create the same number of URefs, so only drawing the bytes differs.
+    let random_bytes = if draw_random_bytes {
+        runtime::random_bytes(RANDOM_BYTES_LENGTH)
+    } else {
+        Vec::new()
+    };
+    runtime::put_key(RANDOM_BYTES_KEY, storage::new_uref(random_bytes).into());
+
+    let uref = storage::new_uref(()).into();
+    runtime::put_key(UREF_KEY, uref);
+}
@@ -0,0 +1,23 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::ApiError;
+
+const BYTES_KEY: &str = "bytes";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let length: u32 = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let bytes = vec![0u8; length as usize];
+    runtime::put_key(BYTES_KEY, storage::new_uref(bytes).into());
+}
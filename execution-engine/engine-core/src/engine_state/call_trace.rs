@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+
+use engine_shared::gas::Gas;
+use types::{bytesrepr, CLValue, Key, Phase};
+
+/// A node of the call tree recorded while executing a module in tracing mode.
+///
+/// The root node stands for the executed module itself, and every contract it calls adds a child
+/// node, in the order of the calls.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallTrace {
+    key: Key,
+    phase: Phase,
+    method: Option<String>,
+    args_size: usize,
+    gas_used: Gas,
+    host_functions: BTreeMap<&'static str, u64>,
+    error: Option<String>,
+    calls: Vec<CallTrace>,
+}
+
+impl CallTrace {
+    fn new(key: Key, phase: Phase, args_bytes: &[u8]) -> Self {
+        CallTrace {
+            key,
+            phase,
+            method: method_name(args_bytes),
+            args_size: args_bytes.len(),
+            gas_used: Gas::default(),
+            host_functions: BTreeMap::new(),
+            error: None,
+            calls: Vec::new(),
+        }
+    }
+
+    /// The key of the account or contract which was executed.
+    pub fn key(&self) -> Key {
+        self.key
+    }
+
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// The method which was called, by convention the first argument if it is a string.
+    pub fn method(&self) -> Option<&str> {
+        self.method.as_ref().map(String::as_str)
+    }
+
+    /// The size of the serialized arguments.
+    pub fn args_size(&self) -> usize {
+        self.args_size
+    }
+
+    /// The gas used by this call, including the calls it made.
+    pub fn gas_used(&self) -> Gas {
+        self.gas_used
+    }
+
+    /// The number of invocations of every host function called directly by this call.
+    pub fn host_functions(&self) -> &BTreeMap<&'static str, u64> {
+        &self.host_functions
+    }
+
+    /// The error which ended this call, if any.  A call reverted by a nested call reports the
+    /// error as well, so the revert point is the deepest call reporting it.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_ref().map(String::as_str)
+    }
+
+    pub fn calls(&self) -> &[CallTrace] {
+        &self.calls
+    }
+}
+
+fn method_name(args_bytes: &[u8]) -> Option<String> {
+    let args: Vec<CLValue> = bytesrepr::deserialize(args_bytes.to_vec()).ok()?;
+    args.into_iter().next()?.into_t().ok()
+}
+
+/// Builds the [`CallTrace`] of a module while it is being executed.  It is shared by the runtimes
+/// of all nested calls, and keeps the calls which have not returned yet on a stack.
+#[derive(Debug)]
+pub(crate) struct CallTracer {
+    stack: Vec<CallTrace>,
+}
+
+impl CallTracer {
+    pub(crate) fn new(key: Key, phase: Phase, args_bytes: &[u8]) -> Self {
+        CallTracer {
+            stack: vec![CallTrace::new(key, phase, args_bytes)],
+        }
+    }
+
+    pub(crate) fn enter(&mut self, key: Key, phase: Phase, args_bytes: &[u8]) {
+        self.stack.push(CallTrace::new(key, phase, args_bytes));
+    }
+
+    pub(crate) fn exit(&mut self, gas_used: Gas, error: Option<String>) {
+        // The root is only removed by `finish`.
+        if self.stack.len() < 2 {
+            return;
+        }
+        let mut call = self.stack.pop().expect("stack should not be empty");
+        call.gas_used = gas_used;
+        call.error = error;
+        if let Some(caller) = self.stack.last_mut() {
+            caller.calls.push(call);
+        }
+    }
+
+    pub(crate) fn record_host_function(&mut self, name: &'static str) {
+        if let Some(call) = self.stack.last_mut() {
+            *call.host_functions.entry(name).or_insert(0) += 1;
+        }
+    }
+
+    /// Returns the trace of the executed module.
+    pub(crate) fn finish(&mut self, gas_used: Gas, error: Option<String>) -> CallTrace {
+        // Every call exits before its caller does, so only the root is left.
+        let mut root = self.stack.swap_remove(0);
+        root.gas_used = gas_used;
+        root.error = error;
+        root
+    }
+}
@@ -0,0 +1,32 @@
+use types::{CLValue, Key};
+
+/// An event emitted by a contract through the `emit_event` host function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractEvent {
+    emitter: Key,
+    topic: String,
+    payload: CLValue,
+}
+
+impl ContractEvent {
+    pub fn new(emitter: Key, topic: String, payload: CLValue) -> Self {
+        ContractEvent {
+            emitter,
+            topic,
+            payload,
+        }
+    }
+
+    /// The key of the account or contract which emitted the event.
+    pub fn emitter(&self) -> Key {
+        self.emitter
+    }
+
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    pub fn payload(&self) -> &CLValue {
+        &self.payload
+    }
+}
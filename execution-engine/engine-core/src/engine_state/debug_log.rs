@@ -0,0 +1,96 @@
+use std::mem;
+
+/// The maximum total size in bytes of the messages kept by a [`DebugLog`].
+pub const MAX_DEBUG_LOG_SIZE: usize = 64 * 1024;
+
+/// The message ending a [`DebugLog`] which exceeded [`MAX_DEBUG_LOG_SIZE`].
+pub const DEBUG_LOG_TRUNCATED: &str = "[debug log truncated]";
+
+/// Collects the messages printed by contracts while a module is executed.  It is shared by the
+/// runtimes of all nested calls.
+///
+/// Once the messages reach [`MAX_DEBUG_LOG_SIZE`] all further messages are dropped, and the log
+/// ends with [`DEBUG_LOG_TRUNCATED`].
+#[derive(Debug, Default)]
+pub(crate) struct DebugLog {
+    messages: Vec<String>,
+    size: usize,
+    truncated: bool,
+}
+
+impl DebugLog {
+    pub(crate) fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns whether a message of `size` bytes would be kept, so that callers can skip reading
+    /// messages which would be dropped.  The log is truncated if it wouldn't.
+    pub(crate) fn fits(&mut self, size: usize) -> bool {
+        if self.truncated {
+            return false;
+        }
+        if self.size + size > MAX_DEBUG_LOG_SIZE {
+            self.truncated = true;
+            return false;
+        }
+        true
+    }
+
+    pub(crate) fn push(&mut self, message: String) {
+        if !self.fits(message.len()) {
+            return;
+        }
+        self.size += message.len();
+        self.messages.push(message);
+    }
+
+    /// Returns the messages collected so far, and empties the log.
+    pub(crate) fn take(&mut self) -> Vec<String> {
+        let mut messages = mem::replace(&mut self.messages, Vec::new());
+        if self.truncated {
+            messages.push(DEBUG_LOG_TRUNCATED.to_string());
+        }
+        self.size = 0;
+        self.truncated = false;
+        messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_keep_messages_in_order() {
+        let mut debug_log = DebugLog::new();
+        debug_log.push("first".to_string());
+        debug_log.push("second".to_string());
+        assert_eq!(debug_log.take(), vec!["first", "second"]);
+        assert!(debug_log.take().is_empty());
+    }
+
+    #[test]
+    fn should_truncate_at_max_size() {
+        let mut debug_log = DebugLog::new();
+        let half = "a".repeat(MAX_DEBUG_LOG_SIZE / 2);
+        debug_log.push(half.clone());
+        debug_log.push(half.clone());
+        debug_log.push("b".to_string());
+        // A shorter message is dropped as well once the log was truncated.
+        debug_log.push(String::new());
+        assert_eq!(
+            debug_log.take(),
+            vec![half.clone(), half, DEBUG_LOG_TRUNCATED.to_string()]
+        );
+    }
+
+    #[test]
+    fn should_not_fit_messages_past_max_size() {
+        let mut debug_log = DebugLog::new();
+        assert!(debug_log.fits(MAX_DEBUG_LOG_SIZE));
+        assert!(!debug_log.fits(MAX_DEBUG_LOG_SIZE + 1));
+        // Nothing fits once the log was truncated.
+        assert!(!debug_log.fits(0));
+        assert_eq!(debug_log.take(), vec![DEBUG_LOG_TRUNCATED]);
+    }
+}
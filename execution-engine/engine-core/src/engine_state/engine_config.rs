@@ -4,6 +4,9 @@ pub struct EngineConfig {
     // feature flags go here
     use_system_contracts: bool,
     enable_bonding: bool,
+    parallel_execution_threads: usize,
+    disable_debug_log: bool,
+    module_cache_size: usize,
 }
 
 impl EngineConfig {
@@ -29,4 +32,40 @@ impl EngineConfig {
         self.enable_bonding = enable_bonding;
         self
     }
+
+    /// Number of threads on which deploys are executed speculatively.  Deploys are executed one
+    /// after another if it is less than 2.
+    pub fn parallel_execution_threads(self) -> usize {
+        self.parallel_execution_threads
+    }
+
+    pub fn with_parallel_execution_threads(
+        mut self,
+        parallel_execution_threads: usize,
+    ) -> EngineConfig {
+        self.parallel_execution_threads = parallel_execution_threads;
+        self
+    }
+
+    /// Whether the messages printed by contracts are discarded instead of being returned in the
+    /// `debug_log` of execution results.
+    pub fn disable_debug_log(self) -> bool {
+        self.disable_debug_log
+    }
+
+    pub fn with_disable_debug_log(mut self, disable_debug_log: bool) -> EngineConfig {
+        self.disable_debug_log = disable_debug_log;
+        self
+    }
+
+    /// Number of deserialized stored contracts kept in memory between deploys.  Nothing is kept
+    /// if it is 0.
+    pub fn module_cache_size(self) -> usize {
+        self.module_cache_size
+    }
+
+    pub fn with_module_cache_size(mut self, module_cache_size: usize) -> EngineConfig {
+        self.module_cache_size = module_cache_size;
+        self
+    }
 }
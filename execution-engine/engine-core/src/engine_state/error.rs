@@ -1,7 +1,7 @@
 use failure::Fail;
 
 use engine_shared::newtypes::Blake2bHash;
-use types::{bytesrepr, system_contract_errors::mint};
+use types::{account::PublicKey, bytesrepr, system_contract_errors::mint};
 
 use crate::execution;
 use types::ProtocolVersion;
@@ -12,10 +12,20 @@ pub enum Error {
     InvalidHashLength { expected: usize, actual: usize },
     #[fail(display = "Invalid public key length: expected {}, actual {}", _0, _1)]
     InvalidPublicKeyLength { expected: usize, actual: usize },
+    #[fail(display = "Missing proposer")]
+    MissingProposer,
     #[fail(display = "Invalid protocol version: {}", _0)]
     InvalidProtocolVersion(ProtocolVersion),
     #[fail(display = "Invalid upgrade config")]
     InvalidUpgradeConfig,
+    #[fail(
+        display = "Invalid vesting schedule for genesis account {}: {}",
+        public_key, reason
+    )]
+    InvalidVestingSchedule {
+        public_key: PublicKey,
+        reason: &'static str,
+    },
     #[fail(display = "Wasm preprocessing error: {}", _0)]
     WasmPreprocessing(engine_wasm_prep::PreprocessingError),
     #[fail(display = "Wasm serialization error: {:?}", _0)]
@@ -28,6 +38,13 @@ pub enum Error {
     Authorization,
     #[fail(display = "Insufficient payment")]
     InsufficientPayment,
+    #[fail(
+        display = "Gas price too low: minimum {}, actual {}",
+        min_gas_price, gas_price
+    )]
+    GasPriceTooLow { gas_price: u64, min_gas_price: u64 },
+    #[fail(display = "Block gas limit exceeded")]
+    BlockGasLimitExceeded,
     #[fail(display = "Deploy error")]
     Deploy,
     #[fail(display = "Payment finalization error")]
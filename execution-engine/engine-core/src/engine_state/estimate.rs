@@ -0,0 +1,72 @@
+use std::fmt;
+
+use engine_shared::{gas::Gas, newtypes::Blake2bHash};
+use types::{account::PublicKey, ProtocolVersion};
+
+use super::{deploy_item::DeployItem, execution_result::ExecutionResult};
+
+/// Executes a deploy without charging for it and without committing its effects.
+pub struct EstimateRequest {
+    pub parent_state_hash: Blake2bHash,
+    pub block_time: u64,
+    pub block_height: u64,
+    /// Public key of the validator which proposed the block.
+    pub proposer: PublicKey,
+    pub deploy_item: DeployItem,
+    pub protocol_version: ProtocolVersion,
+    /// Gas available to each of the payment and the session code, capped by the protocol's
+    /// session gas limit.  That limit if `None`.
+    pub gas_limit: Option<Gas>,
+}
+
+impl EstimateRequest {
+    pub fn new(
+        parent_state_hash: Blake2bHash,
+        block_time: u64,
+        block_height: u64,
+        proposer: PublicKey,
+        deploy_item: DeployItem,
+        protocol_version: ProtocolVersion,
+        gas_limit: Option<Gas>,
+    ) -> Self {
+        Self {
+            parent_state_hash,
+            block_time,
+            block_height,
+            proposer,
+            deploy_item,
+            protocol_version,
+            gas_limit,
+        }
+    }
+}
+
+pub enum EstimateResult {
+    RootNotFound(Blake2bHash),
+    /// `session` and `finalize` are `None` if the payment code failed, as the deploy would not
+    /// get any further.
+    Success {
+        payment: ExecutionResult,
+        session: Option<ExecutionResult>,
+        finalize: Option<ExecutionResult>,
+    },
+}
+
+impl fmt::Display for EstimateResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::RootNotFound(hash) => write!(f, "Root not found: {}", hash),
+            Self::Success {
+                payment,
+                session,
+                finalize,
+            } => write!(
+                f,
+                "Success: payment {:?}, session {:?}, finalize {:?}",
+                payment.cost(),
+                session.as_ref().map(ExecutionResult::cost),
+                finalize.as_ref().map(ExecutionResult::cost)
+            ),
+        }
+    }
+}
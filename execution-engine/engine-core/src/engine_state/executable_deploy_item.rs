@@ -3,22 +3,38 @@ pub enum ExecutableDeployItem {
     ModuleBytes {
         module_bytes: Vec<u8>,
         args: Vec<u8>,
+        named_args: Vec<u8>,
     },
     StoredContractByHash {
         hash: Vec<u8>,
+        entry_point: String,
         args: Vec<u8>,
+        named_args: Vec<u8>,
     },
     StoredContractByName {
         name: String,
+        entry_point: String,
         args: Vec<u8>,
+        named_args: Vec<u8>,
     },
     StoredContractByURef {
         uref: Vec<u8>,
+        entry_point: String,
         args: Vec<u8>,
+        named_args: Vec<u8>,
     },
 }
 
 impl ExecutableDeployItem {
+    pub fn named_args(&self) -> &[u8] {
+        match self {
+            ExecutableDeployItem::ModuleBytes { named_args, .. } => named_args,
+            ExecutableDeployItem::StoredContractByHash { named_args, .. } => named_args,
+            ExecutableDeployItem::StoredContractByName { named_args, .. } => named_args,
+            ExecutableDeployItem::StoredContractByURef { named_args, .. } => named_args,
+        }
+    }
+
     pub fn take_args(self) -> Vec<u8> {
         match self {
             ExecutableDeployItem::ModuleBytes { args, .. } => args,
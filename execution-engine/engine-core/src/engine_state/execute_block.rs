@@ -0,0 +1,140 @@
+use std::{fmt, mem};
+
+use engine_shared::{newtypes::Blake2bHash, TypeMismatch};
+use engine_storage::global_state::CommitResult;
+use types::{account::PublicKey, bytesrepr, Key, ProtocolVersion};
+
+use super::{
+    deploy_item::DeployItem, execution_effect::ExecutionEffect, execution_result::ExecutionResult,
+};
+
+/// Executes the deploys of a block one after another, each one seeing the effects of the ones
+/// before it, and commits the result if requested.
+pub struct ExecuteBlockRequest {
+    pub parent_state_hash: Blake2bHash,
+    pub block_time: u64,
+    pub block_height: u64,
+    /// Public key of the validator which proposed the block, if the request names one.
+    pub proposer: Option<PublicKey>,
+    pub deploys: Vec<Result<DeployItem, ExecutionResult>>,
+    pub protocol_version: ProtocolVersion,
+    /// Whether to run `step` of the proof of profession contract after the deploys.
+    pub run_step: bool,
+    /// Whether to commit the effects of the block, or only return them.
+    pub commit: bool,
+}
+
+impl ExecuteBlockRequest {
+    pub fn new(
+        parent_state_hash: Blake2bHash,
+        block_time: u64,
+        block_height: u64,
+        proposer: Option<PublicKey>,
+        deploys: Vec<Result<DeployItem, ExecutionResult>>,
+        protocol_version: ProtocolVersion,
+        run_step: bool,
+        commit: bool,
+    ) -> Self {
+        Self {
+            parent_state_hash,
+            block_time,
+            block_height,
+            proposer,
+            deploys,
+            protocol_version,
+            run_step,
+            commit,
+        }
+    }
+
+    pub fn take_deploys(&mut self) -> Vec<Result<DeployItem, ExecutionResult>> {
+        mem::replace(&mut self.deploys, vec![])
+    }
+}
+
+impl Default for ExecuteBlockRequest {
+    fn default() -> Self {
+        Self {
+            parent_state_hash: [0u8; 32].into(),
+            block_time: 0,
+            block_height: 0,
+            proposer: None,
+            deploys: vec![],
+            protocol_version: Default::default(),
+            run_step: false,
+            commit: true,
+        }
+    }
+}
+
+pub enum ExecuteBlockResult {
+    RootNotFound(Blake2bHash),
+    KeyNotFound(Key),
+    TypeMismatch(TypeMismatch),
+    Serialization(bytesrepr::Error),
+    Success {
+        post_state_hash: Blake2bHash,
+        execution_results: Vec<ExecutionResult>,
+        /// Number of deploys which were executed again because their speculative results
+        /// conflicted with the deploys preceding them.
+        reexecuted_deploys: usize,
+    },
+    /// The block was executed but, as requested, not committed.
+    Uncommitted {
+        effect: ExecutionEffect,
+        execution_results: Vec<ExecutionResult>,
+        reexecuted_deploys: usize,
+    },
+}
+
+impl fmt::Display for ExecuteBlockResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Self::RootNotFound(hash) => write!(f, "Root not found: {}", hash),
+            Self::KeyNotFound(key) => write!(f, "Key not found: {}", key),
+            Self::TypeMismatch(type_mismatch) => write!(f, "Type mismatch: {:?}", type_mismatch),
+            Self::Serialization(error) => write!(f, "Serialization error: {:?}", error),
+            Self::Success {
+                post_state_hash,
+                execution_results,
+                ..
+            } => write!(
+                f,
+                "Success: {} ({} deploys)",
+                post_state_hash,
+                execution_results.len()
+            ),
+            Self::Uncommitted {
+                effect,
+                execution_results,
+                ..
+            } => write!(
+                f,
+                "Uncommitted: {} transforms ({} deploys)",
+                effect.transforms.len(),
+                execution_results.len()
+            ),
+        }
+    }
+}
+
+impl ExecuteBlockResult {
+    pub fn from_commit_result(
+        commit_result: CommitResult,
+        parent_state_hash: Blake2bHash,
+        execution_results: Vec<ExecutionResult>,
+        reexecuted_deploys: usize,
+    ) -> Self {
+        match commit_result {
+            CommitResult::RootNotFound => Self::RootNotFound(parent_state_hash),
+            CommitResult::KeyNotFound(key) => Self::KeyNotFound(key),
+            CommitResult::TypeMismatch(type_mismatch) => Self::TypeMismatch(type_mismatch),
+            CommitResult::Serialization(error) => Self::Serialization(error),
+            CommitResult::Success { state_root, .. } => Self::Success {
+                post_state_hash: state_root,
+                execution_results,
+                reexecuted_deploys,
+            },
+        }
+    }
+}
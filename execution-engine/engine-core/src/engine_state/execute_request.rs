@@ -1,29 +1,40 @@
 use std::mem;
 
 use engine_shared::newtypes::Blake2bHash;
-use types::ProtocolVersion;
+use types::{account::PublicKey, ProtocolVersion};
 
 use super::{deploy_item::DeployItem, execution_result::ExecutionResult};
 
 pub struct ExecuteRequest {
     pub parent_state_hash: Blake2bHash,
     pub block_time: u64,
+    pub block_height: u64,
+    /// Public key of the validator which proposed the block, if the request names one.
+    pub proposer: Option<PublicKey>,
     pub deploys: Vec<Result<DeployItem, ExecutionResult>>,
     pub protocol_version: ProtocolVersion,
+    /// Whether the call tree of every deploy should be recorded in its result.
+    pub trace: bool,
 }
 
 impl ExecuteRequest {
     pub fn new(
         parent_state_hash: Blake2bHash,
         block_time: u64,
+        block_height: u64,
+        proposer: Option<PublicKey>,
         deploys: Vec<Result<DeployItem, ExecutionResult>>,
         protocol_version: ProtocolVersion,
+        trace: bool,
     ) -> Self {
         Self {
             parent_state_hash,
             block_time,
+            block_height,
+            proposer,
             deploys,
             protocol_version,
+            trace,
         }
     }
 
@@ -37,8 +48,11 @@ impl Default for ExecuteRequest {
         Self {
             parent_state_hash: [0u8; 32].into(),
             block_time: 0,
+            block_height: 0,
+            proposer: None,
             deploys: vec![],
             protocol_version: Default::default(),
+            trace: false,
         }
     }
 }
@@ -1,4 +1,7 @@
-use super::{error, execution_effect::ExecutionEffect, op::Op, CONV_RATE};
+use super::{
+    call_trace::CallTrace, contract_event::ContractEvent, error, execution_effect::ExecutionEffect,
+    op::Op,
+};
 use engine_shared::{
     additive_map::AdditiveMap, gas::Gas, motes::Motes, newtypes::CorrelationId,
     stored_value::StoredValue, transform::Transform,
@@ -38,6 +41,14 @@ fn make_payment_error_effects(
     ExecutionEffect::new(ops, transforms)
 }
 
+/// The `trace` of an execution holds the call tree of every executed module, in the order of
+/// execution.  It is only recorded in tracing mode, and empty otherwise.
+///
+/// The `events` emitted by contracts are only kept by successful executions, since all other
+/// effects of a failed execution are discarded as well.
+///
+/// The `debug_log` holds the messages printed by contracts, unless disabled by the engine
+/// configuration.
 #[derive(Debug)]
 pub enum ExecutionResult {
     /// An error condition that happened during execution
@@ -45,9 +56,17 @@ pub enum ExecutionResult {
         error: error::Error,
         effect: ExecutionEffect,
         cost: Gas,
+        trace: Vec<CallTrace>,
+        debug_log: Vec<String>,
     },
     /// Execution was finished successfully
-    Success { effect: ExecutionEffect, cost: Gas },
+    Success {
+        effect: ExecutionEffect,
+        cost: Gas,
+        trace: Vec<CallTrace>,
+        events: Vec<ContractEvent>,
+        debug_log: Vec<String>,
+    },
 }
 
 pub enum ForcedTransferResult {
@@ -66,6 +85,8 @@ impl ExecutionResult {
             error,
             effect: Default::default(),
             cost: Gas::default(),
+            trace: Vec::new(),
+            debug_log: Vec::new(),
         }
     }
 
@@ -106,28 +127,152 @@ impl ExecutionResult {
         }
     }
 
+    pub fn trace(&self) -> &[CallTrace] {
+        match self {
+            ExecutionResult::Failure { trace, .. } => trace,
+            ExecutionResult::Success { trace, .. } => trace,
+        }
+    }
+
+    pub fn debug_log(&self) -> &[String] {
+        match self {
+            ExecutionResult::Failure { debug_log, .. } => debug_log,
+            ExecutionResult::Success { debug_log, .. } => debug_log,
+        }
+    }
+
+    pub fn events(&self) -> &[ContractEvent] {
+        match self {
+            ExecutionResult::Failure { .. } => &[],
+            ExecutionResult::Success { events, .. } => events,
+        }
+    }
+
     pub fn with_cost(self, cost: Gas) -> Self {
         match self {
-            ExecutionResult::Failure { error, effect, .. } => ExecutionResult::Failure {
+            ExecutionResult::Failure {
+                error,
+                effect,
+                trace,
+                debug_log,
+                ..
+            } => ExecutionResult::Failure {
                 error,
                 effect,
                 cost,
+                trace,
+                debug_log,
+            },
+            ExecutionResult::Success {
+                effect,
+                trace,
+                events,
+                debug_log,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                cost,
+                trace,
+                events,
+                debug_log,
             },
-            ExecutionResult::Success { effect, .. } => ExecutionResult::Success { effect, cost },
         }
     }
 
     pub fn with_effect(self, effect: ExecutionEffect) -> Self {
         match self {
-            ExecutionResult::Failure { error, cost, .. } => ExecutionResult::Failure {
+            ExecutionResult::Failure {
+                error,
+                cost,
+                trace,
+                debug_log,
+                ..
+            } => ExecutionResult::Failure {
+                error,
+                effect,
+                cost,
+                trace,
+                debug_log,
+            },
+            ExecutionResult::Success {
+                cost,
+                trace,
+                events,
+                debug_log,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                cost,
+                trace,
+                events,
+                debug_log,
+            },
+        }
+    }
+
+    pub fn with_trace(self, trace: Vec<CallTrace>) -> Self {
+        match self {
+            ExecutionResult::Failure {
                 error,
                 effect,
                 cost,
+                debug_log,
+                ..
+            } => ExecutionResult::Failure {
+                error,
+                effect,
+                cost,
+                trace,
+                debug_log,
+            },
+            ExecutionResult::Success {
+                effect,
+                cost,
+                events,
+                debug_log,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                cost,
+                trace,
+                events,
+                debug_log,
             },
-            ExecutionResult::Success { cost, .. } => ExecutionResult::Success { effect, cost },
         }
     }
 
+    /// Replaces the events of a successful execution.  Failed executions do not keep any events.
+    pub fn with_events(self, events: Vec<ContractEvent>) -> Self {
+        match self {
+            ExecutionResult::Failure { .. } => self,
+            ExecutionResult::Success {
+                effect,
+                cost,
+                trace,
+                debug_log,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                cost,
+                trace,
+                events,
+                debug_log,
+            },
+        }
+    }
+
+    pub fn with_debug_log(mut self, debug_log: Vec<String>) -> Self {
+        match &mut self {
+            ExecutionResult::Failure {
+                debug_log: current, ..
+            }
+            | ExecutionResult::Success {
+                debug_log: current, ..
+            } => *current = debug_log,
+        }
+        self
+    }
+
     pub fn error(&self) -> Option<&error::Error> {
         match self {
             ExecutionResult::Failure { error, .. } => Some(error),
@@ -147,14 +292,15 @@ impl ExecutionResult {
     pub fn check_forced_transfer(
         &self,
         payment_purse_balance: Motes,
+        gas_price: u64,
     ) -> Option<ForcedTransferResult> {
-        let payment_result_cost = match Motes::from_gas(self.cost(), CONV_RATE) {
+        let payment_result_cost = match Motes::from_gas(self.cost(), gas_price) {
             Some(cost) => cost,
-            // Multiplying cost by CONV_RATE overflowed the U512 range
+            // Multiplying cost by gas_price overflowed the U512 range
             None => return Some(ForcedTransferResult::InsufficientPayment),
         };
         // payment_code_spec_3_b_ii: if (balance of PoS pay purse) < (gas spent during
-        // payment code execution) * conv_rate, no session
+        // payment code execution) * gas_price, no session
         let insufficient_balance_to_continue = payment_purse_balance < payment_result_cost;
 
         match self {
@@ -176,6 +322,7 @@ impl ExecutionResult {
     pub fn new_payment_code_error(
         error: error::Error,
         max_payment_cost: Motes,
+        gas_price: u64,
         account_main_purse_balance: Motes,
         account_main_purse: Key,
         rewards_purse: Key,
@@ -186,11 +333,13 @@ impl ExecutionResult {
             account_main_purse,
             rewards_purse,
         );
-        let cost = Gas::from_motes(max_payment_cost, CONV_RATE).unwrap_or_default();
+        let cost = Gas::from_motes(max_payment_cost, gas_price).unwrap_or_default();
         ExecutionResult::Failure {
             error,
             effect,
             cost,
+            trace: Vec::new(),
+            debug_log: Vec::new(),
         }
     }
 }
@@ -266,10 +415,16 @@ impl ExecutionResultBuilder {
         let cost = self.total_cost();
         let mut ops = AdditiveMap::new();
         let mut transforms = AdditiveMap::new();
+        let mut trace = Vec::new();
+        let mut events = Vec::new();
+        let mut debug_log = Vec::new();
 
         let mut ret: ExecutionResult = ExecutionResult::Success {
             effect: Default::default(),
             cost,
+            trace: Vec::new(),
+            events: Vec::new(),
+            debug_log: Vec::new(),
         };
 
         match self.payment_execution_result {
@@ -278,6 +433,9 @@ impl ExecutionResultBuilder {
                     return Ok(result);
                 } else {
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    trace.extend_from_slice(result.trace());
+                    events.extend_from_slice(result.events());
+                    debug_log.extend_from_slice(result.debug_log());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingPaymentExecutionResult),
@@ -287,10 +445,13 @@ impl ExecutionResultBuilder {
         // exec error
         match self.session_execution_result {
             Some(result) => {
+                trace.extend_from_slice(result.trace());
+                debug_log.extend_from_slice(result.debug_log());
                 if result.is_failure() {
                     ret = result.with_cost(cost);
                 } else {
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    events.extend_from_slice(result.events());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingSessionExecutionResult),
@@ -298,13 +459,18 @@ impl ExecutionResultBuilder {
 
         match self.finalize_execution_result {
             Some(result) => {
+                trace.extend_from_slice(result.trace());
+                debug_log.extend_from_slice(result.debug_log());
                 if result.is_failure() {
                     // payment_code_spec_5_a: Finalization Error should only ever be raised here
-                    return Ok(ExecutionResult::precondition_failure(
-                        error::Error::Finalization,
-                    ));
+                    return Ok(
+                        ExecutionResult::precondition_failure(error::Error::Finalization)
+                            .with_trace(trace)
+                            .with_debug_log(debug_log),
+                    );
                 } else {
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    events.extend_from_slice(result.events());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingFinalizeExecutionResult),
@@ -313,7 +479,11 @@ impl ExecutionResultBuilder {
         // Remove redundant writes to allow more opportunity to commute
         let reduced_effect = Self::reduce_identity_writes(ops, transforms, reader, correlation_id);
 
-        Ok(ret.with_effect(reduced_effect))
+        Ok(ret
+            .with_effect(reduced_effect)
+            .with_trace(trace)
+            .with_events(events)
+            .with_debug_log(debug_log))
     }
 
     fn add_effects(
@@ -6,7 +6,13 @@ use rand::{
     Rng,
 };
 
-use engine_shared::{motes::Motes, newtypes::Blake2bHash, TypeMismatch};
+use engine_shared::{
+    gas_limits::GasLimits,
+    host_function_costs::{self, HostFunctionCosts},
+    motes::Motes,
+    newtypes::Blake2bHash,
+    TypeMismatch,
+};
 use engine_storage::global_state::CommitResult;
 use engine_wasm_prep::wasm_costs::WasmCosts;
 use types::{account::PublicKey, bytesrepr, Key, ProtocolVersion, U512};
@@ -64,11 +70,62 @@ impl GenesisResult {
     }
 }
 
+/// Locks part of a genesis allocation in the account's main purse.
+///
+/// The whole `locked_amount` is locked until `cliff_timestamp`, and is then released linearly
+/// until `end_timestamp`.  Locked funds can still be bonded (and so delegated) to PoP.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VestingSchedule {
+    locked_amount: Motes,
+    cliff_timestamp: u64,
+    end_timestamp: u64,
+}
+
+impl VestingSchedule {
+    pub fn new(locked_amount: Motes, cliff_timestamp: u64, end_timestamp: u64) -> Self {
+        VestingSchedule {
+            locked_amount,
+            cliff_timestamp,
+            end_timestamp,
+        }
+    }
+
+    pub fn locked_amount(&self) -> Motes {
+        self.locked_amount
+    }
+
+    pub fn cliff_timestamp(&self) -> u64 {
+        self.cliff_timestamp
+    }
+
+    pub fn end_timestamp(&self) -> u64 {
+        self.end_timestamp
+    }
+}
+
+impl Distribution<VestingSchedule> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> VestingSchedule {
+        let mut u512_array = [0u8; 64];
+        rng.fill_bytes(u512_array.as_mut());
+        let locked_amount = Motes::new(U512::from(u512_array.as_ref()));
+
+        let cliff_timestamp = rng.gen_range(0, std::u64::MAX);
+        let end_timestamp = rng.gen_range(cliff_timestamp, std::u64::MAX);
+
+        VestingSchedule {
+            locked_amount,
+            cliff_timestamp,
+            end_timestamp,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct GenesisAccount {
     public_key: PublicKey,
     balance: Motes,
     bonded_amount: Motes,
+    vesting_schedule: Option<VestingSchedule>,
 }
 
 impl GenesisAccount {
@@ -77,9 +134,15 @@ impl GenesisAccount {
             public_key,
             balance,
             bonded_amount,
+            vesting_schedule: None,
         }
     }
 
+    pub fn with_vesting_schedule(mut self, vesting_schedule: VestingSchedule) -> Self {
+        self.vesting_schedule = Some(vesting_schedule);
+        self
+    }
+
     pub fn public_key(&self) -> PublicKey {
         self.public_key
     }
@@ -91,6 +154,10 @@ impl GenesisAccount {
     pub fn bonded_amount(&self) -> Motes {
         self.bonded_amount
     }
+
+    pub fn vesting_schedule(&self) -> Option<VestingSchedule> {
+        self.vesting_schedule
+    }
 }
 
 impl Distribution<GenesisAccount> for Standard {
@@ -104,10 +171,13 @@ impl Distribution<GenesisAccount> for Standard {
         rng.fill_bytes(u512_array.as_mut());
         let bonded_amount = Motes::new(U512::from(u512_array.as_ref()));
 
+        let vesting_schedule = if rng.gen() { Some(rng.gen()) } else { None };
+
         GenesisAccount {
             public_key,
             balance,
             bonded_amount,
+            vesting_schedule,
         }
     }
 }
@@ -123,6 +193,8 @@ pub struct GenesisConfig {
     accounts: Vec<GenesisAccount>,
     state_infos: Vec<String>,
     wasm_costs: WasmCosts,
+    gas_limits: GasLimits,
+    host_function_costs: HostFunctionCosts,
 }
 
 impl GenesisConfig {
@@ -148,9 +220,21 @@ impl GenesisConfig {
             accounts,
             state_infos,
             wasm_costs,
+            gas_limits: GasLimits::default(),
+            host_function_costs: HostFunctionCosts::default(),
         }
     }
 
+    pub fn with_gas_limits(mut self, gas_limits: GasLimits) -> Self {
+        self.gas_limits = gas_limits;
+        self
+    }
+
+    pub fn with_host_function_costs(mut self, host_function_costs: HostFunctionCosts) -> Self {
+        self.host_function_costs = host_function_costs;
+        self
+    }
+
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
@@ -179,6 +263,14 @@ impl GenesisConfig {
         self.wasm_costs
     }
 
+    pub fn gas_limits(&self) -> GasLimits {
+        self.gas_limits
+    }
+
+    pub fn host_function_costs(&self) -> HostFunctionCosts {
+        self.host_function_costs
+    }
+
     pub fn get_bonded_validators(&self) -> impl Iterator<Item = (PublicKey, Motes)> + '_ {
         let zero = Motes::zero();
         self.accounts.iter().filter_map(move |genesis_account| {
@@ -260,6 +352,26 @@ impl Distribution<GenesisConfig> for Standard {
             max_stack_height: rng.gen(),
             opcodes_mul: rng.gen(),
             opcodes_div: rng.gen(),
+            storage_per_byte: rng.gen(),
+            max_module_size: rng.gen(),
+            max_functions: rng.gen(),
+            max_globals: rng.gen(),
+            max_table_size: rng.gen(),
+            max_memory_pages: rng.gen(),
+        };
+
+        let gas_limits = GasLimits {
+            max_payment_cost: rng.gen(),
+            max_session_gas: rng.gen(),
+            max_block_gas: rng.gen(),
+        };
+
+        let host_function_costs = {
+            let bytes = iter::repeat(())
+                .map(|_| rng.gen())
+                .take(host_function_costs::HOST_FUNCTION_COSTS_SERIALIZED_LENGTH)
+                .collect();
+            bytesrepr::deserialize(bytes).expect("should deserialize host function costs")
         };
 
         GenesisConfig {
@@ -272,6 +384,8 @@ impl Distribution<GenesisConfig> for Standard {
             accounts,
             state_infos,
             wasm_costs,
+            gas_limits,
+            host_function_costs,
         }
     }
 }
@@ -1,13 +1,21 @@
+pub mod call_trace;
+pub mod contract_event;
+pub mod debug_log;
 pub mod deploy_item;
 pub mod engine_config;
 mod error;
+pub mod estimate;
 pub mod executable_deploy_item;
+pub mod execute_block;
 pub mod execute_request;
 pub mod execution_effect;
 pub mod execution_result;
 pub mod genesis;
+pub mod module_cache;
 pub mod op;
+pub mod pop_query;
 pub mod query;
+mod speculative;
 pub mod step;
 pub mod system_contract_cache;
 pub mod upgrade;
@@ -15,7 +23,8 @@ pub mod utils;
 
 use std::{
     cell::RefCell,
-    collections::{BTreeMap, BTreeSet, HashMap},
+    cmp,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     rc::Rc,
 };
 
@@ -28,6 +37,7 @@ use engine_shared::{
     additive_map::AdditiveMap,
     contract::Contract,
     gas::Gas,
+    gas_limits::DEFAULT_MAX_PAYMENT_COST,
     motes::Motes,
     newtypes::{Blake2bHash, CorrelationId},
     stored_value::StoredValue,
@@ -41,8 +51,11 @@ use engine_storage::{
 use engine_wasm_prep::{wasm_costs::WasmCosts, Preprocessor};
 use proof_of_stake::Stakes;
 use types::{
-    account::PublicKey, bytesrepr::ToBytes, system_contract_errors::mint, AccessRights, BlockTime,
-    Key, Phase, ProtocolVersion, URef, KEY_HASH_LENGTH, U512, UREF_ADDR_LENGTH,
+    account::PublicKey,
+    bytesrepr::{self, FromBytes, ToBytes},
+    system_contract_errors::mint,
+    AccessRights, BlockTime, CLTyped, CLValue, Key, Phase, ProtocolVersion, URef,
+    DEFAULT_ENTRY_POINT, KEY_HASH_LENGTH, U512, UREF_ADDR_LENGTH,
 };
 
 pub use self::{
@@ -53,37 +66,52 @@ use crate::{
     engine_state::{
         deploy_item::DeployItem,
         error::Error::MissingSystemContract,
+        estimate::{EstimateRequest, EstimateResult},
         executable_deploy_item::ExecutableDeployItem,
+        execute_block::{ExecuteBlockRequest, ExecuteBlockResult},
         execute_request::ExecuteRequest,
         execution_result::{ExecutionResult, ForcedTransferResult},
         genesis::{
             GenesisAccount, GenesisConfig, GenesisResult, PLACEHOLDER_KEY, POS_BONDING_PURSE,
             POS_PAYMENT_PURSE, POS_REWARDS_PURSE,
         },
+        module_cache::ModuleCache,
+        pop_query::{PopQueryRequest, PopQueryResult, POP_QUERY_GAS_LIMIT},
         query::{QueryRequest, QueryResult},
+        speculative::SpeculativeResult,
         step::{StepRequest, StepResult},
         system_contract_cache::SystemContractCache,
         upgrade::{UpgradeConfig, UpgradeResult},
     },
     execution::{self, AddressGenerator, Executor, CLIENT_API_PROXY_NAME, MINT_NAME, POS_NAME},
-    tracking_copy::{TrackingCopy, TrackingCopyExt},
+    resolvers, runtime,
+    tracking_copy::{AddResult, TrackingCopy, TrackingCopyExt},
     KnownKeys,
 };
 
-// TODO?: MAX_PAYMENT && CONV_RATE values are currently arbitrary w/ real values
-// TBD gas * CONV_RATE = motes
-pub const MAX_PAYMENT: u64 = 1_000_000_000_000_000;
+// TODO?: CONV_RATE value is currently arbitrary w/ real values
+// gas * gas price = motes, CONV_RATE is the conventional gas price
+/// The default maximum payment cost; the one in force is part of the protocol data.
+pub const MAX_PAYMENT: u64 = DEFAULT_MAX_PAYMENT_COST;
 pub const CONV_RATE: u64 = 1_000_000_000;
+/// The most gas the payment code of a deploy can spend, however low its gas price: what
+/// `MAX_PAYMENT` buys at `CONV_RATE`.
+pub const MAX_PAYMENT_GAS: u64 = MAX_PAYMENT / CONV_RATE;
+/// The minimum gas price associated with the genesis protocol version.
+pub const DEFAULT_MIN_GAS_PRICE: u64 = 1;
 
 pub const SYSTEM_ACCOUNT_ADDR: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 
 const GENESIS_INITIAL_BLOCKTIME: u64 = 0;
+const GENESIS_INITIAL_BLOCK_HEIGHT: u64 = 0;
 const MINT_METHOD_NAME: &str = "mint";
+const MINT_VESTING_METHOD_NAME: &str = "mint_vesting";
 
 #[derive(Debug)]
 pub struct EngineState<S> {
     config: EngineConfig,
     system_contract_cache: SystemContractCache,
+    module_cache: ModuleCache,
     state: S,
 }
 
@@ -94,9 +122,11 @@ where
 {
     pub fn new(state: S, config: EngineConfig) -> EngineState<S> {
         let system_contract_cache = Default::default();
+        let module_cache = ModuleCache::new(config.module_cache_size());
         EngineState {
             config,
             system_contract_cache,
+            module_cache,
             state,
         }
     }
@@ -105,6 +135,10 @@ where
         &self.config
     }
 
+    pub fn module_cache(&self) -> &ModuleCache {
+        &self.module_cache
+    }
+
     pub fn wasm_costs(
         &self,
         protocol_version: ProtocolVersion,
@@ -134,6 +168,8 @@ where
         // Preliminaries
         let executor = Executor::new(self.config);
         let blocktime = BlockTime::new(GENESIS_INITIAL_BLOCKTIME);
+        let block_height = GENESIS_INITIAL_BLOCK_HEIGHT;
+        let proposer = Some(SYSTEM_ACCOUNT_ADDR);
         let gas_limit = Gas::new(std::u64::MAX.into());
         let phase = Phase::System;
 
@@ -141,7 +177,25 @@ where
         let initial_root_hash = self.state.empty_root();
         let protocol_version = genesis_config.protocol_version();
         let wasm_costs = genesis_config.wasm_costs();
-        let preprocessor = Preprocessor::new(wasm_costs);
+        let preprocessor = resolvers::create_preprocessor(wasm_costs);
+
+        // Vesting is only enforced by the mint system contract, so a schedule must not be
+        // silently dropped by the host-side mint.
+        for account in genesis_config.accounts() {
+            if let Some(vesting_schedule) = account.vesting_schedule() {
+                let reason = if !self.config.use_system_contracts() {
+                    "vesting requires the mint system contract"
+                } else if vesting_schedule.locked_amount() > account.balance() {
+                    "locked amount exceeds balance"
+                } else {
+                    continue;
+                };
+                return Err(Error::InvalidVestingSchedule {
+                    public_key: account.public_key(),
+                    reason,
+                });
+            }
+        }
 
         // Spec #3: Create "virtual system account" object.
         let virtual_system_account = {
@@ -237,6 +291,8 @@ where
                     &virtual_system_account,
                     authorization_keys,
                     blocktime,
+                    block_height,
+                    proposer,
                     install_deploy_hash,
                     gas_limit,
                     address_generator,
@@ -289,6 +345,8 @@ where
                     &virtual_system_account,
                     dummy_authorization_keys,
                     blocktime,
+                    block_height,
+                    proposer,
                     install_deploy_hash,
                     gas_limit,
                     address_generator,
@@ -379,6 +437,8 @@ where
                     &virtual_system_account,
                     authorization_keys,
                     blocktime,
+                    block_height,
+                    proposer,
                     install_deploy_hash,
                     gas_limit,
                     address_generator,
@@ -398,6 +458,9 @@ where
         // specification.
         let protocol_data = ProtocolData::partial_without_standard_payment(
             wasm_costs,
+            DEFAULT_MIN_GAS_PRICE,
+            genesis_config.gas_limits(),
+            genesis_config.host_function_costs(),
             mint_reference,
             proof_of_stake_reference,
         );
@@ -436,6 +499,8 @@ where
                     &virtual_system_account,
                     authorization_keys,
                     blocktime,
+                    block_height,
+                    proposer,
                     install_deploy_hash,
                     gas_limit,
                     address_generator,
@@ -452,6 +517,9 @@ where
         // Spec #2: Associate given CostTable with given ProtocolVersion.
         let protocol_data = ProtocolData::new(
             wasm_costs,
+            DEFAULT_MIN_GAS_PRICE,
+            genesis_config.gas_limits(),
+            genesis_config.host_function_costs(),
             mint_reference,
             proof_of_stake_reference,
             standard_payment_reference,
@@ -544,9 +612,23 @@ where
                 let module = module.clone();
                 let args = {
                     let motes = account.balance().value();
-                    let args = (MINT_METHOD_NAME, motes);
-                    ArgsParser::parse(args)
-                        .expect("args should convert to `Vec<CLValue>`")
+                    let args = match account.vesting_schedule() {
+                        Some(vesting_schedule) => {
+                            let args = (
+                                MINT_VESTING_METHOD_NAME,
+                                motes,
+                                vesting_schedule.locked_amount().value(),
+                                vesting_schedule.cliff_timestamp(),
+                                vesting_schedule.end_timestamp(),
+                            );
+                            ArgsParser::parse(args)
+                        }
+                        None => {
+                            let args = (MINT_METHOD_NAME, motes);
+                            ArgsParser::parse(args)
+                        }
+                    };
+                    args.expect("args should convert to `Vec<CLValue>`")
                         .into_bytes()
                         .expect("args should serialize")
                 };
@@ -578,6 +660,8 @@ where
                         &virtual_system_account,
                         authorization_keys,
                         blocktime,
+                        block_height,
+                        proposer,
                         purse_creation_deploy_hash,
                         gas_limit,
                         address_generator,
@@ -603,6 +687,8 @@ where
                         &virtual_system_account,
                         authorization_keys,
                         blocktime,
+                        block_height,
+                        proposer,
                         purse_creation_deploy_hash,
                         gas_limit,
                         address_generator,
@@ -693,9 +779,27 @@ where
             None => *current_protocol_data.wasm_costs(),
         };
 
+        let new_min_gas_price = match upgrade_config.min_gas_price() {
+            Some(new_min_gas_price) => new_min_gas_price,
+            None => current_protocol_data.min_gas_price(),
+        };
+
+        let new_gas_limits = match upgrade_config.gas_limits() {
+            Some(new_gas_limits) => new_gas_limits,
+            None => *current_protocol_data.gas_limits(),
+        };
+
+        let new_host_function_costs = match upgrade_config.host_function_costs() {
+            Some(new_host_function_costs) => new_host_function_costs,
+            None => *current_protocol_data.host_function_costs(),
+        };
+
         // 3.1.2.2 persist wasm CostTable
         let new_protocol_data = ProtocolData::new(
             new_wasm_costs,
+            new_min_gas_price,
+            new_gas_limits,
+            new_host_function_costs,
             current_protocol_data.mint(),
             current_protocol_data.proof_of_stake(),
             current_protocol_data.standard_payment(),
@@ -719,7 +823,7 @@ where
 
                 // preprocess installer module
                 let upgrade_installer_module = {
-                    let preprocessor = Preprocessor::new(new_wasm_costs);
+                    let preprocessor = resolvers::create_preprocessor(new_wasm_costs);
                     preprocessor.preprocess(bytes)?
                 };
 
@@ -750,6 +854,8 @@ where
                 };
 
                 let blocktime = BlockTime::default();
+                let block_height = 0;
+                let proposer = Some(SYSTEM_ACCOUNT_ADDR);
 
                 let deploy_hash = {
                     // seeds address generator w/ protocol version
@@ -781,6 +887,8 @@ where
                     &system_account,
                     authorization_keys,
                     blocktime,
+                    block_height,
+                    proposer,
                     deploy_hash,
                     gas_limit,
                     address_generator,
@@ -842,33 +950,80 @@ where
         &self,
         correlation_id: CorrelationId,
         mut exec_request: ExecuteRequest,
-    ) -> Result<Vec<ExecutionResult>, RootNotFound> {
+    ) -> Result<Vec<ExecutionResult>, RootNotFound>
+    where
+        S: Sync,
+    {
         // TODO: do not unwrap
-        let wasm_costs = self
-            .wasm_costs(exec_request.protocol_version)
+        let protocol_data = self
+            .get_protocol_data(exec_request.protocol_version)
             .unwrap()
             .unwrap();
-        let executor = Executor::new(self.config);
-        let preprocessor = Preprocessor::new(wasm_costs);
+        let wasm_costs = *protocol_data.wasm_costs();
+        let max_block_gas = Gas::new(U512::from(protocol_data.gas_limits().max_block_gas));
+        let executor = Executor::new(self.config)
+            .with_tracing(exec_request.trace)
+            .with_module_cache(ModuleCache::clone(&self.module_cache));
+        let preprocessor = resolvers::create_preprocessor(wasm_costs);
+
+        let deploys = exec_request.take_deploys();
+
+        // Speculative results are not traced, so deploys are executed sequentially when tracing.
+        let thread_count = self.config.parallel_execution_threads();
+        let mut speculative_results =
+            if thread_count > 1 && deploys.len() > 1 && !executor.tracing() {
+                self.speculate(
+                    correlation_id,
+                    wasm_costs,
+                    exec_request.protocol_version,
+                    exec_request.parent_state_hash,
+                    BlockTime::new(exec_request.block_time),
+                    exec_request.block_height,
+                    exec_request.proposer,
+                    &deploys,
+                    thread_count,
+                )
+            } else {
+                HashMap::new()
+            };
 
         let mut results = Vec::new();
-
-        for deploy_item in exec_request.take_deploys() {
-            let result = match deploy_item {
-                Ok(deploy_item) => self.deploy(
+        let mut block_gas = Gas::default();
+
+        for (index, deploy_item) in deploys.into_iter().enumerate() {
+            // Every deploy is executed against the parent state, so a speculative result can
+            // always be used.
+            let speculative_result = speculative_results
+                .remove(&index)
+                .map(SpeculativeResult::into_result);
+            // The block's gas is spent, so the remaining deploys are left out of it.
+            if block_gas >= max_block_gas {
+                results.push(ExecutionResult::precondition_failure(
+                    Error::BlockGasLimitExceeded,
+                ));
+                continue;
+            }
+            let result = match (deploy_item, speculative_result) {
+                (_, Some(result)) => Ok(result),
+                (Ok(deploy_item), None) => self.deploy(
                     correlation_id,
                     &executor,
                     &preprocessor,
                     exec_request.protocol_version,
                     exec_request.parent_state_hash,
                     BlockTime::new(exec_request.block_time),
+                    exec_request.block_height,
+                    exec_request.proposer,
                     deploy_item,
                 ),
-                Err(exec_result) => Ok(exec_result), /* this will get pushed into the results vec
-                                                      * below */
+                // this will get pushed into the results vec below
+                (Err(exec_result), None) => Ok(exec_result),
             };
             match result {
-                Ok(result) => results.push(result),
+                Ok(result) => {
+                    charge_block_gas(&result, &mut block_gas);
+                    results.push(result);
+                }
                 Err(error) => {
                     return Err(error);
                 }
@@ -878,21 +1033,171 @@ where
         Ok(results)
     }
 
-    pub fn get_module(
+    pub fn run_estimate(
+        &self,
+        correlation_id: CorrelationId,
+        estimate_request: EstimateRequest,
+    ) -> Result<EstimateResult, Error> {
+        let parent_state_hash = estimate_request.parent_state_hash;
+        let tracking_copy = match self.tracking_copy(parent_state_hash)? {
+            Some(tracking_copy) => Rc::new(RefCell::new(tracking_copy)),
+            None => return Ok(EstimateResult::RootNotFound(parent_state_hash)),
+        };
+
+        let protocol_version = estimate_request.protocol_version;
+        let protocol_data = match self.state.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => protocol_data,
+            Ok(None) => {
+                return Err(Error::InvalidProtocolVersion(protocol_version));
+            }
+            Err(error) => {
+                return Err(Error::Exec(error.into()));
+            }
+        };
+        let executor =
+            Executor::new(self.config).with_module_cache(ModuleCache::clone(&self.module_cache));
+        let preprocessor = resolvers::create_preprocessor(*protocol_data.wasm_costs());
+        let blocktime = BlockTime::new(estimate_request.block_time);
+        let block_height = estimate_request.block_height;
+        let proposer = Some(estimate_request.proposer);
+        // No deploy can use more gas than the protocol allows a session.
+        let max_session_gas = Gas::new(U512::from(protocol_data.gas_limits().max_session_gas));
+        let gas_limit = estimate_request
+            .gas_limit
+            .map_or(max_session_gas, |gas_limit| {
+                cmp::min(gas_limit, max_session_gas)
+            });
+
+        let deploy_item = estimate_request.deploy_item;
+        let address = Key::Account(deploy_item.address);
+        let authorization_keys = deploy_item.authorization_keys;
+        let deploy_hash = deploy_item.deploy_hash;
+        let gas_price = deploy_item.gas_price;
+
+        let account: Account = tracking_copy
+            .borrow_mut()
+            .get_account(correlation_id, deploy_item.address)
+            .map_err(|_| Error::Authorization)?;
+        if !account.can_authorize(&authorization_keys) {
+            return Err(Error::Authorization);
+        }
+        if !account.can_deploy_with(&authorization_keys) {
+            return Err(execution::Error::DeploymentAuthorizationFailure.into());
+        }
+
+        let session_module = self.get_module(
+            Rc::clone(&tracking_copy),
+            &deploy_item.session,
+            &account,
+            correlation_id,
+            &preprocessor,
+            &protocol_version,
+        )?;
+
+        // Neither the account's main purse nor the payment purse is checked, so the payment code
+        // is only executed for its cost.
+        let payment = self.exec_payment(
+            correlation_id,
+            &executor,
+            &preprocessor,
+            protocol_version,
+            protocol_data,
+            Rc::clone(&tracking_copy),
+            blocktime,
+            block_height,
+            proposer,
+            deploy_hash,
+            &account,
+            authorization_keys.clone(),
+            deploy_item.payment,
+            gas_limit,
+        )?;
+        if payment.is_failure() {
+            return Ok(EstimateResult::Success {
+                payment,
+                session: None,
+                finalize: None,
+            });
+        }
+
+        let post_payment_tc = tracking_copy.borrow();
+        let session_tc = Rc::new(RefCell::new(post_payment_tc.fork()));
+        let session_named_args = deploy_item.session.named_args().to_vec();
+        let session = executor.exec(
+            session_module,
+            deploy_item.session.take_args(),
+            session_named_args,
+            address,
+            &account,
+            authorization_keys.clone(),
+            blocktime,
+            block_height,
+            proposer,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            Rc::clone(&session_tc),
+            Phase::Session,
+            protocol_data,
+            SystemContractCache::clone(&self.system_contract_cache),
+        );
+
+        let post_session_rc = if session.is_failure() {
+            Rc::new(RefCell::new(post_payment_tc.fork()))
+        } else {
+            session_tc
+        };
+        let post_session_tc = post_session_rc.borrow();
+        let finalization_tc = Rc::new(RefCell::new(post_session_tc.fork()));
+        let amount_spent =
+            Motes::from_gas(payment.cost() + session.cost(), gas_price).expect("motes overflow");
+        let finalize = self.exec_finalize_payment(
+            correlation_id,
+            &executor,
+            protocol_version,
+            protocol_data,
+            finalization_tc,
+            blocktime,
+            block_height,
+            proposer,
+            deploy_hash,
+            authorization_keys,
+            deploy_item.address,
+            amount_spent,
+        )?;
+
+        Ok(EstimateResult::Success {
+            payment,
+            session: Some(session),
+            finalize: Some(finalize),
+        })
+    }
+
+    pub fn get_module<R>(
         &self,
-        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
         deploy_item: &ExecutableDeployItem,
         account: &Account,
         correlation_id: CorrelationId,
         preprocessor: &Preprocessor,
         protocol_version: &ProtocolVersion,
-    ) -> Result<Module, error::Error> {
-        let stored_contract_key = match deploy_item {
+    ) -> Result<Module, error::Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        let (stored_contract_key, entry_point, args) = match deploy_item {
             ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
                 let module = preprocessor.preprocess(&module_bytes)?;
                 return Ok(module);
             }
-            ExecutableDeployItem::StoredContractByHash { hash, .. } => {
+            ExecutableDeployItem::StoredContractByHash {
+                hash,
+                entry_point,
+                args,
+                ..
+            } => {
                 let hash_len = hash.len();
                 if hash_len != KEY_HASH_LENGTH {
                     return Err(error::Error::InvalidHashLength {
@@ -902,9 +1207,14 @@ where
                 }
                 let mut arr = [0u8; KEY_HASH_LENGTH];
                 arr.copy_from_slice(&hash);
-                Key::Hash(arr)
+                (Key::Hash(arr), entry_point, args)
             }
-            ExecutableDeployItem::StoredContractByName { name, .. } => {
+            ExecutableDeployItem::StoredContractByName {
+                name,
+                entry_point,
+                args,
+                ..
+            } => {
                 let stored_contract_key = account.named_keys().get(name).ok_or_else(|| {
                     error::Error::Exec(execution::Error::URefNotFound(name.to_string()))
                 })?;
@@ -913,9 +1223,14 @@ where
                         return Err(error::Error::Exec(execution::Error::ForgedReference(*uref)));
                     }
                 }
-                *stored_contract_key
+                (*stored_contract_key, entry_point, args)
             }
-            ExecutableDeployItem::StoredContractByURef { uref, .. } => {
+            ExecutableDeployItem::StoredContractByURef {
+                uref,
+                entry_point,
+                args,
+                ..
+            } => {
                 let len = uref.len();
                 if len != UREF_ADDR_LENGTH {
                     return Err(error::Error::InvalidHashLength {
@@ -933,7 +1248,7 @@ where
                     .named_keys()
                     .values()
                     .find(|&named_key| named_key.normalize() == normalized_uref);
-                match maybe_named_key {
+                let stored_contract_key = match maybe_named_key {
                     Some(Key::URef(uref)) if uref.is_readable() => normalized_uref,
                     Some(Key::URef(_)) => {
                         return Err(error::Error::Exec(execution::Error::ForgedReference(
@@ -953,24 +1268,35 @@ where
                             Key::URef(read_only_uref),
                         )));
                     }
-                }
+                };
+                (stored_contract_key, entry_point, args)
             }
         };
         self.get_module_from_key(
             tracking_copy,
             stored_contract_key,
+            entry_point,
+            args,
             correlation_id,
             protocol_version,
         )
     }
 
-    fn get_module_from_key(
+    /// Returns the module of the contract stored under `stored_contract_key`, prepared to be
+    /// called at `entry_point` with the serialized `args`.
+    fn get_module_from_key<R>(
         &self,
-        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
         stored_contract_key: Key,
+        entry_point: &str,
+        args: &[u8],
         correlation_id: CorrelationId,
         protocol_version: &ProtocolVersion,
-    ) -> Result<Module, error::Error> {
+    ) -> Result<Module, error::Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
         let contract = tracking_copy
             .borrow_mut()
             .get_contract(correlation_id, stored_contract_key)?;
@@ -986,8 +1312,20 @@ where
             return Err(error::Error::Exec(exec_error));
         }
 
-        let (ret, _, _) = contract.destructure();
-        let module = engine_wasm_prep::deserialize(&ret)?;
+        let mut module = self.module_cache.get_or_insert_with(
+            correlation_id,
+            stored_contract_key,
+            contract_version,
+            contract.bytes(),
+            engine_wasm_prep::deserialize,
+        )?;
+
+        let args: Vec<CLValue> = if args.is_empty() {
+            Vec::new()
+        } else {
+            bytesrepr::deserialize(args.to_vec())?
+        };
+        runtime::select_entry_point(&mut module, contract.entry_points(), entry_point, &args)?;
         Ok(module)
     }
 
@@ -1000,16 +1338,10 @@ where
         protocol_version: ProtocolVersion,
         prestate_hash: Blake2bHash,
         blocktime: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
         deploy_item: DeployItem,
     ) -> Result<ExecutionResult, RootNotFound> {
-        // spec: https://casperlabs.atlassian.net/wiki/spaces/EN/pages/123404576/Payment+code+execution+specification
-
-        let session = deploy_item.session;
-        let payment = deploy_item.payment;
-        let address = Key::Account(deploy_item.address);
-        let authorization_keys = deploy_item.authorization_keys;
-        let deploy_hash = deploy_item.deploy_hash;
-
         // Create tracking copy (which functions as a deploy context)
         // validation_spec_2: prestate_hash check
         let tracking_copy = match self.tracking_copy(prestate_hash) {
@@ -1018,15 +1350,54 @@ where
             Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
         };
 
+        Ok(self.deploy_on(
+            correlation_id,
+            executor,
+            preprocessor,
+            protocol_version,
+            tracking_copy,
+            blocktime,
+            block_height,
+            proposer,
+            deploy_item,
+        ))
+    }
+
+    /// Executes `deploy_item` on top of `tracking_copy`.
+    ///
+    /// The effects of the deploy are returned in the result and are not applied to
+    /// `tracking_copy`.
+    #[allow(clippy::too_many_arguments)]
+    fn deploy_on<R>(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        blocktime: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
+        deploy_item: DeployItem,
+    ) -> ExecutionResult
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        // spec: https://casperlabs.atlassian.net/wiki/spaces/EN/pages/123404576/Payment+code+execution+specification
+
+        let session = deploy_item.session;
+        let payment = deploy_item.payment;
+        let address = Key::Account(deploy_item.address);
+        let authorization_keys = deploy_item.authorization_keys;
+        let deploy_hash = deploy_item.deploy_hash;
+        let gas_price = deploy_item.gas_price;
+
         // Get addr bytes from `address` (which is actually a Key)
         // validation_spec_3: account validity
         let account_addr = match address.into_account() {
             Some(account_addr) => account_addr,
-            None => {
-                return Ok(ExecutionResult::precondition_failure(
-                    error::Error::Authorization,
-                ))
-            }
+            None => return ExecutionResult::precondition_failure(error::Error::Authorization),
         };
 
         // Get account from tracking copy
@@ -1037,27 +1408,25 @@ where
         {
             Ok(account) => account,
             Err(_) => {
-                return Ok(ExecutionResult::precondition_failure(
-                    error::Error::Authorization,
-                ));
+                return ExecutionResult::precondition_failure(error::Error::Authorization);
             }
         };
 
         // Authorize using provided authorization keys
         // validation_spec_3: account validity
         if !account.can_authorize(&authorization_keys) {
-            return Ok(ExecutionResult::precondition_failure(
+            return ExecutionResult::precondition_failure(
                 crate::engine_state::error::Error::Authorization,
-            ));
+            );
         }
 
         // Check total key weight against deploy threshold
         // validation_spec_4: deploy validity
         if !account.can_deploy_with(&authorization_keys) {
-            return Ok(ExecutionResult::precondition_failure(
+            return ExecutionResult::precondition_failure(
                 // TODO?:this doesn't happen in execution any longer, should error variant be moved
                 execution::Error::DeploymentAuthorizationFailure.into(),
-            ));
+            );
         }
 
         // Create session code `A` from provided session bytes
@@ -1072,7 +1441,7 @@ where
         ) {
             Ok(module) => module,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error));
+                return ExecutionResult::precondition_failure(error);
             }
         };
 
@@ -1081,16 +1450,23 @@ where
             Ok(Some(protocol_data)) => protocol_data,
             Ok(None) => {
                 let error = Error::InvalidProtocolVersion(protocol_version);
-                return Ok(ExecutionResult::precondition_failure(error));
+                return ExecutionResult::precondition_failure(error);
             }
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(Error::Exec(
-                    error.into(),
-                )));
+                return ExecutionResult::precondition_failure(Error::Exec(error.into()));
             }
         };
 
-        let max_payment_cost: Motes = Motes::new(U512::from(MAX_PAYMENT));
+        // Reject deploys offering less than the minimum gas price of the protocol version
+        if gas_price < protocol_data.min_gas_price() {
+            return ExecutionResult::precondition_failure(Error::GasPriceTooLow {
+                gas_price,
+                min_gas_price: protocol_data.min_gas_price(),
+            });
+        }
+
+        let gas_limits = *protocol_data.gas_limits();
+        let max_payment_cost: Motes = Motes::new(U512::from(gas_limits.max_payment_cost));
 
         // Get mint system contract details
         // payment_code_spec_6: system contract validity
@@ -1105,13 +1481,13 @@ where
                 .get_contract(correlation_id, Key::URef(mint_reference))
             {
                 Ok(contract) => contract,
-                Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+                Err(error) => return ExecutionResult::precondition_failure(error.into()),
             };
 
             if !self.system_contract_cache.has(&mint_reference) {
                 let module = match engine_wasm_prep::deserialize(mint_contract.bytes()) {
                     Ok(module) => module,
-                    Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+                    Err(error) => return ExecutionResult::precondition_failure(error.into()),
                 };
                 self.system_contract_cache.insert(mint_reference, module);
             }
@@ -1132,7 +1508,7 @@ where
         {
             Ok(contract) => contract,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error.into()));
+                return ExecutionResult::precondition_failure(error.into());
             }
         };
 
@@ -1145,7 +1521,7 @@ where
                 match proof_of_stake_contract.named_keys().get(POS_REWARDS_PURSE) {
                     Some(key) => *key,
                     None => {
-                        return Ok(ExecutionResult::precondition_failure(Error::Deploy));
+                        return ExecutionResult::precondition_failure(Error::Deploy);
                     }
                 };
 
@@ -1156,7 +1532,7 @@ where
             ) {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
@@ -1172,7 +1548,7 @@ where
             ) {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
@@ -1184,143 +1560,44 @@ where
             .get_purse_balance(correlation_id, account_main_purse_balance_key)
         {
             Ok(balance) => balance,
-            Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+            Err(error) => return ExecutionResult::precondition_failure(error.into()),
         };
 
         // Enforce minimum main purse balance validation
         // validation_spec_5: account main purse minimum balance
         if account_main_purse_balance < max_payment_cost {
-            return Ok(ExecutionResult::precondition_failure(
-                Error::InsufficientPayment,
-            ));
+            return ExecutionResult::precondition_failure(Error::InsufficientPayment);
         }
 
-        // Finalization is executed by system account (currently genesis account)
-        // payment_code_spec_5: system executes finalization
-        let system_account = Account::new(
-            SYSTEM_ACCOUNT_ADDR,
-            Default::default(),
-            URef::new(Default::default(), AccessRights::READ_ADD_WRITE),
-            Default::default(),
-            Default::default(),
-        );
-
         // [`ExecutionResultBuilder`] handles merging of multiple execution results
         let mut execution_result_builder = execution_result::ExecutionResultBuilder::new();
 
         // Execute provided payment code
         let payment_result = {
-            // payment_code_spec_1: init pay environment w/ gas limit == (max_payment_cost /
-            // conv_rate)
-            let pay_gas_limit = Gas::from_motes(max_payment_cost, CONV_RATE).unwrap_or_default();
-
-            let module_bytes_is_empty = match payment {
-                ExecutableDeployItem::ModuleBytes {
-                    ref module_bytes, ..
-                } => module_bytes.is_empty(),
-                _ => false,
-            };
-
-            // Create payment code module from bytes
-            // validation_spec_1: valid wasm bytes
-            let maybe_payment_module = if module_bytes_is_empty {
-                let standard_payment = match self.state.get_protocol_data(protocol_version) {
-                    Ok(Some(protocol_data)) => {
-                        Key::URef(protocol_data.standard_payment()).normalize()
-                    }
-                    Ok(None) => {
-                        return Ok(ExecutionResult::precondition_failure(
-                            Error::InvalidProtocolVersion(protocol_version),
-                        ))
-                    }
-                    Err(_) => return Ok(ExecutionResult::precondition_failure(Error::Deploy)),
-                };
-                // If not in "use-system-contracts" mode, the returned module is the "do_nothing"
-                // Wasm.
-                self.get_module_from_key(
-                    Rc::clone(&tracking_copy),
-                    standard_payment,
-                    correlation_id,
-                    &protocol_version,
-                )
-            } else {
-                self.get_module(
-                    Rc::clone(&tracking_copy),
-                    &payment,
-                    &account,
-                    correlation_id,
-                    preprocessor,
-                    &protocol_version,
-                )
-            };
-
-            let payment_module = match maybe_payment_module {
-                Ok(module) => module,
-                Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error));
-                }
-            };
-            let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
-
-            // payment_code_spec_2: execute payment code
-            let phase = Phase::Payment;
-            if !self.config.use_system_contracts() && module_bytes_is_empty {
-                let mut named_keys = account.named_keys().clone();
-                let address_generator = AddressGenerator::new(&deploy_hash, phase);
-
-                let mut runtime = match executor.create_runtime(
-                    payment_module,
-                    payment.take_args(),
-                    &mut named_keys,
-                    address,
-                    &account,
-                    authorization_keys.clone(),
-                    blocktime,
-                    deploy_hash,
-                    pay_gas_limit,
-                    Rc::new(RefCell::new(address_generator)),
-                    protocol_version,
-                    correlation_id,
-                    Rc::clone(&tracking_copy),
-                    phase,
-                    protocol_data,
-                    system_contract_cache,
-                ) {
-                    Ok((_instance, runtime)) => runtime,
-                    Err(error) => {
-                        return Ok(ExecutionResult::precondition_failure(Error::Exec(error)))
-                    }
-                };
+            // payment_code_spec_1: init pay environment w/ gas limit == min(max_payment_cost /
+            // gas_price, MAX_PAYMENT_GAS)
+            let pay_gas_limit = Gas::from_motes(max_payment_cost, gas_price)
+                .unwrap_or_default()
+                .min(Gas::new(U512::from(MAX_PAYMENT_GAS)));
 
-                let effects_snapshot = tracking_copy.borrow().effect();
-                match runtime.call_host_standard_payment() {
-                    Ok(()) => ExecutionResult::Success {
-                        effect: runtime.context().effect(),
-                        cost: runtime.context().gas_counter(),
-                    },
-                    Err(error) => ExecutionResult::Failure {
-                        error: error.into(),
-                        effect: effects_snapshot,
-                        cost: runtime.context().gas_counter(),
-                    },
-                }
-            } else {
-                executor.exec(
-                    payment_module,
-                    payment.take_args(),
-                    address,
-                    &account,
-                    authorization_keys.clone(),
-                    blocktime,
-                    deploy_hash,
-                    pay_gas_limit,
-                    protocol_version,
-                    correlation_id,
-                    Rc::clone(&tracking_copy),
-                    phase,
-                    protocol_data,
-                    system_contract_cache,
-                )
+            match self.exec_payment(
+                correlation_id,
+                executor,
+                preprocessor,
+                protocol_version,
+                protocol_data,
+                Rc::clone(&tracking_copy),
+                blocktime,
+                block_height,
+                proposer,
+                deploy_hash,
+                &account,
+                authorization_keys.clone(),
+                payment,
+                pay_gas_limit,
+            ) {
+                Ok(payment_result) => payment_result,
+                Err(error) => return ExecutionResult::precondition_failure(error),
             }
         };
 
@@ -1334,7 +1611,7 @@ where
             let payment_purse: Key =
                 match proof_of_stake_contract.named_keys().get(POS_PAYMENT_PURSE) {
                     Some(key) => *key,
-                    None => return Ok(ExecutionResult::precondition_failure(Error::Deploy)),
+                    None => return ExecutionResult::precondition_failure(Error::Deploy),
                 };
 
             let purse_balance_key = match tracking_copy.borrow_mut().get_purse_balance_key(
@@ -1344,7 +1621,7 @@ where
             ) {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             };
 
@@ -1354,23 +1631,30 @@ where
             {
                 Ok(balance) => balance,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
 
-        if let Some(forced_transfer) = payment_result.check_forced_transfer(payment_purse_balance) {
+        if let Some(forced_transfer) =
+            payment_result.check_forced_transfer(payment_purse_balance, gas_price)
+        {
+            let payment_trace = payment_result.trace().to_vec();
+            let payment_debug_log = payment_result.debug_log().to_vec();
             let error = match forced_transfer {
                 ForcedTransferResult::InsufficientPayment => Error::InsufficientPayment,
                 ForcedTransferResult::PaymentFailure => payment_result.take_error().unwrap(),
             };
-            return Ok(ExecutionResult::new_payment_code_error(
+            return ExecutionResult::new_payment_code_error(
                 error,
                 max_payment_cost,
+                gas_price,
                 account_main_purse_balance,
                 account_main_purse_balance_key,
                 rewards_purse_balance_key,
-            ));
+            )
+            .with_trace(payment_trace)
+            .with_debug_log(payment_debug_log);
         }
 
         execution_result_builder.set_payment_execution_result(payment_result);
@@ -1381,21 +1665,27 @@ where
         // session_code_spec_2: execute session code
         let session_result = {
             // payment_code_spec_3_b_i: if (balance of PoS pay purse) >= (gas spent during
-            // payment code execution) * conv_rate, yes session
-            // session_code_spec_1: gas limit = ((balance of PoS payment purse) / conv_rate)
-            // - (gas spent during payment execution)
-            let session_gas_limit: Gas = Gas::from_motes(payment_purse_balance, CONV_RATE)
-                .unwrap_or_default()
-                - payment_result_cost;
+            // payment code execution) * gas_price, yes session
+            // session_code_spec_1: gas limit = ((balance of PoS payment purse) / gas_price)
+            // - (gas spent during payment execution), capped by the protocol's session gas limit
+            let session_gas_limit: Gas = cmp::min(
+                Gas::from_motes(payment_purse_balance, gas_price).unwrap_or_default()
+                    - payment_result_cost,
+                Gas::new(U512::from(gas_limits.max_session_gas)),
+            );
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
+            let session_named_args = session.named_args().to_vec();
             executor.exec(
                 session_module,
                 session.take_args(),
+                session_named_args,
                 address,
                 &account,
                 authorization_keys.clone(),
                 blocktime,
+                block_height,
+                proposer,
                 deploy_hash,
                 session_gas_limit,
                 protocol_version,
@@ -1424,67 +1714,29 @@ where
             let post_session_tc = post_session_rc.borrow();
             let finalization_tc = Rc::new(RefCell::new(post_session_tc.fork()));
 
-            // validation_spec_1: valid wasm bytes
-            let proof_of_stake_module =
-                match self.system_contract_cache.get(&proof_of_stake_reference) {
-                    Some(module) => module,
-                    None => {
-                        let module =
-                            match engine_wasm_prep::deserialize(proof_of_stake_contract.bytes()) {
-                                Ok(module) => module,
-                                Err(error) => {
-                                    return Ok(ExecutionResult::precondition_failure(error.into()))
-                                }
-                            };
-                        self.system_contract_cache
-                            .insert(proof_of_stake_reference, module.clone());
-                        module
-                    }
-                };
-
-            let proof_of_stake_args = {
-                //((gas spent during payment code execution) + (gas spent during session code execution)) * conv_rate
-                let finalize_cost_motes: Motes = Motes::from_gas(execution_result_builder.total_cost(), CONV_RATE).expect("motes overflow");
-                let args = ("finalize_payment", finalize_cost_motes.value(), account_addr);
-                ArgsParser::parse(args)
-                    .expect("args should convert to `Vec<CLValue>`")
-                    .into_bytes()
-                    .expect("args should serialize")
-            };
-
-            // The PoS keys may have changed because of effects during payment and/or
-            // session, so we need to look them up again from the tracking copy
-            let proof_of_stake_contract = match finalization_tc
-                .borrow_mut()
-                .get_contract(correlation_id, Key::URef(proof_of_stake_reference))
-            {
-                Ok(info) => info,
-                Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
-            };
-
-            let mut proof_of_stake_keys = proof_of_stake_contract.named_keys().to_owned();
+            // ((gas spent during payment code execution) + (gas spent during session code
+            // execution)) * gas_price
+            let finalize_cost_motes: Motes =
+                Motes::from_gas(execution_result_builder.total_cost(), gas_price)
+                    .expect("motes overflow");
 
-            let base_key = Key::from(proof_of_stake_reference);
-            let gas_limit = Gas::new(U512::from(std::u64::MAX));
-            let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
-
-            executor.exec_finalize(
-                proof_of_stake_module,
-                proof_of_stake_args,
-                &mut proof_of_stake_keys,
-                base_key,
-                &system_account,
-                authorization_keys,
-                blocktime,
-                deploy_hash,
-                gas_limit,
-                protocol_version,
+            match self.exec_finalize_payment(
                 correlation_id,
-                finalization_tc,
-                Phase::FinalizePayment,
+                executor,
+                protocol_version,
                 protocol_data,
-                system_contract_cache,
-            )
+                finalization_tc,
+                blocktime,
+                block_height,
+                proposer,
+                deploy_hash,
+                authorization_keys,
+                account_addr,
+                finalize_cost_motes,
+            ) {
+                Ok(finalize_result) => finalize_result,
+                Err(error) => return ExecutionResult::precondition_failure(error),
+            }
         };
 
         execution_result_builder.set_finalize_execution_result(finalize_result);
@@ -1497,26 +1749,250 @@ where
         // NOTE: payment_code_spec_5_a is enforced in execution_result_builder.build()
         // payment_code_spec_6: return properly combined set of transforms and
         // appropriate error
-        Ok(ret)
+        ret
     }
 
-    pub fn apply_effect(
+    /// Executes the payment code of a deploy on top of `tracking_copy`.
+    ///
+    /// Returns an error if the payment code could not be executed at all, which is a precondition
+    /// failure of the deploy.
+    #[allow(clippy::too_many_arguments)]
+    fn exec_payment<R>(
         &self,
         correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
         protocol_version: ProtocolVersion,
-        pre_state_hash: Blake2bHash,
-        effects: AdditiveMap<Key, Transform>,
-    ) -> Result<CommitResult, Error>
+        protocol_data: ProtocolData,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        blocktime: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
+        deploy_hash: [u8; 32],
+        account: &Account,
+        authorization_keys: BTreeSet<PublicKey>,
+        payment: ExecutableDeployItem,
+        gas_limit: Gas,
+    ) -> Result<ExecutionResult, Error>
     where
-        Error: From<S::Error>,
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
     {
-        match self.state.commit(correlation_id, pre_state_hash, effects)? {
-            CommitResult::Success { state_root, .. } => {
-                let bonded_validators =
-                    self.get_bonded_validators(correlation_id, protocol_version, state_root)?;
-                Ok(CommitResult::Success {
-                    state_root,
-                    bonded_validators,
+        let address = Key::Account(account.public_key());
+
+        let module_bytes_is_empty = match payment {
+            ExecutableDeployItem::ModuleBytes {
+                ref module_bytes, ..
+            } => module_bytes.is_empty(),
+            _ => false,
+        };
+
+        // Create payment code module from bytes
+        // validation_spec_1: valid wasm bytes
+        let maybe_payment_module = if module_bytes_is_empty {
+            let standard_payment = Key::URef(protocol_data.standard_payment()).normalize();
+            // If not in "use-system-contracts" mode, the returned module is the "do_nothing"
+            // Wasm.
+            self.get_module_from_key(
+                Rc::clone(&tracking_copy),
+                standard_payment,
+                DEFAULT_ENTRY_POINT,
+                &[],
+                correlation_id,
+                &protocol_version,
+            )
+        } else {
+            self.get_module(
+                Rc::clone(&tracking_copy),
+                &payment,
+                account,
+                correlation_id,
+                preprocessor,
+                &protocol_version,
+            )
+        };
+
+        let payment_module = maybe_payment_module?;
+        let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+
+        // payment_code_spec_2: execute payment code
+        let phase = Phase::Payment;
+        if !self.config.use_system_contracts() && module_bytes_is_empty {
+            let mut named_keys = account.named_keys().clone();
+            let address_generator = AddressGenerator::new(&deploy_hash, phase);
+
+            let mut runtime = match executor.create_runtime(
+                payment_module,
+                payment.take_args(),
+                &mut named_keys,
+                address,
+                account,
+                authorization_keys,
+                blocktime,
+                block_height,
+                proposer,
+                deploy_hash,
+                gas_limit,
+                Rc::new(RefCell::new(address_generator)),
+                protocol_version,
+                correlation_id,
+                Rc::clone(&tracking_copy),
+                phase,
+                protocol_data,
+                system_contract_cache,
+            ) {
+                Ok((_instance, runtime)) => runtime,
+                Err(error) => return Err(Error::Exec(error)),
+            };
+
+            let effects_snapshot = tracking_copy.borrow().effect();
+            match runtime.call_host_standard_payment() {
+                Ok(()) => Ok(ExecutionResult::Success {
+                    effect: runtime.context().effect(),
+                    cost: runtime.context().gas_counter(),
+                    trace: Vec::new(),
+                    events: Vec::new(),
+                    debug_log: Vec::new(),
+                }),
+                Err(error) => Ok(ExecutionResult::Failure {
+                    error: error.into(),
+                    effect: effects_snapshot,
+                    cost: runtime.context().gas_counter(),
+                    trace: Vec::new(),
+                    debug_log: Vec::new(),
+                }),
+            }
+        } else {
+            let payment_named_args = payment.named_args().to_vec();
+            Ok(executor.exec(
+                payment_module,
+                payment.take_args(),
+                payment_named_args,
+                address,
+                account,
+                authorization_keys,
+                blocktime,
+                block_height,
+                proposer,
+                deploy_hash,
+                gas_limit,
+                protocol_version,
+                correlation_id,
+                Rc::clone(&tracking_copy),
+                phase,
+                protocol_data,
+                system_contract_cache,
+            ))
+        }
+    }
+
+    /// Executes `finalize_payment` of the proof of stake contract on top of `tracking_copy`,
+    /// charging `amount_spent` to the payment purse.
+    ///
+    /// Returns an error if the contract could not be called at all, which is a precondition
+    /// failure of the deploy.
+    #[allow(clippy::too_many_arguments)]
+    fn exec_finalize_payment<R>(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        protocol_version: ProtocolVersion,
+        protocol_data: ProtocolData,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        blocktime: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
+        deploy_hash: [u8; 32],
+        authorization_keys: BTreeSet<PublicKey>,
+        account_addr: PublicKey,
+        amount_spent: Motes,
+    ) -> Result<ExecutionResult, Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        let proof_of_stake_reference = protocol_data.proof_of_stake();
+
+        // The PoS keys may have changed because of effects during payment and/or
+        // session, so we need to look them up again from the tracking copy
+        let proof_of_stake_contract = tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, Key::URef(proof_of_stake_reference))?;
+
+        // validation_spec_1: valid wasm bytes
+        let proof_of_stake_module = match self.system_contract_cache.get(&proof_of_stake_reference)
+        {
+            Some(module) => module,
+            None => {
+                let module = engine_wasm_prep::deserialize(proof_of_stake_contract.bytes())?;
+                self.system_contract_cache
+                    .insert(proof_of_stake_reference, module.clone());
+                module
+            }
+        };
+
+        let proof_of_stake_args = {
+            let args = ("finalize_payment", amount_spent.value(), account_addr);
+            ArgsParser::parse(args)
+                .expect("args should convert to `Vec<CLValue>`")
+                .into_bytes()
+                .expect("args should serialize")
+        };
+
+        let mut proof_of_stake_keys = proof_of_stake_contract.named_keys().to_owned();
+
+        // Finalization is executed by system account (currently genesis account)
+        // payment_code_spec_5: system executes finalization
+        let system_account = Account::new(
+            SYSTEM_ACCOUNT_ADDR,
+            Default::default(),
+            URef::new(Default::default(), AccessRights::READ_ADD_WRITE),
+            Default::default(),
+            Default::default(),
+        );
+
+        let base_key = Key::from(proof_of_stake_reference);
+        let gas_limit = Gas::new(U512::from(std::u64::MAX));
+        let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+
+        Ok(executor.exec_finalize(
+            proof_of_stake_module,
+            proof_of_stake_args,
+            &mut proof_of_stake_keys,
+            base_key,
+            &system_account,
+            authorization_keys,
+            blocktime,
+            block_height,
+            proposer,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            Phase::FinalizePayment,
+            protocol_data,
+            system_contract_cache,
+        ))
+    }
+
+    pub fn apply_effect(
+        &self,
+        correlation_id: CorrelationId,
+        protocol_version: ProtocolVersion,
+        pre_state_hash: Blake2bHash,
+        effects: AdditiveMap<Key, Transform>,
+    ) -> Result<CommitResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        match self.state.commit(correlation_id, pre_state_hash, effects)? {
+            CommitResult::Success { state_root, .. } => {
+                let bonded_validators =
+                    self.get_bonded_validators(correlation_id, protocol_version, state_root)?;
+                Ok(CommitResult::Success {
+                    state_root,
+                    bonded_validators,
                 })
             }
             commit_result => Ok(commit_result),
@@ -1589,26 +2065,283 @@ where
             }
         };
 
-        // retrieve the system account from tracking copy
-        let system_account = {
-            let key = Key::Account(SYSTEM_ACCOUNT_ADDR);
-            match tracking_copy.borrow_mut().read(correlation_id, &key) {
-                Ok(Some(StoredValue::Account(account))) => account,
-                Ok(_) => panic!("system account must exist"),
-                Err(error) => return Err(Error::Exec(error.into())),
+        self.exec_step(
+            correlation_id,
+            Rc::clone(&tracking_copy),
+            protocol_data,
+            protocol_version,
+            parent_state_hash,
+            step_request.block_time,
+            step_request.block_height,
+            step_request.proposer,
+        )?;
+
+        let effect = tracking_copy.borrow().effect();
+
+        // commit
+        let commit_result = self
+            .state
+            .commit(
+                correlation_id,
+                parent_state_hash,
+                effect.transforms.to_owned(),
+            )
+            .map_err(Into::into)?;
+
+        // return result and effects
+        Ok(StepResult::from_commit_result(
+            commit_result,
+            parent_state_hash,
+            effect,
+        ))
+    }
+
+    pub fn run_execute_block(
+        &self,
+        correlation_id: CorrelationId,
+        mut block_request: ExecuteBlockRequest,
+    ) -> Result<ExecuteBlockResult, Error>
+    where
+        S: Sync,
+    {
+        let parent_state_hash = block_request.parent_state_hash;
+        // The block can't be stepped without its proposer, and the step isn't skipped either.
+        let step_proposer = if block_request.run_step {
+            Some(block_request.proposer.ok_or(Error::MissingProposer)?)
+        } else {
+            None
+        };
+        let tracking_copy = match self.tracking_copy(parent_state_hash)? {
+            Some(tracking_copy) => Rc::new(RefCell::new(tracking_copy)),
+            None => return Ok(ExecuteBlockResult::RootNotFound(parent_state_hash)),
+        };
+
+        let protocol_version = block_request.protocol_version;
+        let protocol_data = match self.state.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => protocol_data,
+            Ok(None) => {
+                return Err(Error::InvalidProtocolVersion(protocol_version));
+            }
+            Err(error) => {
+                return Err(Error::Exec(error.into()));
             }
         };
+        let wasm_costs = *protocol_data.wasm_costs();
+        let max_block_gas = Gas::new(U512::from(protocol_data.gas_limits().max_block_gas));
+        let executor =
+            Executor::new(self.config).with_module_cache(ModuleCache::clone(&self.module_cache));
+        let preprocessor = resolvers::create_preprocessor(wasm_costs);
+        let blocktime = BlockTime::new(block_request.block_time);
+        let block_height = block_request.block_height;
+        let proposer = block_request.proposer;
+        let deploys = block_request.take_deploys();
+
+        let thread_count = self.config.parallel_execution_threads();
+        let mut speculative_results = if thread_count > 1 && deploys.len() > 1 {
+            self.speculate(
+                correlation_id,
+                wasm_costs,
+                protocol_version,
+                parent_state_hash,
+                blocktime,
+                block_height,
+                proposer,
+                &deploys,
+                thread_count,
+            )
+        } else {
+            HashMap::new()
+        };
+        let system_purse_balance_keys = if speculative_results.is_empty() {
+            HashSet::new()
+        } else {
+            speculative::system_purse_balance_keys(
+                correlation_id,
+                &*tracking_copy.borrow(),
+                &protocol_data,
+            )
+        };
+        // Keys modified by the deploys executed so far.  A speculative result is only valid if
+        // the ones among them the deploy touched still hold their parent values.
+        let mut modified_keys = HashSet::new();
+
+        let mut execution_results = Vec::new();
+        let mut reexecuted_deploys = 0;
+        let mut block_gas = Gas::default();
+        for (index, deploy_item) in deploys.into_iter().enumerate() {
+            let speculative_result = speculative_results.remove(&index);
+            // The block's gas is spent, so the remaining deploys are left out of it.
+            if block_gas >= max_block_gas {
+                execution_results.push(ExecutionResult::precondition_failure(
+                    Error::BlockGasLimitExceeded,
+                ));
+                continue;
+            }
+            let speculated = speculative_result.is_some();
+            let speculative_result = speculative_result.and_then(|speculative_result| {
+                speculative_result.validate(
+                    correlation_id,
+                    &modified_keys,
+                    &system_purse_balance_keys,
+                    &mut *tracking_copy.borrow_mut(),
+                )
+            });
+            let result = match (deploy_item, speculative_result) {
+                (_, Some(result)) => result,
+                (Ok(deploy_item), None) => {
+                    if speculated {
+                        reexecuted_deploys += 1;
+                    }
+                    // Run on a fork so that the deploy's effects can be folded into the block
+                    // exactly as they are reported in its result.
+                    let block_tc = tracking_copy.borrow();
+                    let deploy_tc = Rc::new(RefCell::new(block_tc.fork()));
+                    self.deploy_on(
+                        correlation_id,
+                        &executor,
+                        &preprocessor,
+                        protocol_version,
+                        deploy_tc,
+                        blocktime,
+                        block_height,
+                        proposer,
+                        deploy_item,
+                    )
+                }
+                (Err(exec_result), None) => exec_result,
+            };
+            charge_block_gas(&result, &mut block_gas);
 
-        let authorization_keys = {
-            let mut ret = BTreeSet::new();
-            ret.insert(SYSTEM_ACCOUNT_ADDR);
-            ret
+            let transforms = result.effect().transforms.clone();
+            speculative::record_modified_keys(&mut modified_keys, &transforms);
+            match tracking_copy
+                .borrow_mut()
+                .apply_changes(correlation_id, transforms)
+                .map_err(|error| Error::Exec(error.into()))?
+            {
+                AddResult::Success => {}
+                AddResult::KeyNotFound(key) => return Ok(ExecuteBlockResult::KeyNotFound(key)),
+                AddResult::TypeMismatch(type_mismatch) => {
+                    return Ok(ExecuteBlockResult::TypeMismatch(type_mismatch))
+                }
+                AddResult::Serialization(error) => {
+                    return Ok(ExecuteBlockResult::Serialization(error))
+                }
+            }
+            execution_results.push(result);
+        }
+
+        if let Some(proposer) = step_proposer {
+            self.exec_step(
+                correlation_id,
+                Rc::clone(&tracking_copy),
+                protocol_data,
+                protocol_version,
+                parent_state_hash,
+                block_request.block_time,
+                block_height,
+                proposer,
+            )?;
+        }
+
+        let effect = tracking_copy.borrow().effect();
+
+        if !block_request.commit {
+            return Ok(ExecuteBlockResult::Uncommitted {
+                effect,
+                execution_results,
+                reexecuted_deploys,
+            });
+        }
+
+        let commit_result = self
+            .state
+            .commit(correlation_id, parent_state_hash, effect.transforms)
+            .map_err(Into::into)?;
+
+        Ok(ExecuteBlockResult::from_commit_result(
+            commit_result,
+            parent_state_hash,
+            execution_results,
+            reexecuted_deploys,
+        ))
+    }
+
+    pub fn run_pop_query<T>(
+        &self,
+        correlation_id: CorrelationId,
+        pop_query_request: PopQueryRequest,
+    ) -> Result<PopQueryResult<T>, Error>
+    where
+        T: FromBytes + CLTyped,
+    {
+        // Only the read-only methods may run here, as the query runs as the system account.
+        if pop_query_request.method().is_none() {
+            return Ok(PopQueryResult::InvalidMethod);
+        }
+
+        let state_hash = pop_query_request.state_hash();
+        let tracking_copy = match self.tracking_copy(state_hash)? {
+            Some(tracking_copy) => Rc::new(RefCell::new(tracking_copy)),
+            None => return Ok(PopQueryResult::RootNotFound),
+        };
+
+        let protocol_version = pop_query_request.protocol_version();
+        let protocol_data = match self.state.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => protocol_data,
+            Ok(None) => {
+                return Err(Error::InvalidProtocolVersion(protocol_version));
+            }
+            Err(error) => {
+                return Err(Error::Exec(error.into()));
+            }
+        };
+
+        let args = pop_query_request.args().to_vec().into_bytes()?;
+
+        let deploy_hash = {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&state_hash.value());
+            bytes.extend_from_slice(&args);
+            Blake2bHash::new(&bytes).into()
         };
 
+        // The tracking copy is dropped without being committed, so the query can't change the
+        // global state.
+        let ret = self.exec_proof_of_stake(
+            correlation_id,
+            tracking_copy,
+            protocol_data,
+            protocol_version,
+            state_hash,
+            BlockTime::new(0),
+            0,
+            Some(SYSTEM_ACCOUNT_ADDR),
+            deploy_hash,
+            Gas::new(POP_QUERY_GAS_LIMIT.into()),
+            args,
+        )?;
+
+        Ok(PopQueryResult::Success(ret))
+    }
+
+    /// Runs `step` of the proof of profession contract on top of `tracking_copy`.
+    #[allow(clippy::too_many_arguments)]
+    fn exec_step(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<S::Reader>>>,
+        protocol_data: ProtocolData,
+        protocol_version: ProtocolVersion,
+        parent_state_hash: Blake2bHash,
+        block_time: u64,
+        block_height: u64,
+        proposer: PublicKey,
+    ) -> Result<(), Error> {
         let deploy_hash = {
-            let parent_state_hash: &[u8] = &step_request.parent_state_hash.value();
-            let blocktime: &[u8] = &step_request.block_time.to_le_bytes();
-            let protocol_version: &[u8] = &step_request.protocol_version.into_bytes()?;
+            let parent_state_hash: &[u8] = &parent_state_hash.value();
+            let blocktime: &[u8] = &block_time.to_le_bytes();
+            let protocol_version: &[u8] = &protocol_version.into_bytes()?;
 
             let bytes: Vec<u8> = {
                 let mut ret = Vec::new();
@@ -1620,8 +2353,62 @@ where
             Blake2bHash::new(&bytes).into()
         };
 
-        // `step` has no gas limit; approximating with MAX
-        let gas_limit = Gas::new(std::u64::MAX.into());
+        let args = ArgsParser::parse(("step",))
+            .expect("args should convert to `Vec<CLValue>`")
+            .into_bytes()
+            .expect("args should serialize");
+
+        self.exec_proof_of_stake(
+            correlation_id,
+            tracking_copy,
+            protocol_data,
+            protocol_version,
+            parent_state_hash,
+            BlockTime::new(block_time),
+            block_height,
+            Some(proposer),
+            deploy_hash,
+            // system calls have no gas limit; approximating with MAX
+            Gas::new(std::u64::MAX.into()),
+            args,
+        )
+    }
+
+    /// Runs the proof of profession contract as the system account on top of `tracking_copy`.
+    #[allow(clippy::too_many_arguments)]
+    fn exec_proof_of_stake<T>(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<S::Reader>>>,
+        protocol_data: ProtocolData,
+        protocol_version: ProtocolVersion,
+        parent_state_hash: Blake2bHash,
+        block_time: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
+        deploy_hash: [u8; 32],
+        gas_limit: Gas,
+        args: Vec<u8>,
+    ) -> Result<T, Error>
+    where
+        T: FromBytes + CLTyped,
+    {
+        // retrieve the system account from tracking copy
+        let system_account = {
+            let key = Key::Account(SYSTEM_ACCOUNT_ADDR);
+            match tracking_copy.borrow_mut().read(correlation_id, &key) {
+                Ok(Some(StoredValue::Account(account))) => account,
+                Ok(_) => panic!("system account must exist"),
+                Err(error) => return Err(Error::Exec(error.into())),
+            }
+        };
+
+        let authorization_keys = {
+            let mut ret = BTreeSet::new();
+            ret.insert(SYSTEM_ACCOUNT_ADDR);
+            ret
+        };
+
         let phase = Phase::System;
         let address_generator = {
             let generator = AddressGenerator::new(&parent_state_hash.value(), phase);
@@ -1658,50 +2445,39 @@ where
             }
         };
         let base_key = Key::from(proof_of_stake_reference);
-        let state = Rc::clone(&tracking_copy);
         let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
         let executor = Executor::new(self.config);
-        let args = ArgsParser::parse(("step",))
-            .expect("args should convert to `Vec<CLValue>`")
-            .into_bytes()
-            .expect("args should serialize");
 
-        executor.exec_system(
+        let ret = executor.exec_system(
             proof_of_stake_module,
             args,
             &mut proof_of_stake_keys,
             base_key,
             &system_account,
             authorization_keys,
-            BlockTime::new(step_request.block_time),
+            block_time,
+            block_height,
+            proposer,
             deploy_hash,
             gas_limit,
             address_generator,
             protocol_version,
             correlation_id,
-            state,
+            tracking_copy,
             phase,
             protocol_data,
             system_contract_cache,
         )?;
 
-        let effect = tracking_copy.borrow().effect();
-
-        // commit
-        let commit_result = self
-            .state
-            .commit(
-                correlation_id,
-                parent_state_hash,
-                effect.transforms.to_owned(),
-            )
-            .map_err(Into::into)?;
-
-        // return result and effects
-        Ok(StepResult::from_commit_result(
-            commit_result,
-            parent_state_hash,
-            effect,
-        ))
+        Ok(ret)
     }
 }
+
+/// Adds the cost of `result` to `block_gas`, the gas spent by the deploys of a block so far.
+///
+/// The deploys following the one which brings `block_gas` up to the block gas limit are left out
+/// of the block.  That one keeps its result, as its cost and effects are those of work already
+/// done.
+fn charge_block_gas(result: &ExecutionResult, block_gas: &mut Gas) {
+    *block_gas = *block_gas + result.cost();
+}
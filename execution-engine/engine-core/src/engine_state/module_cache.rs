@@ -0,0 +1,227 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
+
+use linked_hash_map::LinkedHashMap;
+use parity_wasm::elements::Module;
+
+use engine_shared::{
+    logging::log_metric,
+    newtypes::{Blake2bHash, CorrelationId},
+};
+use types::{Key, ProtocolVersion};
+
+const MODULE_CACHE_HITS: &str = "module_cache_hits";
+const MODULE_CACHE_MISSES: &str = "module_cache_misses";
+const GET: &str = "get";
+const GAUGE_METRIC_KEY: &str = "gauge";
+
+/// A module cached along with the hash of the bytes it was deserialized from, as the contract
+/// stored under a key can be upgraded.
+#[derive(Debug)]
+struct CachedModule {
+    bytes_hash: Blake2bHash,
+    module: Module,
+}
+
+/// A bounded cache of deserialized stored contracts, keyed by contract key and protocol version,
+/// which evicts the least recently used module when full. A cache with a capacity of zero caches
+/// nothing.
+#[derive(Clone, Default, Debug)]
+pub struct ModuleCache {
+    capacity: usize,
+    modules: Arc<Mutex<LinkedHashMap<(Key, ProtocolVersion), CachedModule>>>,
+    hits: Arc<AtomicU64>,
+    misses: Arc<AtomicU64>,
+}
+
+impl ModuleCache {
+    /// Creates a cache holding at most `capacity` modules.
+    pub fn new(capacity: usize) -> Self {
+        ModuleCache {
+            capacity,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a clone of the module cached for the contract stored under `key` with the given
+    /// `bytes`, deserializing and caching it with `deserialize` if it is missing.
+    pub fn get_or_insert_with<F, E>(
+        &self,
+        correlation_id: CorrelationId,
+        key: Key,
+        protocol_version: ProtocolVersion,
+        bytes: &[u8],
+        deserialize: F,
+    ) -> Result<Module, E>
+    where
+        F: FnOnce(&[u8]) -> Result<Module, E>,
+    {
+        if self.capacity == 0 {
+            return deserialize(bytes);
+        }
+
+        let cache_key = (key.normalize(), protocol_version);
+        let bytes_hash = Blake2bHash::new(bytes);
+        let maybe_module = {
+            let mut modules = self.modules.lock().unwrap();
+            match modules.get_refresh(&cache_key) {
+                Some(cached) if cached.bytes_hash == bytes_hash => Some(cached.module.clone()),
+                _ => None,
+            }
+        };
+
+        let module = match maybe_module {
+            Some(module) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                module
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                // Deserialize without holding the lock so other threads aren't blocked.
+                let module = deserialize(bytes)?;
+                let mut modules = self.modules.lock().unwrap();
+                modules.insert(
+                    cache_key,
+                    CachedModule {
+                        bytes_hash,
+                        module: module.clone(),
+                    },
+                );
+                while modules.len() > self.capacity {
+                    modules.pop_front();
+                }
+                module
+            }
+        };
+        self.log_metrics(correlation_id);
+        Ok(module)
+    }
+
+    /// Returns the number of cached modules.
+    pub fn len(&self) -> usize {
+        self.modules.lock().unwrap().len()
+    }
+
+    /// Returns `true` if no module is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of lookups which found their module in the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of lookups which had to deserialize their module.
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    fn log_metrics(&self, correlation_id: CorrelationId) {
+        log_metric(
+            correlation_id,
+            MODULE_CACHE_HITS,
+            GET,
+            GAUGE_METRIC_KEY,
+            self.hits() as f64,
+        );
+        log_metric(
+            correlation_id,
+            MODULE_CACHE_MISSES,
+            GET,
+            GAUGE_METRIC_KEY,
+            self.misses() as f64,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parity_wasm::elements::Module;
+
+    use engine_shared::newtypes::CorrelationId;
+    use types::{AccessRights, Key, ProtocolVersion, URef};
+
+    use super::ModuleCache;
+
+    fn get(cache: &ModuleCache, key: Key, bytes: &[u8]) -> Module {
+        cache
+            .get_or_insert_with(
+                CorrelationId::new(),
+                key,
+                ProtocolVersion::V1_0_0,
+                bytes,
+                |_| Ok::<_, ()>(Module::default()),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn should_count_hits_and_misses() {
+        let cache = ModuleCache::new(2);
+        let key = Key::Hash([1; 32]);
+
+        get(&cache, key, &[1]);
+        assert_eq!((cache.hits(), cache.misses()), (0, 1));
+        get(&cache, key, &[1]);
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+    }
+
+    #[test]
+    fn should_miss_when_contract_bytes_change() {
+        let cache = ModuleCache::new(2);
+        let key = Key::Hash([1; 32]);
+
+        get(&cache, key, &[1]);
+        get(&cache, key, &[2]);
+        assert_eq!((cache.hits(), cache.misses()), (0, 2));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn should_ignore_access_rights() {
+        let cache = ModuleCache::new(2);
+        let uref = URef::new([1; 32], AccessRights::READ_ADD_WRITE);
+
+        get(&cache, Key::URef(uref), &[1]);
+        get(
+            &cache,
+            Key::URef(uref.with_access_rights(AccessRights::READ)),
+            &[1],
+        );
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+    }
+
+    #[test]
+    fn should_evict_least_recently_used() {
+        let cache = ModuleCache::new(2);
+        let first = Key::Hash([1; 32]);
+        let second = Key::Hash([2; 32]);
+        let third = Key::Hash([3; 32]);
+
+        get(&cache, first, &[]);
+        get(&cache, second, &[]);
+        // Using `first` again makes `second` the least recently used.
+        get(&cache, first, &[]);
+        get(&cache, third, &[]);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.misses(), 3);
+
+        get(&cache, first, &[]);
+        assert_eq!(cache.misses(), 3);
+        get(&cache, second, &[]);
+        assert_eq!(cache.misses(), 4);
+    }
+
+    #[test]
+    fn should_not_cache_with_zero_capacity() {
+        let cache = ModuleCache::new(0);
+        let key = Key::Hash([1; 32]);
+
+        get(&cache, key, &[]);
+        get(&cache, key, &[]);
+        assert!(cache.is_empty());
+    }
+}
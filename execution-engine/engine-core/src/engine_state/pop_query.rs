@@ -0,0 +1,65 @@
+use engine_shared::newtypes::Blake2bHash;
+use types::{CLValue, ProtocolVersion};
+
+/// The read-only methods of the proof of profession contract which can be run as a query.
+pub const POP_QUERY_METHODS: [&str; 4] = [
+    "get_delegations",
+    "get_validator",
+    "get_pending_requests",
+    "get_reward",
+];
+
+/// Most gas a query can spend.  Queries run as the system account and are not paid for.
+pub const POP_QUERY_GAS_LIMIT: u64 = 10_000_000_000;
+
+/// Calls a read-only method of the proof of profession contract on top of `state_hash`.
+///
+/// The effects of the call are discarded, so nothing is committed to global state.
+pub struct PopQueryRequest {
+    state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    args: Vec<CLValue>,
+}
+
+impl PopQueryRequest {
+    pub fn new(
+        state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        args: Vec<CLValue>,
+    ) -> Self {
+        PopQueryRequest {
+            state_hash,
+            protocol_version,
+            args,
+        }
+    }
+
+    pub fn state_hash(&self) -> Blake2bHash {
+        self.state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn args(&self) -> &[CLValue] {
+        &self.args
+    }
+
+    /// Returns the method name passed as the first argument, if it is one of
+    /// [`POP_QUERY_METHODS`].
+    pub fn method(&self) -> Option<String> {
+        let method: String = self.args.first()?.clone().into_t().ok()?;
+        if POP_QUERY_METHODS.contains(&method.as_str()) {
+            Some(method)
+        } else {
+            None
+        }
+    }
+}
+
+pub enum PopQueryResult<T> {
+    RootNotFound,
+    InvalidMethod,
+    Success(T),
+}
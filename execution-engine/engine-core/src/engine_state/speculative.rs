@@ -0,0 +1,267 @@
+//! Speculative execution of deploys on separate threads.
+//!
+//! Every deploy is executed against the parent state, and the ops it performed are recorded. A
+//! deploy whose ops only touched keys which still hold their parent values after the deploys
+//! preceding it would have produced exactly the same result had it been executed after them, so
+//! its speculative result can be used as is. Other deploys have to be executed again.
+//!
+//! Every deploy pays into the purses of the proof of stake contract, so an `Add` to the balance of
+//! one of them is not a conflict: it commutes with the changes made to it by the other deploys.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use crossbeam_utils::thread;
+
+use engine_shared::{
+    additive_map::AdditiveMap,
+    newtypes::{Blake2bHash, CorrelationId},
+    stored_value::StoredValue,
+    transform::Transform,
+};
+use engine_storage::{
+    global_state::{StateProvider, StateReader},
+    protocol_data::ProtocolData,
+};
+use engine_wasm_prep::wasm_costs::WasmCosts;
+use types::{account::PublicKey, BlockTime, Key, ProtocolVersion};
+
+use super::{
+    deploy_item::DeployItem,
+    execution_result::ExecutionResult,
+    genesis::{POS_BONDING_PURSE, POS_PAYMENT_PURSE, POS_REWARDS_PURSE},
+    module_cache::ModuleCache,
+    op::Op,
+    EngineState,
+};
+use crate::{
+    execution::{self, Executor},
+    resolvers,
+    tracking_copy::{TrackingCopy, TrackingCopyExt},
+};
+
+/// Wraps a [`StateReader`] and records the value of every key which is read through it, including
+/// the ones which are not found.
+struct RecordingReader<R> {
+    reader: R,
+    values: RefCell<HashMap<Key, Option<StoredValue>>>,
+}
+
+impl<R> RecordingReader<R> {
+    fn new(reader: R) -> Self {
+        RecordingReader {
+            reader,
+            values: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn into_values(self) -> HashMap<Key, Option<StoredValue>> {
+        self.values.into_inner()
+    }
+}
+
+impl<R: StateReader<Key, StoredValue>> StateReader<Key, StoredValue> for &RecordingReader<R> {
+    type Error = R::Error;
+
+    fn read(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<StoredValue>, Self::Error> {
+        let value = self.reader.read(correlation_id, key)?;
+        self.values
+            .borrow_mut()
+            .entry(*key)
+            .or_insert_with(|| value.clone());
+        Ok(value)
+    }
+}
+
+/// A key touched by a speculatively executed deploy.
+struct Access {
+    key: Key,
+    op: Op,
+    /// The value of `key` in the parent state.
+    value: Option<StoredValue>,
+}
+
+/// The result of a deploy executed against the parent state.
+pub(super) struct SpeculativeResult {
+    result: ExecutionResult,
+    accesses: Vec<Access>,
+}
+
+impl SpeculativeResult {
+    /// Pairs the ops recorded in the effect of `result` with the parent values of the keys.
+    ///
+    /// Looking up the account or a contract doesn't record an op, and neither do precondition
+    /// failures, so a key which was read without an op is counted as a `Read`.
+    fn new(result: ExecutionResult, values: HashMap<Key, Option<StoredValue>>) -> Self {
+        let ops = &result.effect().ops;
+        let accesses = values
+            .into_iter()
+            .map(|(key, value)| Access {
+                key,
+                op: ops.get(&key).copied().unwrap_or(Op::Read),
+                value,
+            })
+            .collect();
+        SpeculativeResult { result, accesses }
+    }
+
+    pub(super) fn into_result(self) -> ExecutionResult {
+        self.result
+    }
+
+    /// Returns the result unless one of the keys in `modified_keys` the deploy touched holds a
+    /// different value in `tracking_copy` than in the parent state.
+    ///
+    /// `Add`s to `commutative_keys` are ignored.
+    pub(super) fn validate<R>(
+        self,
+        correlation_id: CorrelationId,
+        modified_keys: &HashSet<Key>,
+        commutative_keys: &HashSet<Key>,
+        tracking_copy: &mut TrackingCopy<R>,
+    ) -> Option<ExecutionResult>
+    where
+        R: StateReader<Key, StoredValue>,
+    {
+        for access in &self.accesses {
+            if access.op == Op::Add && commutative_keys.contains(&access.key) {
+                continue;
+            }
+            if !modified_keys.contains(&access.key) {
+                continue;
+            }
+            match tracking_copy.get(correlation_id, &access.key) {
+                Ok(value) if value == access.value => {}
+                _ => return None,
+            }
+        }
+        Some(self.result)
+    }
+}
+
+/// Adds the keys modified by `transforms` to `modified_keys`.
+pub(super) fn record_modified_keys(
+    modified_keys: &mut HashSet<Key>,
+    transforms: &AdditiveMap<Key, Transform>,
+) {
+    modified_keys.extend(
+        transforms
+            .iter()
+            .filter(|(_, transform)| **transform != Transform::Identity)
+            .map(|(key, _)| *key),
+    );
+}
+
+/// Returns the balance keys of the purses of the proof of stake contract, or none of them if they
+/// can't be looked up.
+pub(super) fn system_purse_balance_keys<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &TrackingCopy<R>,
+    protocol_data: &ProtocolData,
+) -> HashSet<Key>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    // Looked up on a fork, so that the reads don't end up in the effects of the block.
+    let mut tracking_copy = tracking_copy.fork();
+    let proof_of_stake_contract = match tracking_copy
+        .get_contract(correlation_id, Key::from(protocol_data.proof_of_stake()))
+    {
+        Ok(contract) => contract,
+        Err(_) => return HashSet::new(),
+    };
+    [POS_BONDING_PURSE, POS_PAYMENT_PURSE, POS_REWARDS_PURSE]
+        .iter()
+        .filter_map(|name| proof_of_stake_contract.named_keys().get(*name))
+        .filter_map(|purse_key| {
+            tracking_copy
+                .get_purse_balance_key(correlation_id, protocol_data.mint(), *purse_key)
+                .ok()
+        })
+        .collect()
+}
+
+impl<S> EngineState<S>
+where
+    S: StateProvider + Sync,
+    S::Error: Into<execution::Error>,
+{
+    /// Executes `deploys` against `parent_state_hash` on `thread_count` threads.
+    ///
+    /// The returned map is keyed by the index of the deploy.  Deploys which were not executed,
+    /// either because they failed to parse or because the parent state could not be read, are
+    /// missing from it.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn speculate(
+        &self,
+        correlation_id: CorrelationId,
+        wasm_costs: WasmCosts,
+        protocol_version: ProtocolVersion,
+        parent_state_hash: Blake2bHash,
+        blocktime: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
+        deploys: &[Result<DeployItem, ExecutionResult>],
+        thread_count: usize,
+    ) -> HashMap<usize, SpeculativeResult> {
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..thread_count)
+                .map(|offset| {
+                    scope.spawn(move |_| {
+                        let executor = Executor::new(self.config)
+                            .with_module_cache(ModuleCache::clone(&self.module_cache));
+                        let preprocessor = resolvers::create_preprocessor(wasm_costs);
+                        let mut ret = Vec::new();
+                        for (index, deploy_item) in deploys
+                            .iter()
+                            .enumerate()
+                            .skip(offset)
+                            .step_by(thread_count)
+                        {
+                            let deploy_item = match deploy_item {
+                                Ok(deploy_item) => deploy_item.clone(),
+                                Err(_) => continue,
+                            };
+                            let reader = match self.state.checkout(parent_state_hash) {
+                                Ok(Some(reader)) => RecordingReader::new(reader),
+                                _ => continue,
+                            };
+                            let result = self.deploy_on(
+                                correlation_id,
+                                &executor,
+                                &preprocessor,
+                                protocol_version,
+                                Rc::new(RefCell::new(TrackingCopy::new(&reader))),
+                                blocktime,
+                                block_height,
+                                proposer,
+                                deploy_item,
+                            );
+                            let values = reader.into_values();
+                            ret.push((index, SpeculativeResult::new(result, values)));
+                        }
+                        ret
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| {
+                    handle
+                        .join()
+                        .expect("speculative execution should not panic")
+                })
+                .collect()
+        })
+        .expect("speculative execution should not panic")
+    }
+}
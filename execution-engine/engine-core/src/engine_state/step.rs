@@ -2,13 +2,16 @@ use std::fmt;
 
 use engine_shared::{newtypes::Blake2bHash, TypeMismatch};
 use engine_storage::global_state::CommitResult;
-use types::{bytesrepr, Key, ProtocolVersion};
+use types::{account::PublicKey, bytesrepr, Key, ProtocolVersion};
 
 use crate::engine_state::execution_effect::ExecutionEffect;
 
 pub struct StepRequest {
     pub parent_state_hash: Blake2bHash,
     pub block_time: u64,
+    pub block_height: u64,
+    /// Public key of the validator which proposed the block.
+    pub proposer: PublicKey,
     pub protocol_version: ProtocolVersion,
 }
 
@@ -16,11 +19,15 @@ impl StepRequest {
     pub fn new(
         parent_state_hash: Blake2bHash,
         block_time: u64,
+        block_height: u64,
+        proposer: PublicKey,
         protocol_version: ProtocolVersion,
     ) -> Self {
         Self {
             parent_state_hash,
             block_time,
+            block_height,
+            proposer,
             protocol_version,
         }
     }
@@ -31,6 +38,8 @@ impl Default for StepRequest {
         Self {
             parent_state_hash: [0u8; 32].into(),
             block_time: 0,
+            block_height: 0,
+            proposer: PublicKey::ed25519_from([0u8; 32]),
             protocol_version: Default::default(),
         }
     }
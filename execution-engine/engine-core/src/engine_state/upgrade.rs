@@ -1,6 +1,9 @@
 use std::fmt;
 
-use engine_shared::{newtypes::Blake2bHash, TypeMismatch};
+use engine_shared::{
+    gas_limits::GasLimits, host_function_costs::HostFunctionCosts, newtypes::Blake2bHash,
+    TypeMismatch,
+};
 use engine_storage::global_state::CommitResult;
 use engine_wasm_prep::wasm_costs::WasmCosts;
 use types::{bytesrepr, Key, ProtocolVersion};
@@ -60,6 +63,9 @@ pub struct UpgradeConfig {
     upgrade_installer_args: Option<Vec<u8>>,
     upgrade_installer_bytes: Option<Vec<u8>>,
     wasm_costs: Option<WasmCosts>,
+    host_function_costs: Option<HostFunctionCosts>,
+    min_gas_price: Option<u64>,
+    gas_limits: Option<GasLimits>,
     activation_point: Option<ActivationPoint>,
 }
 
@@ -71,6 +77,9 @@ impl UpgradeConfig {
         upgrade_installer_args: Option<Vec<u8>>,
         upgrade_installer_bytes: Option<Vec<u8>>,
         wasm_costs: Option<WasmCosts>,
+        host_function_costs: Option<HostFunctionCosts>,
+        min_gas_price: Option<u64>,
+        gas_limits: Option<GasLimits>,
         activation_point: Option<ActivationPoint>,
     ) -> Self {
         UpgradeConfig {
@@ -80,6 +89,9 @@ impl UpgradeConfig {
             upgrade_installer_args,
             upgrade_installer_bytes,
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            gas_limits,
             activation_point,
         }
     }
@@ -110,6 +122,18 @@ impl UpgradeConfig {
         self.wasm_costs
     }
 
+    pub fn host_function_costs(&self) -> Option<HostFunctionCosts> {
+        self.host_function_costs
+    }
+
+    pub fn min_gas_price(&self) -> Option<u64> {
+        self.min_gas_price
+    }
+
+    pub fn gas_limits(&self) -> Option<GasLimits> {
+        self.gas_limits
+    }
+
     pub fn activation_point(&self) -> Option<u64> {
         self.activation_point
     }
@@ -5,7 +5,7 @@ use parity_wasm::elements;
 use engine_shared::TypeMismatch;
 use types::{
     account::{AddKeyFailure, RemoveKeyFailure, SetThresholdFailure, UpdateKeyFailure},
-    bytesrepr, system_contract_errors, AccessRights, CLValueError, Key, URef,
+    bytesrepr, system_contract_errors, AccessRights, CLType, CLValueError, Key, URef,
 };
 
 use crate::resolvers::error::ResolverError;
@@ -47,6 +47,15 @@ pub enum Error {
     CLValue(CLValueError),
     HostBufferEmpty,
     UnsupportedWasmStart,
+    MemoryLimitExceeded {
+        max_memory_pages: u32,
+    },
+    EntryPointNotFound(String),
+    InvalidEntryPointArgs {
+        entry_point: String,
+        expected: Vec<CLType>,
+        found: Vec<CLType>,
+    },
 }
 
 impl fmt::Display for Error {
@@ -14,12 +14,13 @@ use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
 use types::{
     account::PublicKey,
     bytesrepr::{self, FromBytes},
-    BlockTime, CLTyped, CLValue, Key, Phase, ProtocolVersion,
+    BlockTime, CLTyped, CLValue, Key, Phase, ProtocolVersion, RuntimeArgs,
 };
 
 use crate::{
     engine_state::{
-        execution_result::ExecutionResult, system_contract_cache::SystemContractCache, EngineConfig,
+        call_trace::CallTracer, debug_log::DebugLog, execution_result::ExecutionResult,
+        module_cache::ModuleCache, system_contract_cache::SystemContractCache, EngineConfig,
     },
     execution::{address_generator::AddressGenerator, Error, FN_STORE_ID_INITIAL},
     runtime::{extract_access_rights_from_keys, instance_and_memory, Runtime},
@@ -48,6 +49,8 @@ macro_rules! on_fail_charge {
                     error: exec_err.into(),
                     effect: Default::default(),
                     cost: $cost,
+                    trace: Vec::new(),
+                    debug_log: Vec::new(),
                 };
             }
         }
@@ -62,6 +65,8 @@ macro_rules! on_fail_charge {
                     error: exec_err.into(),
                     effect: $effect,
                     cost: $cost,
+                    trace: Vec::new(),
+                    debug_log: Vec::new(),
                 };
             }
         }
@@ -70,26 +75,95 @@ macro_rules! on_fail_charge {
 
 pub struct Executor {
     config: EngineConfig,
+    tracing: bool,
+    module_cache: Option<ModuleCache>,
 }
 
 #[allow(clippy::too_many_arguments)]
 impl Executor {
     pub fn new(config: EngineConfig) -> Self {
-        Executor { config }
+        Executor {
+            config,
+            tracing: false,
+            module_cache: None,
+        }
+    }
+
+    /// Makes the executor record the call tree of every module it executes in the `trace` of the
+    /// execution result.
+    pub fn with_tracing(mut self, tracing: bool) -> Self {
+        self.tracing = tracing;
+        self
+    }
+
+    /// Makes the executor look up the modules of stored contracts called during execution in
+    /// `module_cache`.
+    pub fn with_module_cache(mut self, module_cache: ModuleCache) -> Self {
+        self.module_cache = Some(module_cache);
+        self
     }
 
     pub fn config(&self) -> EngineConfig {
         self.config
     }
 
+    pub fn tracing(&self) -> bool {
+        self.tracing
+    }
+
+    fn tracer(&self, base_key: Key, phase: Phase, args: &[u8]) -> Option<Rc<RefCell<CallTracer>>> {
+        if self.tracing {
+            Some(Rc::new(RefCell::new(CallTracer::new(
+                base_key, phase, args,
+            ))))
+        } else {
+            None
+        }
+    }
+
+    fn debug_log(&self) -> Option<Rc<RefCell<DebugLog>>> {
+        if self.config.disable_debug_log() {
+            None
+        } else {
+            Some(Rc::new(RefCell::new(DebugLog::new())))
+        }
+    }
+
+    /// Adds the call trace and the debug log recorded while executing a module to its result.
+    fn with_records(
+        result: ExecutionResult,
+        tracer: Option<Rc<RefCell<CallTracer>>>,
+        debug_log: Option<Rc<RefCell<DebugLog>>>,
+    ) -> ExecutionResult {
+        let result = match tracer {
+            Some(tracer) => {
+                let trace = tracer
+                    .borrow_mut()
+                    .finish(result.cost(), result.error().map(ToString::to_string));
+                result.with_trace(vec![trace])
+            }
+            None => result,
+        };
+        match debug_log {
+            Some(debug_log) => {
+                let messages = debug_log.borrow_mut().take();
+                result.with_debug_log(messages)
+            }
+            None => result,
+        }
+    }
+
     pub fn exec<R>(
         &self,
         parity_module: Module,
         args: Vec<u8>,
+        named_args: Vec<u8>,
         base_key: Key,
         account: &Account,
         authorized_keys: BTreeSet<PublicKey>,
         blocktime: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
         deploy_hash: [u8; 32],
         gas_limit: Gas,
         protocol_version: ProtocolVersion,
@@ -103,8 +177,63 @@ impl Executor {
         R: StateReader<Key, StoredValue>,
         R::Error: Into<Error>,
     {
-        let (instance, memory) =
-            on_fail_charge!(instance_and_memory(parity_module.clone(), protocol_version));
+        let tracer = self.tracer(base_key, phase, &args);
+        let debug_log = self.debug_log();
+        let result = self.exec_module(
+            parity_module,
+            args,
+            named_args,
+            base_key,
+            account,
+            authorized_keys,
+            blocktime,
+            block_height,
+            proposer,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            tc,
+            phase,
+            protocol_data,
+            system_contract_cache,
+            tracer.clone(),
+            debug_log.clone(),
+        );
+        Self::with_records(result, tracer, debug_log)
+    }
+
+    fn exec_module<R>(
+        &self,
+        parity_module: Module,
+        args: Vec<u8>,
+        named_args: Vec<u8>,
+        base_key: Key,
+        account: &Account,
+        authorized_keys: BTreeSet<PublicKey>,
+        blocktime: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
+        deploy_hash: [u8; 32],
+        gas_limit: Gas,
+        protocol_version: ProtocolVersion,
+        correlation_id: CorrelationId,
+        tc: Rc<RefCell<TrackingCopy<R>>>,
+        phase: Phase,
+        protocol_data: ProtocolData,
+        system_contract_cache: SystemContractCache,
+        tracer: Option<Rc<RefCell<CallTracer>>>,
+        debug_log: Option<Rc<RefCell<DebugLog>>>,
+    ) -> ExecutionResult
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<Error>,
+    {
+        let (instance, memory) = on_fail_charge!(instance_and_memory(
+            parity_module.clone(),
+            protocol_version,
+            protocol_data.wasm_costs().max_memory_pages
+        ));
 
         let mut named_keys = account.named_keys().clone();
 
@@ -133,15 +262,25 @@ impl Executor {
             on_fail_charge!(bytesrepr::deserialize(args), gas, effects_snapshot)
         };
 
+        let named_args: RuntimeArgs = if named_args.is_empty() {
+            RuntimeArgs::new()
+        } else {
+            let gas = Gas::new(named_args.len().into());
+            on_fail_charge!(bytesrepr::deserialize(named_args), gas, effects_snapshot)
+        };
+
         let context = RuntimeContext::new(
             tc,
             &mut named_keys,
             access_rights,
             args.clone(),
+            named_args,
             authorized_keys,
             &account,
             base_key,
             blocktime,
+            block_height,
+            proposer,
             deploy_hash,
             gas_limit,
             gas_counter,
@@ -160,6 +299,15 @@ impl Executor {
             parity_module,
             context,
         );
+        if let Some(tracer) = tracer {
+            runtime.set_tracer(tracer);
+        }
+        if let Some(debug_log) = debug_log {
+            runtime.set_debug_log(debug_log);
+        }
+        if let Some(module_cache) = &self.module_cache {
+            runtime.set_module_cache(ModuleCache::clone(module_cache));
+        }
 
         if !self.config.use_system_contracts() {
             if runtime.is_mint(base_key) {
@@ -173,6 +321,9 @@ impl Executor {
                         return ExecutionResult::Success {
                             effect: runtime.context().effect(),
                             cost: runtime.context().gas_counter(),
+                            trace: Vec::new(),
+                            events: runtime.take_events(),
+                            debug_log: Vec::new(),
                         }
                     }
                     Err(error) => {
@@ -180,6 +331,8 @@ impl Executor {
                             error: error.into(),
                             effect: effects_snapshot,
                             cost: runtime.context().gas_counter(),
+                            trace: Vec::new(),
+                            debug_log: Vec::new(),
                         }
                     }
                 }
@@ -194,6 +347,9 @@ impl Executor {
                         return ExecutionResult::Success {
                             effect: runtime.context().effect(),
                             cost: runtime.context().gas_counter(),
+                            trace: Vec::new(),
+                            events: runtime.take_events(),
+                            debug_log: Vec::new(),
                         }
                     }
                     Err(error) => {
@@ -201,6 +357,8 @@ impl Executor {
                             error: error.into(),
                             effect: effects_snapshot,
                             cost: runtime.context().gas_counter(),
+                            trace: Vec::new(),
+                            debug_log: Vec::new(),
                         }
                     }
                 }
@@ -216,6 +374,9 @@ impl Executor {
         ExecutionResult::Success {
             effect: runtime.context().effect(),
             cost: runtime.context().gas_counter(),
+            trace: Vec::new(),
+            events: runtime.take_events(),
+            debug_log: Vec::new(),
         }
     }
 
@@ -228,6 +389,58 @@ impl Executor {
         account: &Account,
         authorization_keys: BTreeSet<PublicKey>,
         blocktime: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
+        deploy_hash: [u8; 32],
+        gas_limit: Gas,
+        protocol_version: ProtocolVersion,
+        correlation_id: CorrelationId,
+        state: Rc<RefCell<TrackingCopy<R>>>,
+        phase: Phase,
+        protocol_data: ProtocolData,
+        system_contract_cache: SystemContractCache,
+    ) -> ExecutionResult
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<Error>,
+    {
+        let tracer = self.tracer(base_key, phase, &args);
+        let debug_log = self.debug_log();
+        let result = self.exec_finalize_module(
+            parity_module,
+            args,
+            named_keys,
+            base_key,
+            account,
+            authorization_keys,
+            blocktime,
+            block_height,
+            proposer,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            state,
+            phase,
+            protocol_data,
+            system_contract_cache,
+            tracer.clone(),
+            debug_log.clone(),
+        );
+        Self::with_records(result, tracer, debug_log)
+    }
+
+    fn exec_finalize_module<R>(
+        &self,
+        parity_module: Module,
+        args: Vec<u8>,
+        named_keys: &mut BTreeMap<String, Key>,
+        base_key: Key,
+        account: &Account,
+        authorization_keys: BTreeSet<PublicKey>,
+        blocktime: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
         deploy_hash: [u8; 32],
         gas_limit: Gas,
         protocol_version: ProtocolVersion,
@@ -236,6 +449,8 @@ impl Executor {
         phase: Phase,
         protocol_data: ProtocolData,
         system_contract_cache: SystemContractCache,
+        tracer: Option<Rc<RefCell<CallTracer>>>,
+        debug_log: Option<Rc<RefCell<DebugLog>>>,
     ) -> ExecutionResult
     where
         R: StateReader<Key, StoredValue>,
@@ -277,10 +492,13 @@ impl Executor {
             &mut named_keys,
             access_rights,
             args.clone(),
+            RuntimeArgs::new(),
             authorization_keys,
             &account,
             base_key,
             blocktime,
+            block_height,
+            proposer,
             deploy_hash,
             gas_limit,
             gas_counter,
@@ -292,8 +510,11 @@ impl Executor {
             protocol_data,
         );
 
-        let (instance, memory) =
-            on_fail_charge!(instance_and_memory(parity_module.clone(), protocol_version));
+        let (instance, memory) = on_fail_charge!(instance_and_memory(
+            parity_module.clone(),
+            protocol_version,
+            protocol_data.wasm_costs().max_memory_pages
+        ));
 
         let mut runtime = Runtime::new(
             self.config,
@@ -302,6 +523,15 @@ impl Executor {
             parity_module,
             context,
         );
+        if let Some(tracer) = tracer {
+            runtime.set_tracer(tracer);
+        }
+        if let Some(debug_log) = debug_log {
+            runtime.set_debug_log(debug_log);
+        }
+        if let Some(module_cache) = &self.module_cache {
+            runtime.set_module_cache(ModuleCache::clone(module_cache));
+        }
 
         if !self.config.use_system_contracts() {
             match runtime.call_host_proof_of_stake(
@@ -314,6 +544,9 @@ impl Executor {
                     return ExecutionResult::Success {
                         effect: runtime.context().effect(),
                         cost: runtime.context().gas_counter(),
+                        trace: Vec::new(),
+                        events: runtime.take_events(),
+                        debug_log: Vec::new(),
                     }
                 }
                 Err(error) => {
@@ -321,6 +554,8 @@ impl Executor {
                         error: error.into(),
                         effect: effects_snapshot,
                         cost: runtime.context().gas_counter(),
+                        trace: Vec::new(),
+                        debug_log: Vec::new(),
                     }
                 }
             }
@@ -332,6 +567,9 @@ impl Executor {
                 return ExecutionResult::Success {
                     effect: runtime.context().effect(),
                     cost: runtime.context().gas_counter(),
+                    trace: Vec::new(),
+                    events: runtime.take_events(),
+                    debug_log: Vec::new(),
                 }
             }
         };
@@ -343,6 +581,9 @@ impl Executor {
                     return ExecutionResult::Success {
                         effect: runtime.context().effect(),
                         cost: runtime.context().gas_counter(),
+                        trace: Vec::new(),
+                        events: runtime.take_events(),
+                        debug_log: Vec::new(),
                     };
                 }
                 Error::Revert(status) => {
@@ -350,6 +591,8 @@ impl Executor {
                         error: Error::Revert(*status).into(),
                         effect: effects_snapshot,
                         cost: runtime.context().gas_counter(),
+                        trace: Vec::new(),
+                        debug_log: Vec::new(),
                     };
                 }
                 _ => {}
@@ -360,6 +603,8 @@ impl Executor {
             error: Error::Interpreter(error).into(),
             effect: effects_snapshot,
             cost: runtime.context().gas_counter(),
+            trace: Vec::new(),
+            debug_log: Vec::new(),
         }
     }
 
@@ -372,6 +617,8 @@ impl Executor {
         account: &'a Account,
         authorization_keys: BTreeSet<PublicKey>,
         blocktime: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
         deploy_hash: [u8; 32],
         gas_limit: Gas,
         address_generator: Rc<RefCell<AddressGenerator>>,
@@ -408,10 +655,13 @@ impl Executor {
             keys,
             access_rights,
             args,
+            RuntimeArgs::new(),
             authorization_keys,
             account,
             base_key,
             blocktime,
+            block_height,
+            proposer,
             deploy_hash,
             gas_limit,
             gas_counter,
@@ -423,7 +673,11 @@ impl Executor {
             protocol_data,
         );
 
-        let (instance, memory) = instance_and_memory(module.clone(), protocol_version)?;
+        let (instance, memory) = instance_and_memory(
+            module.clone(),
+            protocol_version,
+            protocol_data.wasm_costs().max_memory_pages,
+        )?;
 
         let runtime = Runtime::new(
             self.config,
@@ -445,6 +699,8 @@ impl Executor {
         account: &Account,
         authorization_keys: BTreeSet<PublicKey>,
         blocktime: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
         deploy_hash: [u8; 32],
         gas_limit: Gas,
         address_generator: Rc<RefCell<AddressGenerator>>,
@@ -468,6 +724,8 @@ impl Executor {
             account,
             authorization_keys,
             blocktime,
+            block_height,
+            proposer,
             deploy_hash,
             gas_limit,
             address_generator,
@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use blake2::{
+    digest::{Input, VariableOutput},
+    VarBlake2b,
+};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaChaRng;
+
+use types::Phase;
+
+const SEED_LENGTH: usize = 32;
+/// Distinguishes the seeds of the random streams from the seed of the `AddressGenerator`.
+const DOMAIN: &[u8] = b"random_bytes";
+
+fn seed_from(data: &[&[u8]]) -> [u8; SEED_LENGTH] {
+    let mut seed = [0u8; SEED_LENGTH];
+    let mut hasher = VarBlake2b::new(SEED_LENGTH).unwrap();
+    for bytes in data {
+        hasher.input(bytes);
+    }
+    hasher.variable_result(|hash| seed.clone_from_slice(hash));
+    seed
+}
+
+/// A [`RandomGenerator`] generates the random bytes returned to contracts.
+///
+/// Every call depth draws from its own ChaCha stream, seeded from the deploy hash, the [`Phase`]
+/// and the depth, so the bytes returned to a contract only depend on the deploy and on the calls
+/// made before it at the same depth.  The streams are separate from the one of the
+/// [`AddressGenerator`](super::AddressGenerator), so drawing random bytes never changes the
+/// addresses of the [`URef`](types::URef)s created by the deploy.
+pub struct RandomGenerator {
+    seed: [u8; SEED_LENGTH],
+    streams: BTreeMap<u32, ChaChaRng>,
+}
+
+impl RandomGenerator {
+    /// Creates a [`RandomGenerator`] from a 32-byte hash digest and [`Phase`].
+    pub fn new(hash: &[u8], phase: Phase) -> RandomGenerator {
+        let seed = seed_from(&[hash, &[phase as u8], DOMAIN]);
+        RandomGenerator {
+            seed,
+            streams: BTreeMap::new(),
+        }
+    }
+
+    /// Fills `dest` with the next bytes of the stream of the given call depth.
+    pub fn fill_bytes(&mut self, call_depth: u32, dest: &mut [u8]) {
+        let seed = self.seed;
+        self.streams
+            .entry(call_depth)
+            .or_insert_with(|| ChaChaRng::from_seed(seed_from(&[&seed, &call_depth.to_le_bytes()])))
+            .fill_bytes(dest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::Phase;
+
+    use super::RandomGenerator;
+    use crate::execution::AddressGenerator;
+
+    const DEPLOY_HASH_1: [u8; 32] = [1u8; 32];
+    const DEPLOY_HASH_2: [u8; 32] = [2u8; 32];
+
+    fn random_bytes(generator: &mut RandomGenerator, call_depth: u32) -> [u8; 32] {
+        let mut ret = [0u8; 32];
+        generator.fill_bytes(call_depth, &mut ret);
+        ret
+    }
+
+    #[test]
+    fn should_generate_same_bytes_for_same_seed() {
+        let mut rg_a = RandomGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        let mut rg_b = RandomGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+
+        assert_eq!(random_bytes(&mut rg_a, 0), random_bytes(&mut rg_b, 0));
+        assert_eq!(random_bytes(&mut rg_a, 1), random_bytes(&mut rg_b, 1));
+    }
+
+    #[test]
+    fn should_generate_different_bytes_for_different_seeds() {
+        let mut rg_a = RandomGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        let mut rg_b = RandomGenerator::new(&DEPLOY_HASH_2, Phase::Session);
+        let mut rg_c = RandomGenerator::new(&DEPLOY_HASH_1, Phase::Payment);
+
+        let random_a = random_bytes(&mut rg_a, 0);
+        assert_ne!(random_a, random_bytes(&mut rg_b, 0));
+        assert_ne!(random_a, random_bytes(&mut rg_c, 0));
+    }
+
+    #[test]
+    fn should_keep_separate_streams_per_call_depth() {
+        let mut rg_a = RandomGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        let mut rg_b = RandomGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+
+        // Drawing at another depth doesn't advance the stream of depth 0.
+        let random_a = random_bytes(&mut rg_a, 0);
+        let _ = random_bytes(&mut rg_b, 1);
+        assert_eq!(random_a, random_bytes(&mut rg_b, 0));
+        assert_ne!(random_a, random_bytes(&mut rg_a, 1));
+    }
+
+    #[test]
+    fn should_not_generate_uref_addresses() {
+        let mut rg = RandomGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        let mut ag = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+
+        assert_ne!(random_bytes(&mut rg, 0), ag.create_address());
+    }
+}
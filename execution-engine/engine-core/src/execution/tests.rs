@@ -15,6 +15,9 @@ fn on_fail_charge_test_helper<T>(
     ExecutionResult::Success {
         effect: Default::default(),
         cost: success_cost,
+        trace: Vec::new(),
+        events: Vec::new(),
+        debug_log: Vec::new(),
     }
 }
 
@@ -55,6 +58,9 @@ fn on_fail_charge_with_action() {
         ExecutionResult::Success {
             effect: Default::default(),
             cost: Gas::default(),
+            trace: Vec::new(),
+            events: Vec::new(),
+            debug_log: Vec::new(),
         }
     };
     match f() {
@@ -5,30 +5,41 @@ mod v1_resolver;
 
 use wasmi::ModuleImportResolver;
 
+use engine_wasm_prep::{wasm_costs::WasmCosts, Preprocessor};
 use types::ProtocolVersion;
 
-use self::error::ResolverError;
+use self::{error::ResolverError, v1_function_index::FunctionIndex};
 use crate::resolvers::memory_resolver::MemoryResolver;
 
 /// Creates a module resolver for given protocol version.
 ///
 /// * `protocol_version` Version of the protocol. Can't be lower than 1.
+/// * `max_memory_pages` Most memory pages a module can use, from the protocol's wasm costs.
 pub fn create_module_resolver(
     protocol_version: ProtocolVersion,
+    max_memory_pages: u32,
 ) -> Result<impl ModuleImportResolver + MemoryResolver, ResolverError> {
     // TODO: revisit how protocol_version check here is meant to combine with upgrade
     if protocol_version >= ProtocolVersion::V1_0_0 {
-        return Ok(v1_resolver::RuntimeModuleImportResolver::default());
+        return Ok(v1_resolver::RuntimeModuleImportResolver::new(
+            max_memory_pages,
+        ));
     }
     Err(ResolverError::UnknownProtocolVersion(protocol_version))
 }
 
+/// Creates a preprocessor which only accepts modules importing functions the module resolvers
+/// provide.
+pub fn create_preprocessor(wasm_costs: WasmCosts) -> Preprocessor {
+    Preprocessor::new(wasm_costs).with_host_functions(FunctionIndex::all().map(|func| func.name()))
+}
+
 #[test]
 fn resolve_invalid_module() {
-    assert!(create_module_resolver(ProtocolVersion::default()).is_err());
+    assert!(create_module_resolver(ProtocolVersion::default(), 64).is_err());
 }
 
 #[test]
 fn protocol_version_1_always_resolves() {
-    assert!(create_module_resolver(ProtocolVersion::V1_0_0).is_ok());
+    assert!(create_module_resolver(ProtocolVersion::V1_0_0, 64).is_ok());
 }
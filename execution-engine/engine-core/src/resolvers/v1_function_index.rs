@@ -3,6 +3,8 @@ use std::convert::TryFrom;
 use num_derive::{FromPrimitive, ToPrimitive};
 use num_traits::{FromPrimitive, ToPrimitive};
 
+use engine_shared::host_function_costs::{HostFunctionCost, HostFunctionCosts};
+
 #[derive(Debug, PartialEq, FromPrimitive, ToPrimitive)]
 #[repr(usize)]
 pub enum FunctionIndex {
@@ -43,10 +45,215 @@ pub enum FunctionIndex {
     GetMainPurseIndex,
     GetArgSizeFuncIndex,
     ReadHostBufferIndex,
-    #[cfg(feature = "test-support")]
+    EmitEventIndex,
+    Blake2bIndex,
+    Sha256Index,
+    Keccak256Index,
+    VerifyEd25519Index,
+    GetBlockHeightIndex,
+    GetProposerIndex,
+    GetDeployHashIndex,
+    RandomBytesIndex,
+    GrowMemoryIndex,
+    StoreContractIndex,
+    StoreContractAtHashIndex,
+    CallEntryPointIndex,
+    GetNamedArgSizeFuncIndex,
+    GetNamedArgFuncIndex,
     PrintIndex,
 }
 
+impl FunctionIndex {
+    /// Returns all the host functions.
+    pub fn all() -> impl Iterator<Item = FunctionIndex> {
+        (0..)
+            .map(FunctionIndex::try_from)
+            .take_while(Result::is_ok)
+            .filter_map(Result::ok)
+    }
+
+    /// Returns the name under which contracts import the function.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FunctionIndex::WriteFuncIndex => "write",
+            FunctionIndex::WriteLocalFuncIndex => "write_local",
+            FunctionIndex::ReadFuncIndex => "read_value",
+            FunctionIndex::ReadLocalFuncIndex => "read_value_local",
+            FunctionIndex::AddFuncIndex => "add",
+            FunctionIndex::AddLocalFuncIndex => "add_local",
+            FunctionIndex::NewFuncIndex => "new_uref",
+            FunctionIndex::RetFuncIndex => "ret",
+            FunctionIndex::CallContractFuncIndex => "call_contract",
+            FunctionIndex::GetArgFuncIndex => "get_arg",
+            FunctionIndex::GetKeyFuncIndex => "get_key",
+            FunctionIndex::GasFuncIndex => "gas",
+            FunctionIndex::HasKeyFuncIndex => "has_key",
+            FunctionIndex::PutKeyFuncIndex => "put_key",
+            FunctionIndex::StoreFnIndex => "store_function",
+            FunctionIndex::StoreFnAtHashIndex => "store_function_at_hash",
+            FunctionIndex::IsValidURefFnIndex => "is_valid_uref",
+            FunctionIndex::RevertFuncIndex => "revert",
+            FunctionIndex::AddAssociatedKeyFuncIndex => "add_associated_key",
+            FunctionIndex::RemoveAssociatedKeyFuncIndex => "remove_associated_key",
+            FunctionIndex::UpdateAssociatedKeyFuncIndex => "update_associated_key",
+            FunctionIndex::SetActionThresholdFuncIndex => "set_action_threshold",
+            FunctionIndex::LoadNamedKeysFuncIndex => "load_named_keys",
+            FunctionIndex::RemoveKeyFuncIndex => "remove_key",
+            FunctionIndex::GetCallerIndex => "get_caller",
+            FunctionIndex::GetBlocktimeIndex => "get_blocktime",
+            FunctionIndex::CreatePurseIndex => "create_purse",
+            FunctionIndex::TransferToAccountIndex => "transfer_to_account",
+            FunctionIndex::TransferFromPurseToAccountIndex => "transfer_from_purse_to_account",
+            FunctionIndex::TransferFromPurseToPurseIndex => "transfer_from_purse_to_purse",
+            FunctionIndex::GetBalanceIndex => "get_balance",
+            FunctionIndex::GetPhaseIndex => "get_phase",
+            FunctionIndex::UpgradeContractAtURefIndex => "upgrade_contract_at_uref",
+            FunctionIndex::GetSystemContractIndex => "get_system_contract",
+            FunctionIndex::GetMainPurseIndex => "get_main_purse",
+            FunctionIndex::GetArgSizeFuncIndex => "get_arg_size",
+            FunctionIndex::ReadHostBufferIndex => "read_host_buffer",
+            FunctionIndex::EmitEventIndex => "emit_event",
+            FunctionIndex::Blake2bIndex => "blake2b",
+            FunctionIndex::Sha256Index => "sha256",
+            FunctionIndex::Keccak256Index => "keccak256",
+            FunctionIndex::VerifyEd25519Index => "verify_ed25519",
+            FunctionIndex::GetBlockHeightIndex => "get_block_height",
+            FunctionIndex::GetProposerIndex => "get_proposer",
+            FunctionIndex::GetDeployHashIndex => "get_deploy_hash",
+            FunctionIndex::RandomBytesIndex => "random_bytes",
+            FunctionIndex::GrowMemoryIndex => engine_wasm_prep::GROW_MEMORY_FUNCTION,
+            FunctionIndex::StoreContractIndex => "store_contract",
+            FunctionIndex::StoreContractAtHashIndex => "store_contract_at_hash",
+            FunctionIndex::CallEntryPointIndex => "call_entry_point",
+            FunctionIndex::GetNamedArgSizeFuncIndex => "get_named_arg_size",
+            FunctionIndex::GetNamedArgFuncIndex => "get_named_arg",
+            FunctionIndex::PrintIndex => "print",
+        }
+    }
+
+    /// Returns what a call to the function costs under `costs`. Calls to `gas`, which is
+    /// injected by the metering itself, and to the test-only `print` are free. Calls to
+    /// `grow_memory` are charged per page grown by the runtime instead.
+    pub fn cost(&self, costs: &HostFunctionCosts) -> HostFunctionCost {
+        match self {
+            FunctionIndex::WriteFuncIndex => costs.write,
+            FunctionIndex::WriteLocalFuncIndex => costs.write_local,
+            FunctionIndex::ReadFuncIndex => costs.read_value,
+            FunctionIndex::ReadLocalFuncIndex => costs.read_value_local,
+            FunctionIndex::AddFuncIndex => costs.add,
+            FunctionIndex::AddLocalFuncIndex => costs.add_local,
+            FunctionIndex::NewFuncIndex => costs.new_uref,
+            FunctionIndex::RetFuncIndex => costs.ret,
+            FunctionIndex::CallContractFuncIndex | FunctionIndex::CallEntryPointIndex => {
+                costs.call_contract
+            }
+            FunctionIndex::GetArgFuncIndex | FunctionIndex::GetNamedArgFuncIndex => costs.get_arg,
+            FunctionIndex::GetKeyFuncIndex => costs.get_key,
+            FunctionIndex::HasKeyFuncIndex => costs.has_key,
+            FunctionIndex::PutKeyFuncIndex => costs.put_key,
+            FunctionIndex::StoreFnIndex | FunctionIndex::StoreContractIndex => costs.store_function,
+            FunctionIndex::StoreFnAtHashIndex | FunctionIndex::StoreContractAtHashIndex => {
+                costs.store_function_at_hash
+            }
+            FunctionIndex::IsValidURefFnIndex => costs.is_valid_uref,
+            FunctionIndex::RevertFuncIndex => costs.revert,
+            FunctionIndex::AddAssociatedKeyFuncIndex => costs.add_associated_key,
+            FunctionIndex::RemoveAssociatedKeyFuncIndex => costs.remove_associated_key,
+            FunctionIndex::UpdateAssociatedKeyFuncIndex => costs.update_associated_key,
+            FunctionIndex::SetActionThresholdFuncIndex => costs.set_action_threshold,
+            FunctionIndex::LoadNamedKeysFuncIndex => costs.load_named_keys,
+            FunctionIndex::RemoveKeyFuncIndex => costs.remove_key,
+            FunctionIndex::GetCallerIndex => costs.get_caller,
+            FunctionIndex::GetBlocktimeIndex => costs.get_blocktime,
+            FunctionIndex::CreatePurseIndex => costs.create_purse,
+            FunctionIndex::TransferToAccountIndex => costs.transfer_to_account,
+            FunctionIndex::TransferFromPurseToAccountIndex => costs.transfer_from_purse_to_account,
+            FunctionIndex::TransferFromPurseToPurseIndex => costs.transfer_from_purse_to_purse,
+            FunctionIndex::GetBalanceIndex => costs.get_balance,
+            FunctionIndex::GetPhaseIndex => costs.get_phase,
+            FunctionIndex::UpgradeContractAtURefIndex => costs.upgrade_contract_at_uref,
+            FunctionIndex::GetSystemContractIndex => costs.get_system_contract,
+            FunctionIndex::GetMainPurseIndex => costs.get_main_purse,
+            FunctionIndex::GetArgSizeFuncIndex | FunctionIndex::GetNamedArgSizeFuncIndex => {
+                costs.get_arg_size
+            }
+            FunctionIndex::ReadHostBufferIndex => costs.read_host_buffer,
+            FunctionIndex::EmitEventIndex => costs.emit_event,
+            FunctionIndex::Blake2bIndex => costs.blake2b,
+            FunctionIndex::Sha256Index => costs.sha256,
+            FunctionIndex::Keccak256Index => costs.keccak256,
+            FunctionIndex::VerifyEd25519Index => costs.verify_ed25519,
+            FunctionIndex::GetBlockHeightIndex => costs.get_block_height,
+            FunctionIndex::GetProposerIndex => costs.get_proposer,
+            FunctionIndex::GetDeployHashIndex => costs.get_deploy_hash,
+            FunctionIndex::RandomBytesIndex => costs.random_bytes,
+            FunctionIndex::PrintIndex => costs.print,
+            FunctionIndex::GasFuncIndex | FunctionIndex::GrowMemoryIndex => {
+                HostFunctionCost::default()
+            }
+        }
+    }
+
+    /// Returns the positions of the arguments which hold sizes of data passed to or requested from
+    /// the host, which are charged per byte.
+    pub fn size_args(&self) -> &'static [usize] {
+        match self {
+            FunctionIndex::WriteFuncIndex
+            | FunctionIndex::WriteLocalFuncIndex
+            | FunctionIndex::AddFuncIndex
+            | FunctionIndex::AddLocalFuncIndex
+            | FunctionIndex::CallContractFuncIndex
+            | FunctionIndex::GetKeyFuncIndex
+            | FunctionIndex::PutKeyFuncIndex
+            | FunctionIndex::StoreFnIndex
+            | FunctionIndex::StoreFnAtHashIndex
+            | FunctionIndex::StoreContractIndex
+            | FunctionIndex::StoreContractAtHashIndex
+            | FunctionIndex::GetNamedArgFuncIndex
+            | FunctionIndex::TransferToAccountIndex
+            | FunctionIndex::UpgradeContractAtURefIndex
+            | FunctionIndex::EmitEventIndex => &[1, 3],
+            FunctionIndex::ReadFuncIndex
+            | FunctionIndex::ReadLocalFuncIndex
+            | FunctionIndex::RetFuncIndex
+            | FunctionIndex::HasKeyFuncIndex
+            | FunctionIndex::IsValidURefFnIndex
+            | FunctionIndex::AddAssociatedKeyFuncIndex
+            | FunctionIndex::RemoveAssociatedKeyFuncIndex
+            | FunctionIndex::UpdateAssociatedKeyFuncIndex
+            | FunctionIndex::RemoveKeyFuncIndex
+            | FunctionIndex::CreatePurseIndex
+            | FunctionIndex::GetBalanceIndex
+            | FunctionIndex::ReadHostBufferIndex
+            | FunctionIndex::Blake2bIndex
+            | FunctionIndex::Sha256Index
+            | FunctionIndex::Keccak256Index
+            | FunctionIndex::RandomBytesIndex
+            | FunctionIndex::GetNamedArgSizeFuncIndex
+            | FunctionIndex::PrintIndex => &[1],
+            FunctionIndex::NewFuncIndex
+            | FunctionIndex::GetArgFuncIndex
+            | FunctionIndex::GetSystemContractIndex => &[2],
+            FunctionIndex::RevertFuncIndex
+            | FunctionIndex::SetActionThresholdFuncIndex
+            | FunctionIndex::LoadNamedKeysFuncIndex
+            | FunctionIndex::GetCallerIndex
+            | FunctionIndex::GetBlocktimeIndex
+            | FunctionIndex::GetPhaseIndex
+            | FunctionIndex::GetMainPurseIndex
+            | FunctionIndex::GetArgSizeFuncIndex
+            | FunctionIndex::GetBlockHeightIndex
+            | FunctionIndex::GetProposerIndex
+            | FunctionIndex::GetDeployHashIndex => &[],
+            FunctionIndex::TransferFromPurseToAccountIndex
+            | FunctionIndex::TransferFromPurseToPurseIndex
+            | FunctionIndex::VerifyEd25519Index
+            | FunctionIndex::CallEntryPointIndex => &[1, 3, 5],
+            FunctionIndex::GasFuncIndex | FunctionIndex::GrowMemoryIndex => &[],
+        }
+    }
+}
+
 impl Into<usize> for FunctionIndex {
     fn into(self) -> usize {
         // NOTE: This can't fail as `FunctionIndex` is represented by usize,
@@ -14,11 +14,12 @@ pub struct RuntimeModuleImportResolver {
     max_memory: u32,
 }
 
-impl Default for RuntimeModuleImportResolver {
-    fn default() -> Self {
+impl RuntimeModuleImportResolver {
+    /// Creates a resolver providing a memory of at most `max_memory` pages.
+    pub fn new(max_memory: u32) -> Self {
         RuntimeModuleImportResolver {
             memory: RefCell::new(None),
-            max_memory: 64,
+            max_memory,
         }
     }
 }
@@ -188,7 +189,66 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
                 FunctionIndex::ReadHostBufferIndex.into(),
             ),
-            #[cfg(feature = "test-support")]
+            "emit_event" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 4][..], None),
+                FunctionIndex::EmitEventIndex.into(),
+            ),
+            "blake2b" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 3][..], None),
+                FunctionIndex::Blake2bIndex.into(),
+            ),
+            "sha256" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 3][..], None),
+                FunctionIndex::Sha256Index.into(),
+            ),
+            "keccak256" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 3][..], None),
+                FunctionIndex::Keccak256Index.into(),
+            ),
+            "verify_ed25519" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
+                FunctionIndex::VerifyEd25519Index.into(),
+            ),
+            "get_block_height" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], None),
+                FunctionIndex::GetBlockHeightIndex.into(),
+            ),
+            "get_proposer" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
+                FunctionIndex::GetProposerIndex.into(),
+            ),
+            "get_deploy_hash" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], None),
+                FunctionIndex::GetDeployHashIndex.into(),
+            ),
+            "random_bytes" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 2][..], None),
+                FunctionIndex::RandomBytesIndex.into(),
+            ),
+            "grow_memory" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
+                FunctionIndex::GrowMemoryIndex.into(),
+            ),
+            "store_contract" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 5][..], None),
+                FunctionIndex::StoreContractIndex.into(),
+            ),
+            "store_contract_at_hash" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 5][..], None),
+                FunctionIndex::StoreContractAtHashIndex.into(),
+            ),
+            "call_entry_point" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 7][..], Some(ValueType::I32)),
+                FunctionIndex::CallEntryPointIndex.into(),
+            ),
+            "get_named_arg_size" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
+                FunctionIndex::GetNamedArgSizeFuncIndex.into(),
+            ),
+            "get_named_arg" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
+                FunctionIndex::GetNamedArgFuncIndex.into(),
+            ),
             "print" => FuncInstance::alloc_host(
                 Signature::new(&[ValueType::I32; 2][..], None),
                 FunctionIndex::PrintIndex.into(),
@@ -209,16 +269,20 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
         descriptor: &MemoryDescriptor,
     ) -> Result<MemoryRef, InterpreterError> {
         if field_name == "memory" {
-            let effective_max = descriptor.maximum().unwrap_or(self.max_memory + 1);
-            if descriptor.initial() > self.max_memory || effective_max > self.max_memory {
+            if descriptor.initial() > self.max_memory {
                 Err(InterpreterError::Instantiation(
                     "Module requested too much memory".to_owned(),
                 ))
             } else {
+                // A module stored under an earlier protocol version may declare a larger maximum,
+                // so the maximum is capped rather than rejected.
+                let maximum = descriptor
+                    .maximum()
+                    .map_or(self.max_memory, |maximum| maximum.min(self.max_memory));
                 // Note: each "page" is 64 KiB
                 let mem = MemoryInstance::alloc(
                     Pages(descriptor.initial() as usize),
-                    descriptor.maximum().map(|x| Pages(x as usize)),
+                    Some(Pages(maximum as usize)),
                 )?;
                 *self.memory.borrow_mut() = Some(mem.clone());
                 Ok(mem)
@@ -0,0 +1,96 @@
+use ed25519_dalek::{PublicKey as Ed25519PublicKey, Signature as Ed25519Signature};
+use sha2::{Digest, Sha256};
+use tiny_keccak::{Hasher, Keccak};
+
+use engine_shared::newtypes::Blake2bHash;
+
+/// The number of bytes in a digest returned by any of the hashing host functions.
+pub const HASH_LENGTH: usize = 32;
+
+/// Returns the 32-byte BLAKE2b digest of `data`.
+pub fn blake2b(data: &[u8]) -> [u8; HASH_LENGTH] {
+    Blake2bHash::new(data).value()
+}
+
+/// Returns the SHA-256 digest of `data`.
+pub fn sha256(data: &[u8]) -> [u8; HASH_LENGTH] {
+    let mut ret = [0u8; HASH_LENGTH];
+    ret.copy_from_slice(&Sha256::digest(data));
+    ret
+}
+
+/// Returns the Keccak-256 digest of `data`, as used by Ethereum.
+pub fn keccak256(data: &[u8]) -> [u8; HASH_LENGTH] {
+    let mut ret = [0u8; HASH_LENGTH];
+    let mut hasher = Keccak::v256();
+    hasher.update(data);
+    hasher.finalize(&mut ret);
+    ret
+}
+
+/// Checks that `signature` is a valid Ed25519 signature of `message` made with the key pair of
+/// `public_key`.  Malformed keys or signatures are treated as invalid.
+pub fn verify_ed25519(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let public_key = match Ed25519PublicKey::from_bytes(public_key) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    let signature = match Ed25519Signature::from_bytes(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    public_key.verify(message, &signature).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(hex: &str) -> Vec<u8> {
+        base16::decode(hex).expect("should decode hex")
+    }
+
+    // Test 1 from RFC 8032, section 7.1
+    const ED25519_PUBLIC_KEY: &str =
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    const ED25519_SIGNATURE: &str =
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+
+    #[test]
+    fn should_hash_empty_input() {
+        assert_eq!(
+            blake2b(&[]).to_vec(),
+            decode("0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8")
+        );
+        assert_eq!(
+            sha256(&[]).to_vec(),
+            decode("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+        );
+        assert_eq!(
+            keccak256(&[]).to_vec(),
+            decode("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+        );
+    }
+
+    #[test]
+    fn should_verify_valid_ed25519_signature() {
+        let public_key = decode(ED25519_PUBLIC_KEY);
+        let signature = decode(ED25519_SIGNATURE);
+        assert!(verify_ed25519(&public_key, &[], &signature));
+    }
+
+    #[test]
+    fn should_not_verify_signature_of_other_message() {
+        let public_key = decode(ED25519_PUBLIC_KEY);
+        let signature = decode(ED25519_SIGNATURE);
+        assert!(!verify_ed25519(&public_key, b"other message", &signature));
+    }
+
+    #[test]
+    fn should_not_verify_malformed_input() {
+        let public_key = decode(ED25519_PUBLIC_KEY);
+        let signature = decode(ED25519_SIGNATURE);
+        assert!(!verify_ed25519(&public_key[1..], &[], &signature));
+        assert!(!verify_ed25519(&public_key, &[], &signature[1..]));
+    }
+}
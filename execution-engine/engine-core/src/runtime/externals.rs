@@ -6,13 +6,13 @@ use types::{
     account::PublicKey,
     api_error,
     bytesrepr::{self, ToBytes},
-    Key, TransferredTo, U512,
+    EntryPoints, Key, TransferredTo, DEFAULT_ENTRY_POINT, U512,
 };
 
 use engine_shared::{gas::Gas, stored_value::StoredValue};
 use engine_storage::global_state::StateReader;
 
-use super::{args::Args, Error, Runtime};
+use super::{args::Args, crypto, Error, Runtime};
 use crate::resolvers::v1_function_index::FunctionIndex;
 
 impl<'a, R> Externals for Runtime<'a, R>
@@ -26,6 +26,10 @@ where
         args: RuntimeArgs,
     ) -> Result<Option<RuntimeValue>, Trap> {
         let func = FunctionIndex::try_from(index).expect("unknown function index");
+        if let Some(ref tracer) = self.tracer {
+            tracer.borrow_mut().record_host_function(func.name());
+        }
+        self.charge_host_function_call(&func, &args)?;
         match func {
             FunctionIndex::ReadFuncIndex => {
                 // args(0) = pointer to key in Wasm memory
@@ -119,6 +123,27 @@ where
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
+            FunctionIndex::GetNamedArgSizeFuncIndex => {
+                // args(0) = pointer to name of host runtime arg to load
+                // args(1) = size of the name
+                // args(2) = pointer to a argument size (output)
+                let (name_ptr, name_size, size_ptr) = Args::parse(args)?;
+                let name = self.string_from_mem(name_ptr, name_size)?;
+                let ret = self.get_named_arg_size(&name, size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetNamedArgFuncIndex => {
+                // args(0) = pointer to name of host runtime arg to load
+                // args(1) = size of the name
+                // args(2) = pointer to destination in Wasm memory
+                // args(3) = size of destination pointer memory
+                let (name_ptr, name_size, dest_ptr, dest_size): (_, _, _, u32) = Args::parse(args)?;
+                let name = self.string_from_mem(name_ptr, name_size)?;
+                let ret = self.get_named_arg(&name, dest_ptr, dest_size as usize)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
             FunctionIndex::RetFuncIndex => {
                 // args(0) = pointer to value
                 // args(1) = size of value
@@ -139,8 +164,43 @@ where
                 let key_contract: Key = self.key_from_mem(key_ptr, key_size)?;
                 let args_bytes: Vec<u8> = self.bytes_from_mem(args_ptr, args_size as usize)?;
 
-                let ret =
-                    self.call_contract_host_buffer(key_contract, args_bytes, result_size_ptr)?;
+                let ret = self.call_contract_host_buffer(
+                    key_contract,
+                    DEFAULT_ENTRY_POINT,
+                    args_bytes,
+                    result_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::CallEntryPointIndex => {
+                // args(0) = pointer to key where contract is at in global state
+                // args(1) = size of key
+                // args(2) = pointer to entry point name in Wasm memory
+                // args(3) = size of entry point name
+                // args(4) = pointer to function arguments in Wasm memory
+                // args(5) = size of arguments
+                // args(6) = pointer to result size (output)
+                let (
+                    key_ptr,
+                    key_size,
+                    entry_point_ptr,
+                    entry_point_size,
+                    args_ptr,
+                    args_size,
+                    result_size_ptr,
+                ): (_, _, _, _, _, u32, _) = Args::parse(args)?;
+
+                let key_contract: Key = self.key_from_mem(key_ptr, key_size)?;
+                let entry_point = self.string_from_mem(entry_point_ptr, entry_point_size)?;
+                let args_bytes: Vec<u8> = self.bytes_from_mem(args_ptr, args_size as usize)?;
+
+                let ret = self.call_contract_host_buffer(
+                    key_contract,
+                    &entry_point,
+                    args_bytes,
+                    result_size_ptr,
+                )?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
@@ -207,12 +267,48 @@ where
                 Ok(None)
             }
 
+            FunctionIndex::GetBlockHeightIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.get_block_height(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::GetProposerIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                let ret = self.get_proposer(dest_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetDeployHashIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.get_deploy_hash(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::RandomBytesIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                // args(1) = number of random bytes to write
+                let (dest_ptr, dest_size) = Args::parse(args)?;
+                self.random_bytes(dest_ptr, dest_size)?;
+                Ok(None)
+            }
+
             FunctionIndex::GasFuncIndex => {
                 let gas_arg: u32 = Args::parse(args)?;
                 self.gas(Gas::new(gas_arg.into()))?;
                 Ok(None)
             }
 
+            FunctionIndex::GrowMemoryIndex => {
+                // args(0) = number of pages to grow the memory by
+                let pages: u32 = Args::parse(args)?;
+                let ret = self.grow_memory(pages)?;
+                Ok(Some(RuntimeValue::I32(ret)))
+            }
+
             FunctionIndex::StoreFnIndex => {
                 // args(0) = pointer to function name in Wasm memory
                 // args(1) = size of the name
@@ -229,7 +325,7 @@ where
                     .get(urefs_ptr, urefs_size as usize)
                     .map_err(Error::Interpreter)?;
                 let urefs = bytesrepr::deserialize(uref_bytes).map_err(Error::BytesRepr)?;
-                let contract_hash = self.store_function(fn_bytes, urefs)?;
+                let contract_hash = self.store_function(fn_bytes, urefs, EntryPoints::new())?;
                 self.function_address(contract_hash, hash_ptr)?;
                 Ok(None)
             }
@@ -250,7 +346,46 @@ where
                     .get(urefs_ptr, urefs_size as usize)
                     .map_err(Error::Interpreter)?;
                 let urefs = bytesrepr::deserialize(uref_bytes).map_err(Error::BytesRepr)?;
-                let contract_hash = self.store_function_at_hash(fn_bytes, urefs)?;
+                let contract_hash =
+                    self.store_function_at_hash(fn_bytes, urefs, EntryPoints::new())?;
+                self.function_address(contract_hash, hash_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::StoreContractIndex => {
+                // args(0) = pointer to serialized entry points in Wasm memory
+                // args(1) = size of the entry points
+                // args(2) = pointer to additional unforgable names
+                //           to be saved with the contract body
+                // args(3) = size of the additional unforgable names
+                // args(4) = pointer to a Wasm memory where we will save
+                //           uref address of the new contract
+                let (entry_points_ptr, entry_points_size, urefs_ptr, urefs_size, hash_ptr) =
+                    Args::parse(args)?;
+                let entry_points: EntryPoints =
+                    self.t_from_mem(entry_points_ptr, entry_points_size)?;
+                let fn_bytes = self.get_functions_by_entry_points(&entry_points)?;
+                let urefs = self.t_from_mem(urefs_ptr, urefs_size)?;
+                let contract_hash = self.store_function(fn_bytes, urefs, entry_points)?;
+                self.function_address(contract_hash, hash_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::StoreContractAtHashIndex => {
+                // args(0) = pointer to serialized entry points in Wasm memory
+                // args(1) = size of the entry points
+                // args(2) = pointer to additional unforgable names
+                //           to be saved with the contract body
+                // args(3) = size of the additional unforgable names
+                // args(4) = pointer to a Wasm memory where we will save
+                //           hash of the new contract
+                let (entry_points_ptr, entry_points_size, urefs_ptr, urefs_size, hash_ptr) =
+                    Args::parse(args)?;
+                let entry_points: EntryPoints =
+                    self.t_from_mem(entry_points_ptr, entry_points_size)?;
+                let fn_bytes = self.get_functions_by_entry_points(&entry_points)?;
+                let urefs = self.t_from_mem(urefs_ptr, urefs_size)?;
+                let contract_hash = self.store_function_at_hash(fn_bytes, urefs, entry_points)?;
                 self.function_address(contract_hash, hash_ptr)?;
                 Ok(None)
             }
@@ -449,7 +584,56 @@ where
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
-            #[cfg(feature = "test-support")]
+            FunctionIndex::EmitEventIndex => {
+                // args(0) = pointer to the serialized topic in Wasm memory
+                // args(1) = size of the serialized topic
+                // args(2) = pointer to the serialized payload in Wasm memory
+                // args(3) = size of the serialized payload
+                let (topic_ptr, topic_size, payload_ptr, payload_size) = Args::parse(args)?;
+                self.emit_event(topic_ptr, topic_size, payload_ptr, payload_size)?;
+                Ok(None)
+            }
+
+            FunctionIndex::Blake2bIndex => {
+                // args(0) = pointer to input in Wasm memory
+                // args(1) = size of input
+                // args(2) = pointer to Wasm memory where to write the 32-byte digest
+                let (in_ptr, in_size, out_ptr) = Args::parse(args)?;
+                self.hash(crypto::blake2b, in_ptr, in_size, out_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::Sha256Index => {
+                // args(0) = pointer to input in Wasm memory
+                // args(1) = size of input
+                // args(2) = pointer to Wasm memory where to write the 32-byte digest
+                let (in_ptr, in_size, out_ptr) = Args::parse(args)?;
+                self.hash(crypto::sha256, in_ptr, in_size, out_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::Keccak256Index => {
+                // args(0) = pointer to input in Wasm memory
+                // args(1) = size of input
+                // args(2) = pointer to Wasm memory where to write the 32-byte digest
+                let (in_ptr, in_size, out_ptr) = Args::parse(args)?;
+                self.hash(crypto::keccak256, in_ptr, in_size, out_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::VerifyEd25519Index => {
+                // args(0) = pointer to public key bytes in Wasm memory
+                // args(1) = size of public key bytes
+                // args(2) = pointer to message in Wasm memory
+                // args(3) = size of message
+                // args(4) = pointer to signature bytes in Wasm memory
+                // args(5) = size of signature bytes
+                let (pk_ptr, pk_size, msg_ptr, msg_size, sig_ptr, sig_size) = Args::parse(args)?;
+                let ret =
+                    self.verify_ed25519(pk_ptr, pk_size, msg_ptr, msg_size, sig_ptr, sig_size)?;
+                Ok(Some(RuntimeValue::I32(ret)))
+            }
+
             FunctionIndex::PrintIndex => {
                 let (text_ptr, text_size) = Args::parse(args)?;
                 self.print(text_ptr, text_size)?;
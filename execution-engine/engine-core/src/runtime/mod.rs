@@ -1,19 +1,25 @@
 mod args;
+mod crypto;
 mod externals;
 mod mint_internal;
 mod proof_of_stake_internal;
 mod standard_payment_internal;
 
 use std::{
+    cell::RefCell,
     cmp,
     collections::{BTreeMap, HashMap, HashSet},
     convert::TryFrom,
     iter::IntoIterator,
+    rc::Rc,
 };
 
 use itertools::Itertools;
 use parity_wasm::elements::Module;
-use wasmi::{ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, Trap, TrapKind};
+use wasmi::{
+    memory_units::Pages, ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, RuntimeArgs, Trap,
+    TrapKind,
+};
 
 use ::mint::Mint;
 use contract::args_parser::ArgsParser;
@@ -26,25 +32,39 @@ use types::{
     bytesrepr::{self, FromBytes, ToBytes},
     system_contract_errors,
     system_contract_errors::mint,
-    AccessRights, ApiError, CLType, CLTyped, CLValue, Key, ProtocolVersion, SystemContractType,
-    TransferResult, TransferredTo, URef, U128, U256, U512,
+    AccessRights, ApiError, CLType, CLTyped, CLValue, EntryPoints, Key, ProtocolVersion,
+    RuntimeArgs, SystemContractType, TransferResult, TransferredTo, URef, DEFAULT_ENTRY_POINT,
+    U128, U256, U512,
 };
 
 use crate::{
-    engine_state::{system_contract_cache::SystemContractCache, EngineConfig},
-    execution::{Error, MINT_NAME, POS_NAME},
-    resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
+    engine_state::{
+        call_trace::CallTracer, contract_event::ContractEvent, debug_log::DebugLog,
+        module_cache::ModuleCache, system_contract_cache::SystemContractCache, EngineConfig,
+    },
+    execution::{Error, RandomGenerator, MINT_NAME, POS_NAME},
+    resolvers::{
+        create_module_resolver, memory_resolver::MemoryResolver, v1_function_index::FunctionIndex,
+    },
     runtime_context::RuntimeContext,
+    tracking_copy::byte_size::ByteSize,
     Address,
 };
 
 pub struct Runtime<'a, R> {
     system_contract_cache: SystemContractCache,
+    module_cache: Option<ModuleCache>,
     config: EngineConfig,
     memory: MemoryRef,
     module: Module,
     host_buffer: Option<CLValue>,
     context: RuntimeContext<'a, R>,
+    tracer: Option<Rc<RefCell<CallTracer>>>,
+    events: Rc<RefCell<Vec<ContractEvent>>>,
+    debug_log: Option<Rc<RefCell<DebugLog>>>,
+    random_generator: Rc<RefCell<RandomGenerator>>,
+    // Number of contract calls between the deploy and the running contract
+    call_depth: u32,
 }
 
 /// Rename function called `name` in the `module` to `call`.
@@ -63,12 +83,68 @@ pub fn rename_export_to_call(module: &mut Module, name: String) {
     main_export.push_str("call");
 }
 
+/// Prepares the `module` of a stored contract to be called at `entry_point` with `args`.
+///
+/// The args are checked against the parameters of the entry point declared in `entry_points`, and
+/// the function exported under the entry point's name is exported as `call` instead. Contracts
+/// declaring no entry points can only be called at `call`, with any args.
+pub fn select_entry_point(
+    module: &mut Module,
+    entry_points: &EntryPoints,
+    entry_point: &str,
+    args: &[CLValue],
+) -> Result<(), Error> {
+    if entry_points.is_empty() {
+        if entry_point == DEFAULT_ENTRY_POINT {
+            return Ok(());
+        }
+        return Err(Error::EntryPointNotFound(entry_point.to_string()));
+    }
+
+    let declared = entry_points
+        .get(entry_point)
+        .ok_or_else(|| Error::EntryPointNotFound(entry_point.to_string()))?;
+    if !declared.accepts(args) {
+        return Err(Error::InvalidEntryPointArgs {
+            entry_point: entry_point.to_string(),
+            expected: declared
+                .parameters()
+                .iter()
+                .map(|parameter| parameter.cl_type().clone())
+                .collect(),
+            found: args.iter().map(|arg| arg.cl_type().clone()).collect(),
+        });
+    }
+
+    let entries = module
+        .export_section_mut()
+        .ok_or_else(|| Error::EntryPointNotFound(entry_point.to_string()))?
+        .entries_mut();
+    if entry_point != DEFAULT_ENTRY_POINT {
+        entries.retain(|export_entry| export_entry.field() != DEFAULT_ENTRY_POINT);
+    }
+    let export_entry = entries
+        .iter_mut()
+        .find(|export_entry| export_entry.field() == entry_point)
+        .ok_or_else(|| Error::EntryPointNotFound(entry_point.to_string()))?;
+    *export_entry.field_mut() = DEFAULT_ENTRY_POINT.to_string();
+    Ok(())
+}
+
+/// Removes all code from `module` which is not reachable from the functions exported under
+/// `names`.  This only fails if `module` has no exports at all.
+fn strip_to_exports(module: &mut Module, names: Vec<&str>) -> Result<(), Error> {
+    pwasm_utils::optimize(module, names.clone())
+        .map_err(|_| Error::FunctionNotFound(names.join(", ")))
+}
+
 pub fn instance_and_memory(
     parity_module: Module,
     protocol_version: ProtocolVersion,
+    max_memory_pages: u32,
 ) -> Result<(ModuleRef, MemoryRef), Error> {
     let module = wasmi::Module::from_parity_wasm_module(parity_module)?;
-    let resolver = create_module_resolver(protocol_version)?;
+    let resolver = create_module_resolver(protocol_version, max_memory_pages)?;
     let mut imports = ImportsBuilder::new();
     imports.push_resolver("env", &resolver);
     let not_started_module = ModuleInstance::new(&module, &imports)?;
@@ -1351,16 +1427,48 @@ where
         module: Module,
         context: RuntimeContext<'a, R>,
     ) -> Self {
+        let random_generator = Rc::new(RefCell::new(RandomGenerator::new(
+            &context.get_deployhash(),
+            context.phase(),
+        )));
         Runtime {
             config,
             system_contract_cache,
+            module_cache: None,
             memory,
             module,
             host_buffer: None,
             context,
+            tracer: None,
+            events: Rc::new(RefCell::new(Vec::new())),
+            debug_log: None,
+            random_generator,
+            call_depth: 0,
         }
     }
 
+    /// Records the calls made by this runtime, and by the runtimes of nested calls, in `tracer`.
+    pub(crate) fn set_tracer(&mut self, tracer: Rc<RefCell<CallTracer>>) {
+        self.tracer = Some(tracer);
+    }
+
+    /// Collects the messages printed by this runtime, and by the runtimes of nested calls, in
+    /// `debug_log`.  They are discarded if no debug log is set.
+    pub(crate) fn set_debug_log(&mut self, debug_log: Rc<RefCell<DebugLog>>) {
+        self.debug_log = Some(debug_log);
+    }
+
+    /// Looks up the modules of stored contracts called by this runtime, and by the runtimes of
+    /// nested calls, in `module_cache`.
+    pub(crate) fn set_module_cache(&mut self, module_cache: ModuleCache) {
+        self.module_cache = Some(module_cache);
+    }
+
+    /// Takes the events emitted so far by this runtime and the runtimes of nested calls.
+    pub fn take_events(&self) -> Vec<ContractEvent> {
+        self.events.replace(Vec::new())
+    }
+
     pub fn memory(&self) -> &MemoryRef {
         &self.memory
     }
@@ -1403,6 +1511,55 @@ where
         }
     }
 
+    /// Grows the memory by `pages` pages at the `grow_mem` cost per page, returning the previous
+    /// size in pages or -1 if the memory can't grow. Growing past the memory limit of the current
+    /// protocol version is an error.
+    fn grow_memory(&mut self, pages: u32) -> Result<i32, Trap> {
+        let wasm_costs = *self.context.protocol_data().wasm_costs();
+        let current_pages = self.memory.current_size().0 as u32;
+        if current_pages.saturating_add(pages) > wasm_costs.max_memory_pages {
+            return Err(Error::MemoryLimitExceeded {
+                max_memory_pages: wasm_costs.max_memory_pages,
+            }
+            .into());
+        }
+        self.gas(Gas::new(U512::from(wasm_costs.grow_mem) * pages))?;
+        match self.memory.grow(Pages(pages as usize)) {
+            Ok(Pages(previous_pages)) => Ok(previous_pages as i32),
+            Err(_) => Ok(-1),
+        }
+    }
+
+    /// Charges gas for `byte_size` bytes added to global state at the storage cost of the cost
+    /// table.
+    fn charge_storage(&mut self, byte_size: usize) -> Result<(), Error> {
+        let cost_per_byte = self.context.protocol_data().wasm_costs().storage_per_byte;
+        if self.charge_gas(Gas::new(U512::from(byte_size) * cost_per_byte)) {
+            Ok(())
+        } else {
+            Err(Error::GasLimit)
+        }
+    }
+
+    /// Charges the cost of calling `func` with `args` from the host function costs of the current
+    /// protocol version.
+    fn charge_host_function_call(
+        &mut self,
+        func: &FunctionIndex,
+        args: &RuntimeArgs,
+    ) -> Result<(), Trap> {
+        let cost = func.cost(self.context.protocol_data().host_function_costs());
+        if cost.base == 0 && cost.per_byte == 0 {
+            return Ok(());
+        }
+        let mut bytes = U512::zero();
+        for index in func.size_args() {
+            bytes += U512::from(args.nth_checked::<u32>(*index)?);
+        }
+        let amount = U512::from(cost.base) + U512::from(cost.per_byte) * bytes;
+        self.gas(Gas::new(amount))
+    }
+
     fn bytes_from_mem(&self, ptr: u32, size: usize) -> Result<Vec<u8>, Error> {
         self.memory.get(ptr, size).map_err(Into::into)
     }
@@ -1413,6 +1570,12 @@ where
         bytesrepr::deserialize(bytes).map_err(Into::into)
     }
 
+    /// Reads a value of type `T` (defined as `ptr` and `size` tuple) from Wasm memory.
+    fn t_from_mem<T: FromBytes>(&self, ptr: u32, size: u32) -> Result<T, Error> {
+        let bytes = self.bytes_from_mem(ptr, size as usize)?;
+        bytesrepr::deserialize(bytes).map_err(Into::into)
+    }
+
     /// Reads `CLValue` (defined as `cl_value_ptr` and `cl_value_size` tuple) from Wasm memory.
     fn cl_value_from_mem(
         &mut self,
@@ -1444,9 +1607,8 @@ where
 
         if has_name {
             let mut module = self.module.clone();
-            // We only want the function exported under `name` to be callable; `optimize` removes
-            // all code that is not reachable from the exports listed in the second argument.
-            pwasm_utils::optimize(&mut module, vec![&name]).unwrap();
+            // We only want the function exported under `name` to be callable.
+            strip_to_exports(&mut module, vec![&name])?;
             rename_export_to_call(&mut module, name);
 
             parity_wasm::serialize(module).map_err(|e| Error::ParityWasm(e).into())
@@ -1455,6 +1617,45 @@ where
         }
     }
 
+    /// Returns the module stripped of all code not reachable from the functions exported under
+    /// the names of `entry_points`, which keep their names.
+    fn get_functions_by_entry_points(
+        &mut self,
+        entry_points: &EntryPoints,
+    ) -> Result<Vec<u8>, Trap> {
+        if entry_points.is_empty() {
+            return Err(Error::FunctionNotFound(String::from("no entry points declared")).into());
+        }
+
+        let missing_export = {
+            let exports: Vec<&str> = self
+                .module
+                .export_section()
+                .map(|export_section| {
+                    export_section
+                        .entries()
+                        .iter()
+                        .map(|export_entry| export_entry.field())
+                        .collect()
+                })
+                .unwrap_or_default();
+            entry_points
+                .keys()
+                .find(|name| !exports.contains(&name.as_str()))
+                .cloned()
+        };
+        if let Some(name) = missing_export {
+            return Err(Error::FunctionNotFound(name).into());
+        }
+
+        let mut module = self.module.clone();
+        strip_to_exports(
+            &mut module,
+            entry_points.keys().map(String::as_str).collect(),
+        )?;
+        parity_wasm::serialize(module).map_err(|e| Error::ParityWasm(e).into())
+    }
+
     fn is_valid_uref(&mut self, uref_ptr: u32, uref_size: u32) -> Result<bool, Trap> {
         let bytes = self.bytes_from_mem(uref_ptr, uref_size as usize)?;
         let uref: URef = bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?;
@@ -1504,6 +1705,50 @@ where
         Ok(Ok(()))
     }
 
+    fn get_named_arg_size(
+        &mut self,
+        name: &str,
+        size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let arg_size = match self.context.named_args().get(name) {
+            Some(arg) if arg.inner_bytes().len() > u32::max_value() as usize => {
+                return Ok(Err(ApiError::OutOfMemory))
+            }
+            None => return Ok(Err(ApiError::MissingArgument)),
+            Some(arg) => arg.inner_bytes().len() as u32,
+        };
+
+        let arg_size_bytes = arg_size.to_le_bytes(); // Wasm is little-endian
+
+        if let Err(e) = self.memory.set(size_ptr, &arg_size_bytes) {
+            return Err(Error::Interpreter(e).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    fn get_named_arg(
+        &mut self,
+        name: &str,
+        output_ptr: u32,
+        output_size: usize,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let arg = match self.context.named_args().get(name) {
+            Some(arg) => arg,
+            None => return Ok(Err(ApiError::MissingArgument)),
+        };
+
+        if arg.inner_bytes().len() > output_size {
+            return Ok(Err(ApiError::OutOfMemory));
+        }
+
+        if let Err(e) = self.memory.set(output_ptr, arg.inner_bytes()) {
+            return Err(Error::Interpreter(e).into());
+        }
+
+        Ok(Ok(()))
+    }
+
     /// Load the uref known by the given name into the Wasm memory
     fn load_key(
         &mut self,
@@ -1564,6 +1809,7 @@ where
     ) -> Result<(), Trap> {
         let name = self.string_from_mem(name_ptr, name_size)?;
         let key = self.key_from_mem(key_ptr, key_size)?;
+        self.charge_storage(name.byte_size() + key.byte_size())?;
         self.context.put_key(name, key).map_err(Into::into)
     }
 
@@ -1626,6 +1872,40 @@ where
             .map_err(|e| Error::Interpreter(e).into())
     }
 
+    /// Writes current block height to [dest_ptr] in Wasm memory.
+    fn get_block_height(&self, dest_ptr: u32) -> Result<(), Trap> {
+        let block_height = self
+            .context
+            .get_block_height()
+            .into_bytes()
+            .map_err(Error::BytesRepr)?;
+        self.memory
+            .set(dest_ptr, &block_height)
+            .map_err(|e| Error::Interpreter(e).into())
+    }
+
+    /// Writes the public key of the proposer of the current block to [dest_ptr] in Wasm memory.
+    ///
+    /// Returns `ApiError::None` if the request executing the deploy doesn't name a proposer.
+    fn get_proposer(&self, dest_ptr: u32) -> Result<Result<(), ApiError>, Trap> {
+        let proposer = match self.context.get_proposer() {
+            Some(proposer) => proposer.into_bytes().map_err(Error::BytesRepr)?,
+            None => return Ok(Err(ApiError::None)),
+        };
+        self.memory
+            .set(dest_ptr, &proposer)
+            .map_err(Error::Interpreter)?;
+        Ok(Ok(()))
+    }
+
+    /// Writes the hash of the current deploy to [dest_ptr] in Wasm memory.
+    fn get_deploy_hash(&self, dest_ptr: u32) -> Result<(), Trap> {
+        let deploy_hash = self.context.get_deployhash();
+        self.memory
+            .set(dest_ptr, &deploy_hash)
+            .map_err(|e| Error::Interpreter(e).into())
+    }
+
     /// Return some bytes from the memory and terminate the current `sub_call`. Note that the return
     /// type is `Trap`, indicating that this function will always kill the current Wasm instance.
     fn ret(&mut self, value_ptr: u32, value_size: usize) -> Trap {
@@ -1705,6 +1985,8 @@ where
         let account = self.context.account();
         let base_key = self.protocol_data().mint().into();
         let blocktime = self.context.get_blocktime();
+        let block_height = self.context.get_block_height();
+        let proposer = self.context.get_proposer();
         let deploy_hash = self.context.get_deployhash();
         let gas_limit = self.context.gas_limit();
         let gas_counter = self.context.gas_counter();
@@ -1719,10 +2001,13 @@ where
             &mut named_keys,
             access_rights,
             args.to_owned(),
+            RuntimeArgs::new(),
             authorization_keys,
             account,
             base_key,
             blocktime,
+            block_height,
+            proposer,
             deploy_hash,
             gas_limit,
             gas_counter,
@@ -1797,6 +2082,8 @@ where
         let account = self.context.account();
         let base_key = self.protocol_data().proof_of_stake().into();
         let blocktime = self.context.get_blocktime();
+        let block_height = self.context.get_block_height();
+        let proposer = self.context.get_proposer();
         let deploy_hash = self.context.get_deployhash();
         let gas_limit = self.context.gas_limit();
         let gas_counter = self.context.gas_counter();
@@ -1811,10 +2098,13 @@ where
             &mut named_keys,
             access_rights,
             args.to_owned(),
+            RuntimeArgs::new(),
             authorization_keys,
             account,
             base_key,
             blocktime,
+            block_height,
+            proposer,
             deploy_hash,
             gas_limit,
             gas_counter,
@@ -1905,6 +2195,40 @@ where
 
     /// Calls contract living under a `key`, with supplied `args`.
     pub fn call_contract(&mut self, key: Key, args_bytes: Vec<u8>) -> Result<CLValue, Error> {
+        self.call_entry_point(key, DEFAULT_ENTRY_POINT, args_bytes)
+    }
+
+    /// Calls the `entry_point` of the contract living under a `key`, with supplied `args`.
+    pub fn call_entry_point(
+        &mut self,
+        key: Key,
+        entry_point: &str,
+        args_bytes: Vec<u8>,
+    ) -> Result<CLValue, Error> {
+        let tracer = match self.tracer {
+            Some(ref tracer) => Rc::clone(tracer),
+            None => return self.call_contract_untraced(key, entry_point, args_bytes),
+        };
+
+        tracer
+            .borrow_mut()
+            .enter(key, self.context.phase(), &args_bytes);
+        let gas_counter = self.context.gas_counter();
+
+        let result = self.call_contract_untraced(key, entry_point, args_bytes);
+
+        let gas_used = self.context.gas_counter() - gas_counter;
+        let error = result.as_ref().err().map(ToString::to_string);
+        tracer.borrow_mut().exit(gas_used, error);
+        result
+    }
+
+    fn call_contract_untraced(
+        &mut self,
+        key: Key,
+        entry_point: &str,
+        args_bytes: Vec<u8>,
+    ) -> Result<CLValue, Error> {
         let contract = match self.context.read_gs(&key)? {
             Some(StoredValue::Contract(contract)) => contract,
             Some(_) => {
@@ -1964,14 +2288,26 @@ where
             _ => None,
         };
 
-        let module = match maybe_module {
-            Some(module) => module,
-            None => parity_wasm::deserialize_buffer(contract.bytes())?,
+        let mut module = match (maybe_module, &self.module_cache) {
+            (Some(module), _) => module,
+            (None, Some(module_cache)) => module_cache.get_or_insert_with(
+                self.context.correlation_id(),
+                key,
+                contract_version,
+                contract.bytes(),
+                parity_wasm::deserialize_buffer,
+            )?,
+            (None, None) => parity_wasm::deserialize_buffer(contract.bytes())?,
         };
+        select_entry_point(&mut module, contract.entry_points(), entry_point, &args)?;
 
         let mut named_keys = contract.take_named_keys();
 
-        let (instance, memory) = instance_and_memory(module.clone(), contract_version)?;
+        let (instance, memory) = instance_and_memory(
+            module.clone(),
+            contract_version,
+            self.context.protocol_data().wasm_costs().max_memory_pages,
+        )?;
 
         let access_rights = {
             let mut keys: Vec<Key> = named_keys.values().cloned().collect();
@@ -1992,10 +2328,13 @@ where
             &mut named_keys,
             access_rights,
             args,
+            RuntimeArgs::new(),
             self.context.authorization_keys().clone(),
             &self.context.account(),
             key,
             self.context.get_blocktime(),
+            self.context.get_block_height(),
+            self.context.get_proposer(),
             self.context.get_deployhash(),
             self.context.gas_limit(),
             self.context.gas_counter(),
@@ -2009,11 +2348,17 @@ where
 
         let mut runtime = Runtime {
             system_contract_cache,
+            module_cache: self.module_cache.clone(),
             config,
             memory,
             module,
             host_buffer,
             context,
+            tracer: self.tracer.clone(),
+            events: Rc::clone(&self.events),
+            debug_log: self.debug_log.clone(),
+            random_generator: Rc::clone(&self.random_generator),
+            call_depth: self.call_depth + 1,
         };
 
         let result = instance.invoke_export("call", &[], &mut runtime);
@@ -2065,6 +2410,7 @@ where
     fn call_contract_host_buffer(
         &mut self,
         key: Key,
+        entry_point: &str,
         args_bytes: Vec<u8>,
         result_size_ptr: u32,
     ) -> Result<Result<(), ApiError>, Error> {
@@ -2073,7 +2419,7 @@ where
             return Ok(Err(ApiError::HostBufferFull));
         }
 
-        let result = self.call_contract(key, args_bytes)?;
+        let result = self.call_entry_point(key, entry_point, args_bytes)?;
         let result_size = result.inner_bytes().len() as u32; // considered to be safe
 
         // leave the host buffer set to `None` if there's nothing to write there
@@ -2132,11 +2478,13 @@ where
         &mut self,
         fn_bytes: Vec<u8>,
         named_keys: BTreeMap<String, Key>,
+        entry_points: EntryPoints,
     ) -> Result<[u8; 32], Error> {
-        let contract = Contract::new(fn_bytes, named_keys, self.context.protocol_version());
-        let contract_addr = self
-            .context
-            .store_function(StoredValue::Contract(contract))?;
+        let contract = Contract::new(fn_bytes, named_keys, self.context.protocol_version())
+            .with_entry_points(entry_points);
+        let value = StoredValue::Contract(contract);
+        self.charge_storage(value.byte_size())?;
+        let contract_addr = self.context.store_function(value)?;
         Ok(contract_addr)
     }
 
@@ -2147,11 +2495,13 @@ where
         &mut self,
         fn_bytes: Vec<u8>,
         named_keys: BTreeMap<String, Key>,
+        entry_points: EntryPoints,
     ) -> Result<[u8; 32], Error> {
-        let contract = Contract::new(fn_bytes, named_keys, self.context.protocol_version());
-        let new_hash = self
-            .context
-            .store_function_at_hash(StoredValue::Contract(contract))?;
+        let contract = Contract::new(fn_bytes, named_keys, self.context.protocol_version())
+            .with_entry_points(entry_points);
+        let value = StoredValue::Contract(contract);
+        self.charge_storage(value.byte_size())?;
+        let new_hash = self.context.store_function_at_hash(value)?;
         Ok(new_hash)
     }
 
@@ -2167,7 +2517,9 @@ where
     /// access_rights set.
     fn new_uref(&mut self, uref_ptr: u32, value_ptr: u32, value_size: u32) -> Result<(), Trap> {
         let cl_value = self.cl_value_from_mem(value_ptr, value_size)?; // read initial value from memory
-        let uref = self.context.new_uref(StoredValue::CLValue(cl_value))?;
+        let value = StoredValue::CLValue(cl_value);
+        self.charge_storage(value.byte_size())?;
+        let uref = self.context.new_uref(value)?;
         self.memory
             .set(uref_ptr, &uref.into_bytes().map_err(Error::BytesRepr)?)
             .map_err(|e| Error::Interpreter(e).into())
@@ -2183,9 +2535,9 @@ where
     ) -> Result<(), Trap> {
         let key = self.key_from_mem(key_ptr, key_size)?;
         let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
-        self.context
-            .write_gs(key, StoredValue::CLValue(cl_value))
-            .map_err(Into::into)
+        let value = StoredValue::CLValue(cl_value);
+        self.charge_storage(value.byte_size())?;
+        self.context.write_gs(key, value).map_err(Into::into)
     }
 
     /// Writes `value` under a key derived from `key` in the "local cluster" of
@@ -2199,6 +2551,7 @@ where
     ) -> Result<(), Trap> {
         let key_bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
         let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
+        self.charge_storage(cl_value.byte_size())?;
         self.context
             .write_ls(&key_bytes, cl_value)
             .map_err(Into::into)
@@ -2214,9 +2567,9 @@ where
     ) -> Result<(), Trap> {
         let key = self.key_from_mem(key_ptr, key_size)?;
         let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
-        self.context
-            .add_gs(key, StoredValue::CLValue(cl_value))
-            .map_err(Into::into)
+        let value = StoredValue::CLValue(cl_value);
+        self.charge_storage(value.byte_size())?;
+        self.context.add_gs(key, value).map_err(Into::into)
     }
 
     /// Adds `value` to the cell pointed to by a key derived from `key` in the "local cluster" of
@@ -2230,6 +2583,7 @@ where
     ) -> Result<(), Trap> {
         let key_bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
         let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
+        self.charge_storage(cl_value.byte_size())?;
         self.context
             .add_ls(&key_bytes, cl_value)
             .map_err(Into::into)
@@ -2798,10 +3152,79 @@ where
         Ok(Ok(()))
     }
 
-    #[cfg(feature = "test-support")]
+    /// Hashes the input (defined as `in_ptr` and `in_size` tuple) with `hash_fn` and writes the
+    /// digest to [out_ptr] in the Wasm memory.
+    fn hash(
+        &mut self,
+        hash_fn: fn(&[u8]) -> [u8; crypto::HASH_LENGTH],
+        in_ptr: u32,
+        in_size: u32,
+        out_ptr: u32,
+    ) -> Result<(), Trap> {
+        let data = self.bytes_from_mem(in_ptr, in_size as usize)?;
+        let digest = hash_fn(&data);
+        self.memory
+            .set(out_ptr, &digest)
+            .map_err(|e| Error::Interpreter(e).into())
+    }
+
+    /// Verifies an Ed25519 signature of a message with a public key, all read from the Wasm
+    /// memory. Returns 1 if the signature is valid and 0 otherwise.
+    fn verify_ed25519(
+        &mut self,
+        public_key_ptr: u32,
+        public_key_size: u32,
+        message_ptr: u32,
+        message_size: u32,
+        signature_ptr: u32,
+        signature_size: u32,
+    ) -> Result<i32, Trap> {
+        let public_key = self.bytes_from_mem(public_key_ptr, public_key_size as usize)?;
+        let message = self.bytes_from_mem(message_ptr, message_size as usize)?;
+        let signature = self.bytes_from_mem(signature_ptr, signature_size as usize)?;
+        if crypto::verify_ed25519(&public_key, &message, &signature) {
+            Ok(1)
+        } else {
+            Ok(0)
+        }
+    }
+
+    /// Records an event emitted by the contract.
+    fn emit_event(
+        &mut self,
+        topic_ptr: u32,
+        topic_size: u32,
+        payload_ptr: u32,
+        payload_size: u32,
+    ) -> Result<(), Trap> {
+        let topic = self.string_from_mem(topic_ptr, topic_size)?;
+        let payload = self.cl_value_from_mem(payload_ptr, payload_size)?;
+        let event = ContractEvent::new(self.context.base_key(), topic, payload);
+        self.events.borrow_mut().push(event);
+        Ok(())
+    }
+
+    /// Writes `dest_size` random bytes to [dest_ptr] in the Wasm memory.
+    fn random_bytes(&mut self, dest_ptr: u32, dest_size: u32) -> Result<(), Trap> {
+        let mut bytes = vec![0u8; dest_size as usize];
+        self.random_generator
+            .borrow_mut()
+            .fill_bytes(self.call_depth, &mut bytes);
+        self.memory
+            .set(dest_ptr, &bytes)
+            .map_err(|e| Error::Interpreter(e).into())
+    }
+
+    /// Appends a message to the debug log.  The message isn't even read when the debug log is
+    /// disabled, or when it would be dropped for exceeding the size of the debug log.
     fn print(&mut self, text_ptr: u32, text_size: u32) -> Result<(), Trap> {
-        let text = self.string_from_mem(text_ptr, text_size)?;
-        println!("{}", text);
+        if let Some(debug_log) = self.debug_log.clone() {
+            if !debug_log.borrow_mut().fits(text_size as usize) {
+                return Ok(());
+            }
+            let text = self.string_from_mem(text_ptr, text_size)?;
+            debug_log.borrow_mut().push(text);
+        }
         Ok(())
     }
 }
@@ -22,7 +22,7 @@ use types::{
         UpdateKeyFailure, Weight,
     },
     bytesrepr::{self, ToBytes},
-    AccessRights, BlockTime, CLType, CLValue, Key, Phase, ProtocolVersion, URef,
+    AccessRights, BlockTime, CLType, CLValue, Key, Phase, ProtocolVersion, RuntimeArgs, URef,
     KEY_LOCAL_SEED_LENGTH,
 };
 
@@ -60,11 +60,14 @@ pub struct RuntimeContext<'a, R> {
     // Original account for read only tasks taken before execution
     account: &'a Account,
     args: Vec<CLValue>,
+    named_args: RuntimeArgs,
     authorization_keys: BTreeSet<PublicKey>,
     // Key pointing to the entity we are currently running
     //(could point at an account or contract in the global state)
     base_key: Key,
     blocktime: BlockTime,
+    block_height: u64,
+    proposer: Option<PublicKey>,
     deploy_hash: [u8; 32],
     gas_limit: Gas,
     gas_counter: Gas,
@@ -87,10 +90,13 @@ where
         named_keys: &'a mut BTreeMap<String, Key>,
         access_rights: HashMap<Address, HashSet<AccessRights>>,
         args: Vec<CLValue>,
+        named_args: RuntimeArgs,
         authorization_keys: BTreeSet<PublicKey>,
         account: &'a Account,
         base_key: Key,
         blocktime: BlockTime,
+        block_height: u64,
+        proposer: Option<PublicKey>,
         deploy_hash: [u8; 32],
         gas_limit: Gas,
         gas_counter: Gas,
@@ -106,9 +112,12 @@ where
             named_keys,
             access_rights,
             args,
+            named_args,
             account,
             authorization_keys,
             blocktime,
+            block_height,
+            proposer,
             deploy_hash,
             base_key,
             gas_limit,
@@ -215,6 +224,14 @@ where
         self.deploy_hash
     }
 
+    pub fn get_block_height(&self) -> u64 {
+        self.block_height
+    }
+
+    pub fn get_proposer(&self) -> Option<PublicKey> {
+        self.proposer
+    }
+
     pub fn access_rights_extend(&mut self, access_rights: HashMap<Address, HashSet<AccessRights>>) {
         self.access_rights.extend(access_rights);
     }
@@ -227,6 +244,10 @@ where
         &self.args
     }
 
+    pub fn named_args(&self) -> &RuntimeArgs {
+        &self.named_args
+    }
+
     pub fn address_generator(&self) -> Rc<RefCell<AddressGenerator>> {
         Rc::clone(&self.address_generator)
     }
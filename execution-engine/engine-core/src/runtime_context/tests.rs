@@ -24,7 +24,8 @@ use types::{
     account::{
         ActionType, AddKeyFailure, PublicKey, RemoveKeyFailure, SetThresholdFailure, Weight,
     },
-    AccessRights, BlockTime, CLValue, Key, Phase, ProtocolVersion, URef, KEY_LOCAL_SEED_LENGTH,
+    AccessRights, BlockTime, CLValue, Key, Phase, ProtocolVersion, RuntimeArgs, URef,
+    KEY_LOCAL_SEED_LENGTH,
 };
 
 use super::{attenuate_uref_for_account, Address, Error, RuntimeContext};
@@ -118,10 +119,13 @@ fn mock_runtime_context<'a>(
         named_keys,
         access_rights,
         Vec::new(),
+        RuntimeArgs::new(),
         BTreeSet::from_iter(vec![PublicKey::ed25519_from([0; 32])]),
         &account,
         base_key,
         BlockTime::new(0),
+        0,
+        None,
         [1u8; 32],
         Gas::default(),
         Gas::default(),
@@ -449,10 +453,13 @@ fn contract_key_addable_valid() {
         &mut uref_map,
         access_rights,
         Vec::new(),
+        RuntimeArgs::new(),
         BTreeSet::from_iter(vec![PublicKey::ed25519_from(base_acc_addr)]),
         &account,
         contract_key,
         BlockTime::new(0),
+        0,
+        None,
         DEPLOY_HASH,
         Gas::default(),
         Gas::default(),
@@ -511,10 +518,13 @@ fn contract_key_addable_invalid() {
         &mut uref_map,
         access_rights,
         Vec::new(),
+        RuntimeArgs::new(),
         BTreeSet::from_iter(vec![PublicKey::ed25519_from(base_acc_addr)]),
         &account,
         other_contract_key,
         BlockTime::new(0),
+        0,
+        None,
         DEPLOY_HASH,
         Gas::default(),
         Gas::default(),
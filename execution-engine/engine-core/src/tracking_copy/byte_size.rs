@@ -1,7 +1,7 @@
 use std::{collections::BTreeMap, mem};
 
 use engine_shared::{account::Account, contract::Contract, stored_value::StoredValue};
-use types::Key;
+use types::{CLValue, EntryPoint, Key, Parameter};
 
 /// Returns byte size of the element - both heap size and stack size.
 pub trait ByteSize {
@@ -29,6 +29,12 @@ impl ByteSize for Contract {
     }
 }
 
+impl ByteSize for CLValue {
+    fn byte_size(&self) -> usize {
+        mem::size_of::<Self>() + self.serialized_length()
+    }
+}
+
 impl ByteSize for String {
     fn byte_size(&self) -> usize {
         mem::size_of::<Self>() + self.heap_size()
@@ -76,7 +82,18 @@ impl HeapSizeOf for Account {
 // TODO: contract has other fields (re protocol version) that are not repr here...on purpose?
 impl HeapSizeOf for Contract {
     fn heap_size(&self) -> usize {
-        self.named_keys().heap_size() + self.bytes().len()
+        self.named_keys().heap_size() + self.bytes().len() + self.entry_points().heap_size()
+    }
+}
+
+// NOTE: The heap size of parameter types is approximated by their serialized length.
+impl HeapSizeOf for EntryPoint {
+    fn heap_size(&self) -> usize {
+        self.parameters().iter().fold(0, |sum, parameter| {
+            sum + mem::size_of::<Parameter>()
+                + parameter.name().len()
+                + parameter.cl_type().serialized_length()
+        })
     }
 }
 
@@ -1,4 +1,4 @@
-mod byte_size;
+pub(crate) mod byte_size;
 mod ext;
 pub(self) mod meter;
 #[cfg(test)]
@@ -324,6 +324,45 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         }
     }
 
+    /// Applies `transforms` produced against a fork of this `TrackingCopy`, so that they become
+    /// part of its state and its effect.
+    pub fn apply_changes(
+        &mut self,
+        correlation_id: CorrelationId,
+        transforms: AdditiveMap<Key, Transform>,
+    ) -> Result<AddResult, R::Error> {
+        for (key, transform) in transforms {
+            let normalized_key = key.normalize();
+            let op = match transform {
+                Transform::Identity => continue,
+                Transform::Write(_) => Op::Write,
+                _ => Op::Add,
+            };
+            let new_value = match transform.clone() {
+                Transform::Write(value) => value,
+                transform => {
+                    let current_value = match self.get(correlation_id, &normalized_key)? {
+                        None => return Ok(AddResult::KeyNotFound(normalized_key)),
+                        Some(current_value) => current_value,
+                    };
+                    match transform.apply(current_value) {
+                        Ok(new_value) => new_value,
+                        Err(transform::Error::TypeMismatch(type_mismatch)) => {
+                            return Ok(AddResult::TypeMismatch(type_mismatch))
+                        }
+                        Err(transform::Error::Serialization(error)) => {
+                            return Ok(AddResult::Serialization(error))
+                        }
+                    }
+                }
+            };
+            self.cache.insert_write(normalized_key, new_value);
+            self.ops.insert_add(normalized_key, op);
+            self.fns.insert_add(normalized_key, transform);
+        }
+        Ok(AddResult::Success)
+    }
+
     pub fn effect(&self) -> ExecutionEffect {
         ExecutionEffect::new(self.ops.clone(), self.fns.clone())
     }
@@ -0,0 +1,33 @@
+use engine_core::engine_state::call_trace::CallTrace;
+
+use crate::engine_server::ipc::{CallTrace as PbCallTrace, CallTrace_HostFunction};
+
+impl From<&CallTrace> for PbCallTrace {
+    fn from(call_trace: &CallTrace) -> Self {
+        let mut pb_call_trace = PbCallTrace::new();
+        pb_call_trace.set_key(call_trace.key().into());
+        pb_call_trace.set_phase(call_trace.phase() as u32);
+        if let Some(method) = call_trace.method() {
+            pb_call_trace.set_method(method.to_string());
+        }
+        pb_call_trace.set_args_size(call_trace.args_size() as u64);
+        pb_call_trace.set_gas_used(call_trace.gas_used().value().into());
+        pb_call_trace.set_host_functions(
+            call_trace
+                .host_functions()
+                .iter()
+                .map(|(name, count)| {
+                    let mut pb_host_function = CallTrace_HostFunction::new();
+                    pb_host_function.set_name(name.to_string());
+                    pb_host_function.set_count(*count);
+                    pb_host_function
+                })
+                .collect(),
+        );
+        if let Some(error) = call_trace.error() {
+            pb_call_trace.set_error(error.to_string());
+        }
+        pb_call_trace.set_calls(call_trace.calls().iter().map(Into::into).collect());
+        pb_call_trace
+    }
+}
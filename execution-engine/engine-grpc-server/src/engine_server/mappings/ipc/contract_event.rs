@@ -0,0 +1,13 @@
+use engine_core::engine_state::contract_event::ContractEvent;
+
+use crate::engine_server::ipc::ContractEvent as PbContractEvent;
+
+impl From<&ContractEvent> for PbContractEvent {
+    fn from(event: &ContractEvent) -> Self {
+        let mut pb_event = PbContractEvent::new();
+        pb_event.set_emitter(event.emitter().into());
+        pb_event.set_topic(event.topic().to_string());
+        pb_event.set_payload(event.payload().clone().into());
+        pb_event
+    }
+}
@@ -11,14 +11,34 @@ use crate::engine_server::ipc::{DeployError_OutOfGasError, DeployResult};
 
 impl From<ExecutionResult> for DeployResult {
     fn from(execution_result: ExecutionResult) -> DeployResult {
-        match execution_result {
-            ExecutionResult::Success { effect, cost } => detail::execution_success(effect, cost),
+        let (mut pb_deploy_result, trace, events, debug_log) = match execution_result {
+            ExecutionResult::Success {
+                effect,
+                cost,
+                trace,
+                events,
+                debug_log,
+            } => (
+                detail::execution_success(effect, cost),
+                trace,
+                events,
+                debug_log,
+            ),
             ExecutionResult::Failure {
                 error,
                 effect,
                 cost,
-            } => (error, effect, cost).into(),
+                trace,
+                debug_log,
+            } => ((error, effect, cost).into(), trace, Vec::new(), debug_log),
+        };
+        if pb_deploy_result.has_execution_result() {
+            let pb_execution_result = pb_deploy_result.mut_execution_result();
+            pb_execution_result.set_trace(trace.iter().map(Into::into).collect());
+            pb_execution_result.set_events(events.iter().map(Into::into).collect());
+            pb_execution_result.set_debug_log(debug_log.into());
         }
+        pb_deploy_result
     }
 }
 
@@ -30,12 +50,16 @@ impl From<(EngineStateError, ExecutionEffect, Gas)> for DeployResult {
             // all reported as "wasm errors".
             error @ EngineStateError::InvalidHashLength { .. }
             | error @ EngineStateError::InvalidPublicKeyLength { .. }
+            | error @ EngineStateError::MissingProposer
             | error @ EngineStateError::InvalidProtocolVersion { .. }
             | error @ EngineStateError::InvalidUpgradeConfig
+            | error @ EngineStateError::InvalidVestingSchedule { .. }
             | error @ EngineStateError::WasmPreprocessing(_)
             | error @ EngineStateError::WasmSerialization(_)
             | error @ EngineStateError::Exec(ExecutionError::DeploymentAuthorizationFailure)
-            | error @ EngineStateError::Authorization => {
+            | error @ EngineStateError::Authorization
+            | error @ EngineStateError::GasPriceTooLow { .. }
+            | error @ EngineStateError::BlockGasLimitExceeded => {
                 detail::precondition_error(error.to_string())
             }
             EngineStateError::Storage(storage_error) => {
@@ -163,8 +187,10 @@ mod detail {
 mod tests {
     use std::convert::TryInto;
 
+    use engine_core::engine_state::contract_event::ContractEvent;
     use engine_shared::{additive_map::AdditiveMap, transform::Transform};
-    use types::{bytesrepr::Error as BytesReprError, AccessRights, Key, URef, U512};
+    use engine_wasm_prep::PreprocessingError;
+    use types::{bytesrepr::Error as BytesReprError, AccessRights, CLValue, Key, URef, U512};
 
     use super::*;
 
@@ -183,6 +209,9 @@ mod tests {
         let execution_result = ExecutionResult::Success {
             effect: execution_effect,
             cost,
+            trace: Vec::new(),
+            events: Vec::new(),
+            debug_log: Vec::new(),
         };
         let mut ipc_deploy_result: DeployResult = execution_result.into();
         assert!(ipc_deploy_result.has_execution_result());
@@ -203,11 +232,42 @@ mod tests {
         assert_eq!(input_transforms, ipc_transforms);
     }
 
+    #[test]
+    fn deploy_result_to_ipc_events() {
+        let emitter = Key::Hash([2u8; 32]);
+        let payload = CLValue::from_t(U512::from(123)).expect("should create CLValue");
+        let event = ContractEvent::new(emitter, "topic".to_string(), payload.clone());
+        let execution_result = ExecutionResult::Success {
+            effect: Default::default(),
+            cost: Gas::default(),
+            trace: Vec::new(),
+            events: vec![event],
+            debug_log: Vec::new(),
+        };
+        let mut ipc_deploy_result: DeployResult = execution_result.into();
+        let ipc_events = ipc_deploy_result.mut_execution_result().take_events();
+        assert_eq!(ipc_events.len(), 1);
+        let mut ipc_event = ipc_events.into_iter().next().unwrap();
+        let ipc_emitter: Key = ipc_event
+            .take_emitter()
+            .try_into()
+            .expect("should map to Key");
+        assert_eq!(ipc_emitter, emitter);
+        assert_eq!(ipc_event.get_topic(), "topic");
+        let ipc_payload: CLValue = ipc_event
+            .take_payload()
+            .try_into()
+            .expect("should map to CLValue");
+        assert_eq!(ipc_payload, payload);
+    }
+
     fn test_cost<E: Into<EngineStateError>>(expected_cost: Gas, error: E) -> Gas {
         let execution_failure = ExecutionResult::Failure {
             error: error.into(),
             effect: Default::default(),
             cost: expected_cost,
+            trace: Vec::new(),
+            debug_log: Vec::new(),
         };
         let mut ipc_deploy_result: DeployResult = execution_failure.into();
         assert!(ipc_deploy_result.has_execution_result());
@@ -251,6 +311,8 @@ mod tests {
             error: EngineStateError::Exec(revert_error),
             effect: Default::default(),
             cost: Gas::new(amount),
+            trace: Vec::new(),
+            debug_log: Vec::new(),
         };
         let mut ipc_result: DeployResult = exec_result.into();
         assert!(
@@ -271,4 +333,24 @@ mod tests {
             format!("Exit code: {}", REVERT)
         );
     }
+
+    #[test]
+    fn preprocessing_error_maps_to_precondition_failure() {
+        let preprocessing_error = PreprocessingError::UnknownImport {
+            module: "env".to_string(),
+            field: "unknown".to_string(),
+        };
+        let exec_result = ExecutionResult::precondition_failure(
+            EngineStateError::WasmPreprocessing(preprocessing_error),
+        );
+        let ipc_result: DeployResult = exec_result.into();
+        assert!(
+            ipc_result.has_precondition_failure(),
+            "should have precondition failure"
+        );
+        assert_eq!(
+            ipc_result.get_precondition_failure().get_message(),
+            "Wasm preprocessing error: Unknown import: env.unknown"
+        );
+    }
 }
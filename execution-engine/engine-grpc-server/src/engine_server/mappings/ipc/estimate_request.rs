@@ -0,0 +1,67 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::estimate::EstimateRequest;
+use engine_shared::{gas::Gas, newtypes::BLAKE2B_DIGEST_LENGTH};
+use types::{account::PublicKey, U512};
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl TryFrom<ipc::EstimateRequest> for EstimateRequest {
+    type Error = ipc::EstimateResponse;
+
+    fn try_from(mut request: ipc::EstimateRequest) -> Result<Self, Self::Error> {
+        let parent_state_hash = {
+            let parent_state_hash = request.take_parent_state_hash();
+            let length = parent_state_hash.len();
+            if length != BLAKE2B_DIGEST_LENGTH {
+                let mut result = ipc::EstimateResponse::new();
+                result.mut_missing_parent().set_hash(parent_state_hash);
+                return Err(result);
+            }
+            parent_state_hash.as_slice().try_into().map_err(|_| {
+                let mut result = ipc::EstimateResponse::new();
+                result
+                    .mut_missing_parent()
+                    .set_hash(parent_state_hash.clone());
+                result
+            })?
+        };
+
+        let block_time = request.get_block_time();
+
+        let block_height = request.get_block_height();
+
+        let proposer = PublicKey::ed25519_try_from(request.get_proposer()).map_err(|_| {
+            let error = MappingError::invalid_public_key_length(request.get_proposer().len());
+            let mut result = ipc::EstimateResponse::new();
+            result.mut_error().set_message(error.to_string());
+            result
+        })?;
+
+        let deploy_item = request
+            .take_deploy()
+            .try_into()
+            .map_err(|error: MappingError| {
+                let mut result = ipc::EstimateResponse::new();
+                result.mut_error().set_message(error.to_string());
+                result
+            })?;
+
+        let protocol_version = request.take_protocol_version().into();
+
+        let gas_limit = match request.get_gas_limit() {
+            0 => None,
+            gas_limit => Some(Gas::new(U512::from(gas_limit))),
+        };
+
+        Ok(EstimateRequest::new(
+            parent_state_hash,
+            block_time,
+            block_height,
+            proposer,
+            deploy_item,
+            protocol_version,
+            gas_limit,
+        ))
+    }
+}
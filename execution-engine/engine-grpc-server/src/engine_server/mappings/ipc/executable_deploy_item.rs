@@ -1,4 +1,5 @@
 use engine_core::engine_state::executable_deploy_item::ExecutableDeployItem;
+use types::DEFAULT_ENTRY_POINT;
 
 use crate::engine_server::ipc::{DeployPayload, DeployPayload_oneof_payload};
 
@@ -9,24 +10,31 @@ impl From<DeployPayload_oneof_payload> for ExecutableDeployItem {
                 ExecutableDeployItem::ModuleBytes {
                     module_bytes: pb_deploy_code.code,
                     args: pb_deploy_code.args,
+                    named_args: pb_deploy_code.named_args,
                 }
             }
             DeployPayload_oneof_payload::stored_contract_hash(pb_stored_contract_hash) => {
                 ExecutableDeployItem::StoredContractByHash {
                     hash: pb_stored_contract_hash.hash,
+                    entry_point: entry_point_or_default(pb_stored_contract_hash.entry_point),
                     args: pb_stored_contract_hash.args,
+                    named_args: pb_stored_contract_hash.named_args,
                 }
             }
             DeployPayload_oneof_payload::stored_contract_name(pb_stored_contract_name) => {
                 ExecutableDeployItem::StoredContractByName {
                     name: pb_stored_contract_name.stored_contract_name,
+                    entry_point: entry_point_or_default(pb_stored_contract_name.entry_point),
                     args: pb_stored_contract_name.args,
+                    named_args: pb_stored_contract_name.named_args,
                 }
             }
             DeployPayload_oneof_payload::stored_contract_uref(pb_stored_contract_uref) => {
                 ExecutableDeployItem::StoredContractByURef {
                     uref: pb_stored_contract_uref.uref,
+                    entry_point: entry_point_or_default(pb_stored_contract_uref.entry_point),
                     args: pb_stored_contract_uref.args,
+                    named_args: pb_stored_contract_uref.named_args,
                 }
             }
         }
@@ -37,27 +45,62 @@ impl From<ExecutableDeployItem> for DeployPayload {
     fn from(edi: ExecutableDeployItem) -> Self {
         let mut result = DeployPayload::new();
         match edi {
-            ExecutableDeployItem::ModuleBytes { module_bytes, args } => {
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes,
+                args,
+                named_args,
+            } => {
                 let code = result.mut_deploy_code();
                 code.set_code(module_bytes);
                 code.set_args(args);
+                code.set_named_args(named_args);
             }
-            ExecutableDeployItem::StoredContractByHash { hash, args } => {
+            ExecutableDeployItem::StoredContractByHash {
+                hash,
+                entry_point,
+                args,
+                named_args,
+            } => {
                 let inner = result.mut_stored_contract_hash();
                 inner.set_hash(hash);
+                inner.set_entry_point(entry_point);
                 inner.set_args(args);
+                inner.set_named_args(named_args);
             }
-            ExecutableDeployItem::StoredContractByName { name, args } => {
+            ExecutableDeployItem::StoredContractByName {
+                name,
+                entry_point,
+                args,
+                named_args,
+            } => {
                 let inner = result.mut_stored_contract_name();
                 inner.set_stored_contract_name(name);
+                inner.set_entry_point(entry_point);
                 inner.set_args(args);
+                inner.set_named_args(named_args);
             }
-            ExecutableDeployItem::StoredContractByURef { uref, args } => {
+            ExecutableDeployItem::StoredContractByURef {
+                uref,
+                entry_point,
+                args,
+                named_args,
+            } => {
                 let inner = result.mut_stored_contract_uref();
                 inner.set_uref(uref);
+                inner.set_entry_point(entry_point);
                 inner.set_args(args);
+                inner.set_named_args(named_args);
             }
         }
         result
     }
 }
+
+/// Deploys built before stored contracts had entry points leave the entry point empty.
+fn entry_point_or_default(entry_point: String) -> String {
+    if entry_point.is_empty() {
+        DEFAULT_ENTRY_POINT.to_string()
+    } else {
+        entry_point
+    }
+}
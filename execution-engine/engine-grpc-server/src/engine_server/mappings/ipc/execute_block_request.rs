@@ -0,0 +1,101 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::{
+    execute_block::ExecuteBlockRequest, execution_result::ExecutionResult,
+};
+use engine_shared::newtypes::BLAKE2B_DIGEST_LENGTH;
+use types::account::PublicKey;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl TryFrom<ipc::ExecuteBlockRequest> for ExecuteBlockRequest {
+    type Error = ipc::ExecuteBlockResponse;
+
+    fn try_from(mut request: ipc::ExecuteBlockRequest) -> Result<Self, Self::Error> {
+        let parent_state_hash = {
+            let parent_state_hash = request.take_parent_state_hash();
+            let length = parent_state_hash.len();
+            if length != BLAKE2B_DIGEST_LENGTH {
+                let mut result = ipc::ExecuteBlockResponse::new();
+                result.mut_missing_parent().set_hash(parent_state_hash);
+                return Err(result);
+            }
+            parent_state_hash.as_slice().try_into().map_err(|_| {
+                let mut result = ipc::ExecuteBlockResponse::new();
+                result
+                    .mut_missing_parent()
+                    .set_hash(parent_state_hash.clone());
+                result
+            })?
+        };
+
+        let block_time = request.get_block_time();
+
+        let block_height = request.get_block_height();
+
+        // Empty bytes leave the proposer out, but a malformed one fails the whole request.
+        let proposer_bytes = request.take_proposer();
+        let proposer = if proposer_bytes.is_empty() {
+            None
+        } else {
+            let proposer = PublicKey::ed25519_try_from(&proposer_bytes).map_err(|_| {
+                let error = MappingError::invalid_public_key_length(proposer_bytes.len());
+                let mut result = ipc::ExecuteBlockResponse::new();
+                result.mut_error().set_message(error.to_string());
+                result
+            })?;
+            Some(proposer)
+        };
+
+        let deploys = Into::<Vec<_>>::into(request.take_deploys())
+            .into_iter()
+            .map(|deploy_item| {
+                deploy_item
+                    .try_into()
+                    .map_err(|err: MappingError| ExecutionResult::precondition_failure(err.into()))
+            })
+            .collect();
+
+        let protocol_version = request.take_protocol_version().into();
+
+        let run_step = request.get_run_step();
+
+        let commit = request.get_commit();
+
+        Ok(ExecuteBlockRequest::new(
+            parent_state_hash,
+            block_time,
+            block_height,
+            proposer,
+            deploys,
+            protocol_version,
+            run_step,
+            commit,
+        ))
+    }
+}
+
+impl From<ExecuteBlockRequest> for ipc::ExecuteBlockRequest {
+    fn from(req: ExecuteBlockRequest) -> Self {
+        let mut result = ipc::ExecuteBlockRequest::new();
+        result.set_parent_state_hash(req.parent_state_hash.to_vec());
+        result.set_block_time(req.block_time);
+        result.set_deploys(
+            req.deploys
+                .into_iter()
+                .map(|res| match res {
+                    Ok(deploy_item) => deploy_item.into(),
+                    Err(_) => ipc::DeployItem::new(),
+                })
+                .collect(),
+        );
+        result.set_protocol_version(req.protocol_version.into());
+        result.set_run_step(req.run_step);
+        result.set_block_height(req.block_height);
+        if let Some(proposer) = req.proposer {
+            result.set_proposer(proposer.value().to_vec());
+        }
+        result.set_commit(req.commit);
+        result
+    }
+}
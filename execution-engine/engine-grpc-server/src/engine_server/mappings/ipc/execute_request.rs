@@ -4,6 +4,7 @@ use engine_core::engine_state::{
     execute_request::ExecuteRequest, execution_result::ExecutionResult,
 };
 use engine_shared::newtypes::BLAKE2B_DIGEST_LENGTH;
+use types::account::PublicKey;
 
 use crate::engine_server::{ipc, mappings::MappingError};
 
@@ -30,6 +31,22 @@ impl TryFrom<ipc::ExecuteRequest> for ExecuteRequest {
 
         let block_time = request.get_block_time();
 
+        let block_height = request.get_block_height();
+
+        // Empty bytes leave the proposer out, but a malformed one fails the whole request.
+        let proposer_bytes = request.take_proposer();
+        let proposer = if proposer_bytes.is_empty() {
+            None
+        } else {
+            let proposer = PublicKey::ed25519_try_from(&proposer_bytes).map_err(|_| {
+                let error = MappingError::invalid_public_key_length(proposer_bytes.len());
+                let mut result = ipc::ExecuteResponse::new();
+                result.mut_error().set_message(error.to_string());
+                result
+            })?;
+            Some(proposer)
+        };
+
         let deploys = Into::<Vec<_>>::into(request.take_deploys())
             .into_iter()
             .map(|deploy_item| {
@@ -41,11 +58,16 @@ impl TryFrom<ipc::ExecuteRequest> for ExecuteRequest {
 
         let protocol_version = request.take_protocol_version().into();
 
+        let trace = request.get_trace();
+
         Ok(ExecuteRequest::new(
             parent_state_hash,
             block_time,
+            block_height,
+            proposer,
             deploys,
             protocol_version,
+            trace,
         ))
     }
 }
@@ -65,6 +87,11 @@ impl From<ExecuteRequest> for ipc::ExecuteRequest {
                 .collect(),
         );
         result.set_protocol_version(req.protocol_version.into());
+        result.set_trace(req.trace);
+        result.set_block_height(req.block_height);
+        if let Some(proposer) = req.proposer {
+            result.set_proposer(proposer.value().to_vec());
+        }
         result
     }
 }
@@ -0,0 +1,41 @@
+use engine_shared::gas_limits::GasLimits;
+
+use crate::engine_server::ipc::ChainSpec_GasLimits;
+
+impl From<GasLimits> for ChainSpec_GasLimits {
+    fn from(gas_limits: GasLimits) -> Self {
+        ChainSpec_GasLimits {
+            max_payment_cost: gas_limits.max_payment_cost,
+            max_session_gas: gas_limits.max_session_gas,
+            max_block_gas: gas_limits.max_block_gas,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ChainSpec_GasLimits> for GasLimits {
+    fn from(pb_gas_limits: ChainSpec_GasLimits) -> Self {
+        GasLimits {
+            max_payment_cost: pb_gas_limits.max_payment_cost,
+            max_session_gas: pb_gas_limits.max_session_gas,
+            max_block_gas: pb_gas_limits.max_block_gas,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use engine_shared::gas_limits::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(gas_limits in gens::gas_limits_arb()) {
+            test_utils::protobuf_round_trip::<GasLimits, ChainSpec_GasLimits>(gas_limits);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::convert::{TryFrom, TryInto};
 
-use engine_core::engine_state::genesis::GenesisAccount;
+use engine_core::engine_state::genesis::{GenesisAccount, VestingSchedule};
 use engine_shared::motes::Motes;
 use types::account::PublicKey;
 
@@ -13,6 +13,9 @@ impl From<GenesisAccount> for ChainSpec_GenesisAccount {
         pb_genesis_account.set_public_key(genesis_account.public_key().as_bytes().to_vec());
         pb_genesis_account.set_balance(genesis_account.balance().value().into());
         pb_genesis_account.set_bonded_amount(genesis_account.bonded_amount().value().into());
+        if let Some(vesting_schedule) = genesis_account.vesting_schedule() {
+            pb_genesis_account.set_vesting_schedule(vesting_schedule.into());
+        }
 
         pb_genesis_account
     }
@@ -35,7 +38,13 @@ impl TryFrom<ChainSpec_GenesisAccount> for GenesisAccount {
             .take_bonded_amount()
             .try_into()
             .map(Motes::new)?;
-        Ok(GenesisAccount::new(public_key, balance, bonded_amount))
+        let genesis_account = GenesisAccount::new(public_key, balance, bonded_amount);
+        if !pb_genesis_account.has_vesting_schedule() {
+            return Ok(genesis_account);
+        }
+        let vesting_schedule: VestingSchedule =
+            pb_genesis_account.take_vesting_schedule().try_into()?;
+        Ok(genesis_account.with_vesting_schedule(vesting_schedule))
     }
 }
 
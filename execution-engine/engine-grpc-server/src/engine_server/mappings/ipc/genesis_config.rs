@@ -1,6 +1,7 @@
 use std::convert::{TryFrom, TryInto};
 
 use engine_core::engine_state::genesis::{GenesisAccount, GenesisConfig};
+use engine_shared::{gas_limits::GasLimits, host_function_costs::HostFunctionCosts};
 
 use crate::engine_server::{
     ipc::{ChainSpec_GenesisAccount, ChainSpec_GenesisConfig},
@@ -38,6 +39,10 @@ impl From<GenesisConfig> for ChainSpec_GenesisConfig {
             .mut_costs()
             .set_wasm(genesis_config.wasm_costs().into());
         pb_genesis_config
+            .mut_costs()
+            .set_host(genesis_config.host_function_costs().into());
+        pb_genesis_config.set_gas_limits(genesis_config.gas_limits().into());
+        pb_genesis_config
     }
 }
 
@@ -54,7 +59,18 @@ impl TryFrom<ChainSpec_GenesisConfig> for GenesisConfig {
             .map(TryInto::try_into)
             .collect::<Result<Vec<GenesisAccount>, Self::Error>>()?;
         let state_infos = pb_genesis_config.take_state_infos().into_vec();
-        let wasm_costs = pb_genesis_config.take_costs().take_wasm().into();
+        let mut pb_costs = pb_genesis_config.take_costs();
+        let wasm_costs = pb_costs.take_wasm().into();
+        let host_function_costs = if pb_costs.has_host() {
+            pb_costs.take_host().into()
+        } else {
+            HostFunctionCosts::default()
+        };
+        let gas_limits = if pb_genesis_config.has_gas_limits() {
+            pb_genesis_config.take_gas_limits().into()
+        } else {
+            GasLimits::default()
+        };
         let mint_initializer_bytes = pb_genesis_config.mint_installer;
         let proof_of_stake_initializer_bytes = pb_genesis_config.pos_installer;
         let standard_payment_installer_bytes = pb_genesis_config.standard_payment_installer;
@@ -68,7 +84,9 @@ impl TryFrom<ChainSpec_GenesisConfig> for GenesisConfig {
             accounts,
             state_infos,
             wasm_costs,
-        ))
+        )
+        .with_gas_limits(gas_limits)
+        .with_host_function_costs(host_function_costs))
     }
 }
 
@@ -2,14 +2,22 @@
 //! defined in protobuf/io/casperlabs/ipc/ipc.proto
 
 mod bond;
+mod call_trace;
+mod contract_event;
 mod deploy_item;
 mod deploy_result;
+mod estimate_request;
 mod executable_deploy_item;
+mod execute_block_request;
 mod execute_request;
 mod execution_effect;
+mod gas_limits;
 mod genesis_account;
 mod genesis_config;
+mod host_function_costs;
+mod pop_state_request;
 mod query_request;
 mod step_request;
 mod upgrade_request;
+mod vesting_schedule;
 mod wasm_costs;
@@ -0,0 +1,82 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_shared::newtypes::BLAKE2B_DIGEST_LENGTH;
+use types::account::PublicKey;
+
+use crate::engine_server::{ipc, mappings::MappingError, pop_state::PopStateRequest};
+
+impl From<PopStateRequest> for ipc::PopStateRequest {
+    fn from(request: PopStateRequest) -> Self {
+        let mut pb_request = ipc::PopStateRequest::new();
+        pb_request.set_state_hash(request.state_hash.to_vec());
+        pb_request.set_protocol_version(request.protocol_version.into());
+        if let Some(public_key) = request.public_key {
+            pb_request.set_public_key(public_key.as_bytes().to_vec());
+        }
+        pb_request
+    }
+}
+
+impl TryFrom<ipc::PopStateRequest> for PopStateRequest {
+    type Error = MappingError;
+
+    fn try_from(mut pb_request: ipc::PopStateRequest) -> Result<Self, Self::Error> {
+        let state_hash = {
+            let state_hash = pb_request.get_state_hash();
+            let length = state_hash.len();
+            if length != BLAKE2B_DIGEST_LENGTH {
+                return Err(MappingError::InvalidStateHashLength {
+                    expected: BLAKE2B_DIGEST_LENGTH,
+                    actual: length,
+                });
+            }
+            state_hash
+                .try_into()
+                .map_err(|_| MappingError::TryFromSlice)?
+        };
+
+        let protocol_version = pb_request.take_protocol_version().into();
+
+        let public_key = if pb_request.get_public_key().is_empty() {
+            None
+        } else {
+            let public_key =
+                PublicKey::ed25519_try_from(pb_request.get_public_key()).map_err(|_| {
+                    MappingError::invalid_public_key_length(pb_request.public_key.len())
+                })?;
+            Some(public_key)
+        };
+
+        Ok(PopStateRequest {
+            state_hash,
+            protocol_version,
+            public_key,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{option, proptest};
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(
+            state_hash in proptest::array::uniform32(proptest::num::u8::ANY),
+            protocol_version in gens::protocol_version_arb(),
+            public_key in option::of(gens::public_key_arb()),
+        ) {
+            let request = PopStateRequest {
+                state_hash: state_hash.into(),
+                protocol_version,
+                public_key,
+            };
+            test_utils::protobuf_round_trip::<PopStateRequest, ipc::PopStateRequest>(request);
+        }
+    }
+}
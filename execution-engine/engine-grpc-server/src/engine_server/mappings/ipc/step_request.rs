@@ -2,8 +2,9 @@ use std::convert::{TryFrom, TryInto};
 
 use engine_core::engine_state::step::StepRequest;
 use engine_shared::newtypes::BLAKE2B_DIGEST_LENGTH;
+use types::account::PublicKey;
 
-use crate::engine_server::ipc;
+use crate::engine_server::{ipc, mappings::MappingError};
 
 impl TryFrom<ipc::StepRequest> for StepRequest {
     type Error = ipc::StepResponse;
@@ -27,11 +28,20 @@ impl TryFrom<ipc::StepRequest> for StepRequest {
         };
 
         let block_time = request.get_block_time();
+        let block_height = request.get_block_height();
+        let proposer = PublicKey::ed25519_try_from(request.get_proposer()).map_err(|_| {
+            let error = MappingError::invalid_public_key_length(request.get_proposer().len());
+            let mut result = ipc::StepResponse::new();
+            result.mut_error().set_message(error.to_string());
+            result
+        })?;
         let protocol_version = request.take_protocol_version().into();
 
         Ok(StepRequest::new(
             parent_state_hash,
             block_time,
+            block_height,
+            proposer,
             protocol_version,
         ))
     }
@@ -43,6 +53,8 @@ impl From<StepRequest> for ipc::StepRequest {
         result.set_parent_state_hash(req.parent_state_hash.to_vec());
         result.set_block_time(req.block_time);
         result.set_protocol_version(req.protocol_version.into());
+        result.set_block_height(req.block_height);
+        result.set_proposer(req.proposer.value().to_vec());
         result
     }
 }
@@ -30,10 +30,30 @@ impl TryFrom<UpgradeRequest> for UpgradeConfig {
                 (bytes, args)
             };
 
-        let wasm_costs = if !upgrade_point.has_new_costs() {
+        let (wasm_costs, host_function_costs) = if !upgrade_point.has_new_costs() {
+            (None, None)
+        } else {
+            let new_costs = upgrade_point.mut_new_costs();
+            let wasm_costs = if new_costs.has_wasm() {
+                Some(new_costs.take_wasm().into())
+            } else {
+                None
+            };
+            let host_function_costs = if new_costs.has_host() {
+                Some(new_costs.take_host().into())
+            } else {
+                None
+            };
+            (wasm_costs, host_function_costs)
+        };
+        let min_gas_price = match upgrade_point.get_new_min_gas_price() {
+            0 => None,
+            new_min_gas_price => Some(new_min_gas_price),
+        };
+        let gas_limits = if !upgrade_point.has_new_gas_limits() {
             None
         } else {
-            Some(upgrade_point.mut_new_costs().take_wasm().into())
+            Some(upgrade_point.take_new_gas_limits().into())
         };
         let activation_point = if !upgrade_point.has_activation_point() {
             None
@@ -48,6 +68,9 @@ impl TryFrom<UpgradeRequest> for UpgradeConfig {
             upgrade_installer_args,
             upgrade_installer_bytes,
             wasm_costs,
+            host_function_costs,
+            min_gas_price,
+            gas_limits,
             activation_point,
         ))
     }
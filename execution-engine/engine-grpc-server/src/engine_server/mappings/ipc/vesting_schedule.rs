@@ -0,0 +1,67 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::genesis::VestingSchedule;
+use engine_shared::motes::Motes;
+
+use crate::engine_server::{
+    ipc::ChainSpec_VestingSchedule,
+    mappings::{MappingError, ParsingError},
+};
+
+impl From<VestingSchedule> for ChainSpec_VestingSchedule {
+    fn from(vesting_schedule: VestingSchedule) -> Self {
+        let mut pb_vesting_schedule = ChainSpec_VestingSchedule::new();
+
+        pb_vesting_schedule.set_locked_amount(vesting_schedule.locked_amount().value().into());
+        pb_vesting_schedule.set_cliff_timestamp(vesting_schedule.cliff_timestamp());
+        pb_vesting_schedule.set_end_timestamp(vesting_schedule.end_timestamp());
+
+        pb_vesting_schedule
+    }
+}
+
+impl TryFrom<ChainSpec_VestingSchedule> for VestingSchedule {
+    type Error = MappingError;
+
+    fn try_from(mut pb_vesting_schedule: ChainSpec_VestingSchedule) -> Result<Self, Self::Error> {
+        let locked_amount = pb_vesting_schedule
+            .take_locked_amount()
+            .try_into()
+            .map(Motes::new)?;
+        let cliff_timestamp = pb_vesting_schedule.get_cliff_timestamp();
+        let end_timestamp = pb_vesting_schedule.get_end_timestamp();
+        if end_timestamp < cliff_timestamp {
+            return Err(
+                ParsingError::from("Vesting schedule must not end before its cliff").into(),
+            );
+        }
+        Ok(VestingSchedule::new(
+            locked_amount,
+            cliff_timestamp,
+            end_timestamp,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    #[test]
+    fn round_trip() {
+        let vesting_schedule = rand::random();
+        test_utils::protobuf_round_trip::<VestingSchedule, ChainSpec_VestingSchedule>(
+            vesting_schedule,
+        );
+    }
+
+    #[test]
+    fn should_fail_to_parse_schedule_ending_before_cliff() {
+        let mut pb_vesting_schedule = ChainSpec_VestingSchedule::new();
+        pb_vesting_schedule.set_locked_amount(Default::default());
+        pb_vesting_schedule.set_cliff_timestamp(2);
+        pb_vesting_schedule.set_end_timestamp(1);
+        assert!(VestingSchedule::try_from(pb_vesting_schedule).is_err());
+    }
+}
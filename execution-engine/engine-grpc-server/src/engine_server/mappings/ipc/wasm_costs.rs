@@ -15,6 +15,12 @@ impl From<WasmCosts> for ChainSpec_CostTable_WasmCosts {
             max_stack_height: wasm_costs.max_stack_height,
             opcodes_mul: wasm_costs.opcodes_mul,
             opcodes_div: wasm_costs.opcodes_div,
+            storage_per_byte: wasm_costs.storage_per_byte,
+            max_module_size: wasm_costs.max_module_size,
+            max_functions: wasm_costs.max_functions,
+            max_globals: wasm_costs.max_globals,
+            max_table_size: wasm_costs.max_table_size,
+            max_memory_pages: wasm_costs.max_memory_pages,
             ..Default::default()
         }
     }
@@ -33,6 +39,12 @@ impl From<ChainSpec_CostTable_WasmCosts> for WasmCosts {
             max_stack_height: pb_wasm_costs.max_stack_height,
             opcodes_mul: pb_wasm_costs.opcodes_mul,
             opcodes_div: pb_wasm_costs.opcodes_div,
+            storage_per_byte: pb_wasm_costs.storage_per_byte,
+            max_module_size: pb_wasm_costs.max_module_size,
+            max_functions: pb_wasm_costs.max_functions,
+            max_globals: pb_wasm_costs.max_globals,
+            max_table_size: pb_wasm_costs.max_table_size,
+            max_memory_pages: pb_wasm_costs.max_memory_pages,
         }
     }
 }
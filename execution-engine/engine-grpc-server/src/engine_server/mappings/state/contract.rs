@@ -2,7 +2,7 @@ use std::convert::{TryFrom, TryInto};
 
 use engine_shared::contract::Contract;
 
-use super::NamedKeyMap;
+use super::{EntryPointMap, NamedKeyMap};
 use crate::engine_server::{
     mappings::ParsingError,
     state::{self, NamedKey},
@@ -10,12 +10,15 @@ use crate::engine_server::{
 
 impl From<Contract> for state::Contract {
     fn from(contract: Contract) -> Self {
+        let entry_points: Vec<state::EntryPoint> =
+            EntryPointMap::new(contract.entry_points().clone()).into();
         let (bytes, named_keys, protocol_version) = contract.destructure();
         let mut pb_contract = state::Contract::new();
         let named_keys: Vec<NamedKey> = NamedKeyMap::new(named_keys).into();
         pb_contract.set_body(bytes);
         pb_contract.set_named_keys(named_keys.into());
         pb_contract.set_protocol_version(protocol_version.into());
+        pb_contract.set_entry_points(entry_points.into());
         pb_contract
     }
 }
@@ -25,8 +28,10 @@ impl TryFrom<state::Contract> for Contract {
 
     fn try_from(mut pb_contract: state::Contract) -> Result<Self, Self::Error> {
         let named_keys: NamedKeyMap = pb_contract.take_named_keys().into_vec().try_into()?;
+        let entry_points: EntryPointMap = pb_contract.take_entry_points().into_vec().try_into()?;
         let protocol_version = pb_contract.take_protocol_version().into();
-        let contract = Contract::new(pb_contract.body, named_keys.into_inner(), protocol_version);
+        let contract = Contract::new(pb_contract.body, named_keys.into_inner(), protocol_version)
+            .with_entry_points(entry_points.into_inner());
         Ok(contract)
     }
 }
@@ -0,0 +1,108 @@
+use std::convert::{TryFrom, TryInto};
+
+use types::{EntryPoint, EntryPoints, Parameter};
+
+use crate::engine_server::{
+    mappings::ParsingError,
+    state::{self, EntryPoint_Parameter},
+};
+
+impl From<Parameter> for EntryPoint_Parameter {
+    fn from(parameter: Parameter) -> Self {
+        let mut pb_parameter = EntryPoint_Parameter::new();
+        pb_parameter.set_name(parameter.name().to_string());
+        pb_parameter.set_cl_type(parameter.cl_type().clone().into());
+        pb_parameter
+    }
+}
+
+impl TryFrom<EntryPoint_Parameter> for Parameter {
+    type Error = ParsingError;
+
+    fn try_from(mut pb_parameter: EntryPoint_Parameter) -> Result<Self, Self::Error> {
+        let cl_type = pb_parameter.take_cl_type().try_into()?;
+        Ok(Parameter::new(pb_parameter.name, cl_type))
+    }
+}
+
+impl From<(String, EntryPoint)> for state::EntryPoint {
+    fn from((name, entry_point): (String, EntryPoint)) -> Self {
+        let mut pb_entry_point = state::EntryPoint::new();
+        pb_entry_point.set_name(name);
+        let parameters: Vec<EntryPoint_Parameter> = entry_point
+            .parameters()
+            .iter()
+            .cloned()
+            .map(Into::into)
+            .collect();
+        pb_entry_point.set_parameters(parameters.into());
+        pb_entry_point
+    }
+}
+
+impl TryFrom<state::EntryPoint> for (String, EntryPoint) {
+    type Error = ParsingError;
+
+    fn try_from(mut pb_entry_point: state::EntryPoint) -> Result<Self, Self::Error> {
+        let parameters = pb_entry_point
+            .take_parameters()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<Parameter>, ParsingError>>()?;
+        Ok((pb_entry_point.name, EntryPoint::new(parameters)))
+    }
+}
+
+/// Thin wrapper to allow us to implement `From` and `TryFrom` helpers to convert to and from
+/// `EntryPoints` and `Vec<state::EntryPoint>`.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct EntryPointMap(EntryPoints);
+
+impl EntryPointMap {
+    pub fn new(inner: EntryPoints) -> Self {
+        Self(inner)
+    }
+
+    pub fn into_inner(self) -> EntryPoints {
+        self.0
+    }
+}
+
+impl From<EntryPointMap> for Vec<state::EntryPoint> {
+    fn from(entry_point_map: EntryPointMap) -> Self {
+        entry_point_map.0.into_iter().map(Into::into).collect()
+    }
+}
+
+impl TryFrom<Vec<state::EntryPoint>> for EntryPointMap {
+    type Error = ParsingError;
+
+    fn try_from(pb_entry_points: Vec<state::EntryPoint>) -> Result<Self, Self::Error> {
+        let mut entry_point_map = EntryPointMap(EntryPoints::new());
+        for pb_entry_point in pb_entry_points {
+            let (name, entry_point) = pb_entry_point.try_into()?;
+            let _ = entry_point_map.0.insert(name, entry_point);
+        }
+        Ok(entry_point_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn map_round_trip(entry_points in gens::entry_points_arb(10)) {
+            let entry_point_map = EntryPointMap(entry_points);
+            test_utils::protobuf_round_trip::<EntryPointMap, Vec<state::EntryPoint>>(
+                entry_point_map
+            );
+        }
+    }
+}
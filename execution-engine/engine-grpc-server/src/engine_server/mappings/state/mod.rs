@@ -6,10 +6,12 @@ mod big_int;
 mod cl_type;
 mod cl_value;
 mod contract;
+mod entry_point;
 mod key;
 mod named_key;
 mod protocol_version;
 mod stored_value;
 mod uref;
 
+pub(crate) use entry_point::EntryPointMap;
 pub(crate) use named_key::NamedKeyMap;
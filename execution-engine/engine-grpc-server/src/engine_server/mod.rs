@@ -15,6 +15,7 @@ include!(concat!(
     "/../../../../generated_protobuf/transforms.rs"
 ));
 pub mod mappings;
+pub mod pop_state;
 
 use std::{
     collections::BTreeMap,
@@ -30,6 +31,8 @@ use grpc::{Error as GrpcError, RequestOptions, ServerBuilder, SingleResponse};
 use log::{info, warn, Level};
 
 use engine_core::engine_state::{
+    estimate::{EstimateRequest, EstimateResult},
+    execute_block::{ExecuteBlockRequest, ExecuteBlockResult},
     execute_request::ExecuteRequest,
     genesis::{GenesisConfig, GenesisResult},
     query::{QueryRequest, QueryResult},
@@ -38,36 +41,46 @@ use engine_core::engine_state::{
     EngineState, Error as EngineError,
 };
 use engine_shared::{
-    logging::{self, log_duration},
+    logging::{self, log_duration, log_metric},
     newtypes::{Blake2bHash, CorrelationId},
 };
 use engine_storage::global_state::{CommitResult, StateProvider};
-use types::{bytesrepr::ToBytes, ProtocolVersion};
+use types::{account::PublicKey, bytesrepr::ToBytes, ProtocolVersion};
 
 use self::{
     ipc::{
         BidStateRequest, BidStateResponse, ChainSpec_GenesisConfig, CommitRequest, CommitResponse,
-        DistributeRewardsRequest, DistributeRewardsResponse, ExecuteResponse, GenesisResponse,
-        QueryResponse, SlashRequest, SlashResponse, StepResponse, UnbondPayoutRequest,
-        UnbondPayoutResponse, UpgradeRequest, UpgradeResponse,
+        DistributeRewardsRequest, DistributeRewardsResponse, EstimateResponse,
+        ExecuteBlockResponse, ExecuteResponse, GenesisResponse, PopDelegationsResponse,
+        PopPendingRequestsResponse, PopRewardsResponse, PopValidatorsResponse, QueryResponse,
+        SlashRequest, SlashResponse, StepResponse, UnbondPayoutRequest, UnbondPayoutResponse,
+        UpgradeRequest, UpgradeResponse,
     },
     ipc_grpc::{ExecutionEngineService, ExecutionEngineServiceServer},
     mappings::{ParsingError, TransformMap},
+    pop_state::{PopStateReader, PopStateRequest, PopStateResponse, PopStateResult},
 };
 
 const METRIC_DURATION_COMMIT: &str = "commit_duration";
 const METRIC_DURATION_EXEC: &str = "exec_duration";
+const METRIC_DURATION_EXEC_BLOCK: &str = "exec_block_duration";
+const METRIC_DURATION_ESTIMATE: &str = "estimate_duration";
 const METRIC_DURATION_QUERY: &str = "query_duration";
 const METRIC_DURATION_GENESIS: &str = "genesis_duration";
 const METRIC_DURATION_UPGRADE: &str = "upgrade_duration";
 const METRIC_DURATION_STEP: &str = "step_duration";
+const METRIC_DURATION_POP_STATE: &str = "pop_state_duration";
+const METRIC_EXEC_BLOCK_REEXECUTED: &str = "exec_block_reexecuted";
 
 const TAG_RESPONSE_COMMIT: &str = "commit_response";
 const TAG_RESPONSE_EXEC: &str = "exec_response";
+const TAG_RESPONSE_EXEC_BLOCK: &str = "exec_block_response";
+const TAG_RESPONSE_ESTIMATE: &str = "estimate_response";
 const TAG_RESPONSE_QUERY: &str = "query_response";
 const TAG_RESPONSE_GENESIS: &str = "genesis_response";
 const TAG_RESPONSE_UPGRADE: &str = "upgrade_response";
 const TAG_RESPONSE_STEP: &str = "step_response";
+const TAG_RESPONSE_POP_STATE: &str = "pop_state_response";
 
 const UNIMPLEMENTED: &str = "unimplemented";
 
@@ -80,7 +93,7 @@ const DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
 // (outer layer) leading to cleaner design.
 impl<S> ExecutionEngineService for EngineState<S>
 where
-    S: StateProvider,
+    S: StateProvider + Sync,
     EngineError: From<S::Error>,
     S::Error: Into<engine_core::execution::Error> + Debug,
 {
@@ -210,6 +223,136 @@ where
         SingleResponse::completed(exec_response)
     }
 
+    fn execute_block(
+        &self,
+        _request_options: RequestOptions,
+        exec_block_request: ipc::ExecuteBlockRequest,
+    ) -> SingleResponse<ExecuteBlockResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let exec_block_request: ExecuteBlockRequest = match exec_block_request.try_into() {
+            Ok(ret) => ret,
+            Err(err) => {
+                return SingleResponse::completed(err);
+            }
+        };
+
+        let mut response = ExecuteBlockResponse::new();
+        match self.run_execute_block(correlation_id, exec_block_request) {
+            Ok(ExecuteBlockResult::Success {
+                post_state_hash,
+                execution_results,
+                reexecuted_deploys,
+            }) => {
+                log_reexecuted_deploys(correlation_id, reexecuted_deploys);
+                let deploy_results = execution_results.into_iter().map(Into::into);
+                let result = response.mut_success();
+                result.set_post_state_hash(post_state_hash.to_vec());
+                result.set_deploy_results(FromIterator::from_iter(deploy_results));
+            }
+            Ok(ExecuteBlockResult::Uncommitted {
+                effect,
+                execution_results,
+                reexecuted_deploys,
+            }) => {
+                log_reexecuted_deploys(correlation_id, reexecuted_deploys);
+                let deploy_results = execution_results.into_iter().map(Into::into);
+                let result = response.mut_success();
+                result.set_deploy_results(FromIterator::from_iter(deploy_results));
+                result.set_effect(effect.into());
+            }
+            Ok(ExecuteBlockResult::RootNotFound(hash)) => {
+                info!("execute block error: RootNotFound");
+                response.mut_missing_parent().set_hash(hash.to_vec());
+            }
+            Ok(ExecuteBlockResult::KeyNotFound(key)) => {
+                warn!("{:?} not found", key);
+                response.set_key_not_found(key.into());
+            }
+            Ok(ExecuteBlockResult::TypeMismatch(type_mismatch)) => {
+                warn!("{:?}", type_mismatch);
+                response.set_type_mismatch(type_mismatch.into());
+            }
+            Ok(ExecuteBlockResult::Serialization(error)) => {
+                warn!("{:?}", error);
+                response
+                    .mut_failed_transform()
+                    .set_message(format!("{:?}", error));
+            }
+            Err(error @ EngineError::MissingProposer) => {
+                warn!("Error {:?} when executing block", error);
+                response.mut_error().set_message(error.to_string());
+            }
+            Err(error) => {
+                warn!("Error {:?} when executing block", error);
+                response
+                    .mut_failed_transform()
+                    .set_message(error.to_string());
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_EXEC_BLOCK,
+            TAG_RESPONSE_EXEC_BLOCK,
+            start.elapsed(),
+        );
+        SingleResponse::completed(response)
+    }
+
+    fn estimate_gas(
+        &self,
+        _request_options: RequestOptions,
+        estimate_request: ipc::EstimateRequest,
+    ) -> SingleResponse<EstimateResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let estimate_request: EstimateRequest = match estimate_request.try_into() {
+            Ok(ret) => ret,
+            Err(err) => {
+                return SingleResponse::completed(err);
+            }
+        };
+
+        let mut response = EstimateResponse::new();
+        match self.run_estimate(correlation_id, estimate_request) {
+            Ok(EstimateResult::Success {
+                payment,
+                session,
+                finalize,
+            }) => {
+                let result = response.mut_success();
+                let mut total_cost = payment.cost();
+                result.set_payment(payment.into());
+                if let Some(session) = session {
+                    total_cost = total_cost + session.cost();
+                    result.set_session(session.into());
+                }
+                if let Some(finalize) = finalize {
+                    total_cost = total_cost + finalize.cost();
+                    result.set_finalize(finalize.into());
+                }
+                result.set_total_cost(total_cost.value().into());
+            }
+            Ok(EstimateResult::RootNotFound(hash)) => {
+                response.mut_missing_parent().set_hash(hash.to_vec());
+            }
+            Err(error) => {
+                response.mut_error().set_message(error.to_string());
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_ESTIMATE,
+            TAG_RESPONSE_ESTIMATE,
+            start.elapsed(),
+        );
+        SingleResponse::completed(response)
+    }
+
     fn commit(
         &self,
         _request_options: RequestOptions,
@@ -531,6 +674,81 @@ where
         );
         SingleResponse::completed(step_response)
     }
+
+    fn pop_delegations(
+        &self,
+        _request_options: RequestOptions,
+        pop_state_request: ipc::PopStateRequest,
+    ) -> SingleResponse<PopDelegationsResponse> {
+        run_pop_state(self, pop_state_request, pop_state::delegations)
+    }
+
+    fn pop_validators(
+        &self,
+        _request_options: RequestOptions,
+        pop_state_request: ipc::PopStateRequest,
+    ) -> SingleResponse<PopValidatorsResponse> {
+        run_pop_state(self, pop_state_request, pop_state::validators)
+    }
+
+    fn pop_rewards(
+        &self,
+        _request_options: RequestOptions,
+        pop_state_request: ipc::PopStateRequest,
+    ) -> SingleResponse<PopRewardsResponse> {
+        run_pop_state(self, pop_state_request, pop_state::rewards)
+    }
+
+    fn pop_pending_requests(
+        &self,
+        _request_options: RequestOptions,
+        pop_state_request: ipc::PopStateRequest,
+    ) -> SingleResponse<PopPendingRequestsResponse> {
+        run_pop_state(self, pop_state_request, pop_state::pending_requests)
+    }
+}
+
+/// Shared body of the PoP state endpoints: parses the request, decodes the state with `f` and
+/// records the duration.
+fn run_pop_state<S, R, F>(
+    engine_state: &EngineState<S>,
+    pop_state_request: ipc::PopStateRequest,
+    f: F,
+) -> SingleResponse<R>
+where
+    S: StateProvider,
+    EngineError: From<S::Error>,
+    S::Error: Into<engine_core::execution::Error> + Debug,
+    R: PopStateResponse + Send + 'static,
+    F: FnOnce(&PopStateReader<S>, Option<PublicKey>) -> Result<R::Success, String>,
+{
+    let start = Instant::now();
+    let correlation_id = CorrelationId::new();
+
+    let result = match PopStateRequest::try_from(pop_state_request) {
+        Ok(request) => pop_state::run(engine_state, correlation_id, request, f),
+        Err(error) => PopStateResult::Error(error.to_string()),
+    };
+    let mut response = R::default();
+    response.set_result(result);
+
+    log_duration(
+        correlation_id,
+        METRIC_DURATION_POP_STATE,
+        TAG_RESPONSE_POP_STATE,
+        start.elapsed(),
+    );
+    SingleResponse::completed(response)
+}
+
+fn log_reexecuted_deploys(correlation_id: CorrelationId, reexecuted_deploys: usize) {
+    log_metric(
+        correlation_id,
+        METRIC_EXEC_BLOCK_REEXECUTED,
+        TAG_RESPONSE_EXEC_BLOCK,
+        "reexecuted_deploys",
+        reexecuted_deploys as f64,
+    );
 }
 
 // Helper method which returns single DeployResult that is set to be a
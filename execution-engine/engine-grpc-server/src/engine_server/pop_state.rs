@@ -0,0 +1,358 @@
+//! Decodes the local state of the proof of profession contract at a given state hash.
+//!
+//! The layout of the PoP state is owned by the `pop-types` crate; this module only reads it through
+//! the public query path of the engine and converts it into protobuf messages.
+
+use std::{collections::BTreeMap, convert::TryFrom, fmt::Debug};
+
+use engine_core::{
+    engine_state::{
+        query::{QueryRequest, QueryResult},
+        EngineState, Error as EngineError,
+    },
+    execution,
+};
+use engine_shared::{
+    newtypes::{Blake2bHash, CorrelationId},
+    stored_value::StoredValue,
+};
+use engine_storage::global_state::StateProvider;
+use pop_types::{
+    keys, DelegationKey, Delegations, DurationQueue, RedelegateRequest, UnbondRequest,
+    UndelegateRequest,
+};
+use types::{
+    account::PublicKey,
+    bytesrepr::{FromBytes, ToBytes},
+    CLTyped, CLValue, Key, ProtocolVersion, U512,
+};
+
+use crate::engine_server::ipc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PopStateRequest {
+    pub state_hash: Blake2bHash,
+    pub protocol_version: ProtocolVersion,
+    pub public_key: Option<PublicKey>,
+}
+
+pub enum PopStateResult<T> {
+    Success(T),
+    RootNotFound(Blake2bHash),
+    Error(String),
+}
+
+/// A protobuf response to a PoP state query; all of them share the `success`, `missing_parent` and
+/// `error` variants.
+pub trait PopStateResponse: Default {
+    type Success;
+
+    fn set_result(&mut self, result: PopStateResult<Self::Success>);
+}
+
+macro_rules! impl_pop_state_response {
+    ($response:ty, $success:ty) => {
+        impl PopStateResponse for $response {
+            type Success = $success;
+
+            fn set_result(&mut self, result: PopStateResult<Self::Success>) {
+                match result {
+                    PopStateResult::Success(success) => self.set_success(success),
+                    PopStateResult::RootNotFound(hash) => {
+                        self.mut_missing_parent().set_hash(hash.to_vec())
+                    }
+                    PopStateResult::Error(message) => self.mut_error().set_message(message),
+                }
+            }
+        }
+    };
+}
+
+impl_pop_state_response!(ipc::PopDelegationsResponse, ipc::PopDelegations);
+impl_pop_state_response!(ipc::PopValidatorsResponse, ipc::PopValidators);
+impl_pop_state_response!(ipc::PopRewardsResponse, ipc::PopRewards);
+impl_pop_state_response!(ipc::PopPendingRequestsResponse, ipc::PopPendingRequests);
+
+/// Reads values of the PoP contract at a single state hash.
+pub struct PopStateReader<'a, S> {
+    engine_state: &'a EngineState<S>,
+    correlation_id: CorrelationId,
+    state_hash: Blake2bHash,
+    seed: [u8; 32],
+    named_keys: BTreeMap<String, Key>,
+}
+
+impl<'a, S> PopStateReader<'a, S>
+where
+    S: StateProvider,
+    EngineError: From<S::Error>,
+    S::Error: Into<execution::Error> + Debug,
+{
+    /// Returns `Ok(None)` if `state_hash` is not found.
+    fn new(
+        engine_state: &'a EngineState<S>,
+        correlation_id: CorrelationId,
+        state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+    ) -> Result<Option<Self>, String> {
+        let pop_uref = match engine_state.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => protocol_data.proof_of_stake(),
+            Ok(None) => return Err(format!("Invalid protocol version: {}", protocol_version)),
+            Err(error) => return Err(error.to_string()),
+        };
+
+        let query_request = QueryRequest::new(state_hash, Key::URef(pop_uref), vec![]);
+        let named_keys = match engine_state.run_query(correlation_id, query_request) {
+            Ok(QueryResult::Success(StoredValue::Contract(contract))) => {
+                contract.named_keys().to_owned()
+            }
+            Ok(QueryResult::RootNotFound) => return Ok(None),
+            Ok(_) => return Err(String::from("Proof of profession contract not found")),
+            Err(error) => return Err(error.to_string()),
+        };
+
+        Ok(Some(PopStateReader {
+            engine_state,
+            correlation_id,
+            state_hash,
+            seed: pop_uref.addr(),
+            named_keys,
+        }))
+    }
+
+    /// Reads a value written by `storage::write_local` in the PoP contract, or its default if it
+    /// was never written.
+    fn read_local<K, T>(&self, key: K) -> Result<T, String>
+    where
+        K: ToBytes,
+        T: CLTyped + FromBytes + Default,
+    {
+        let key_bytes = key.to_bytes().map_err(|error| error.to_string())?;
+        let key = Key::local(self.seed, &key_bytes);
+        let query_request = QueryRequest::new(self.state_hash, key, vec![]);
+        match self
+            .engine_state
+            .run_query(self.correlation_id, query_request)
+        {
+            Ok(QueryResult::Success(value)) => CLValue::try_from(value)
+                .map_err(|error| format!("{:?}", error))?
+                .into_t()
+                .map_err(|error| format!("{:?}", error)),
+            Ok(QueryResult::ValueNotFound(_)) => Ok(T::default()),
+            Ok(QueryResult::RootNotFound) => Err(String::from("Root not found")),
+            Ok(QueryResult::CircularReference(message)) => Err(message),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    fn delegations(&self) -> Result<Delegations, String> {
+        let mut table = BTreeMap::new();
+        for name in self.named_keys.keys() {
+            if let Some((delegation_key, amount)) =
+                pop_types::parse_delegation(name).map_err(|error| format!("{:?}", error))?
+            {
+                table.insert(delegation_key, amount);
+            }
+        }
+        Ok(Delegations::new(table))
+    }
+
+    fn bonding_amount(&self, public_key: &PublicKey) -> Result<U512, String> {
+        self.read_local(keys::bonding_amount_key(public_key))
+    }
+
+    fn commission_amount(&self, public_key: &PublicKey) -> Result<U512, String> {
+        self.read_local(keys::commission_amount_key(public_key))
+    }
+
+    fn reward_amount(&self, public_key: &PublicKey) -> Result<U512, String> {
+        self.read_local(keys::reward_amount_key(public_key))
+    }
+}
+
+/// Runs `f` against the PoP state requested by `request`.
+pub fn run<S, T, F>(
+    engine_state: &EngineState<S>,
+    correlation_id: CorrelationId,
+    request: PopStateRequest,
+    f: F,
+) -> PopStateResult<T>
+where
+    S: StateProvider,
+    EngineError: From<S::Error>,
+    S::Error: Into<execution::Error> + Debug,
+    F: FnOnce(&PopStateReader<S>, Option<PublicKey>) -> Result<T, String>,
+{
+    let reader = match PopStateReader::new(
+        engine_state,
+        correlation_id,
+        request.state_hash,
+        request.protocol_version,
+    ) {
+        Ok(Some(reader)) => reader,
+        Ok(None) => return PopStateResult::RootNotFound(request.state_hash),
+        Err(message) => return PopStateResult::Error(message),
+    };
+
+    match f(&reader, request.public_key) {
+        Ok(ret) => PopStateResult::Success(ret),
+        Err(message) => PopStateResult::Error(message),
+    }
+}
+
+pub fn delegations<S>(
+    reader: &PopStateReader<S>,
+    maybe_delegator: Option<PublicKey>,
+) -> Result<ipc::PopDelegations, String>
+where
+    S: StateProvider,
+    EngineError: From<S::Error>,
+    S::Error: Into<execution::Error> + Debug,
+{
+    let delegations = reader.delegations()?;
+    let mut ret = ipc::PopDelegations::new();
+    for (
+        DelegationKey {
+            delegator,
+            validator,
+        },
+        amount,
+    ) in delegations.iter()
+    {
+        if maybe_delegator.map_or(false, |public_key| public_key != *delegator) {
+            continue;
+        }
+        let mut pb_delegation = ipc::PopDelegation::new();
+        pb_delegation.set_delegator(delegator.as_bytes().to_vec());
+        pb_delegation.set_validator(validator.as_bytes().to_vec());
+        pb_delegation.set_amount((*amount).into());
+        ret.mut_delegations().push(pb_delegation);
+    }
+    Ok(ret)
+}
+
+pub fn validators<S>(
+    reader: &PopStateReader<S>,
+    maybe_validator: Option<PublicKey>,
+) -> Result<ipc::PopValidators, String>
+where
+    S: StateProvider,
+    EngineError: From<S::Error>,
+    S::Error: Into<execution::Error> + Debug,
+{
+    let delegations = reader.delegations()?;
+    let mut ret = ipc::PopValidators::new();
+    for (validator, delegated_amount) in delegations.validators() {
+        if maybe_validator.map_or(false, |public_key| public_key != validator) {
+            continue;
+        }
+        let mut pb_validator = ipc::PopValidator::new();
+        pb_validator.set_public_key(validator.as_bytes().to_vec());
+        pb_validator.set_bonded_amount(reader.bonding_amount(&validator)?.into());
+        pb_validator.set_delegated_amount(delegated_amount.into());
+        pb_validator.set_commission(reader.commission_amount(&validator)?.into());
+        ret.mut_validators().push(pb_validator);
+    }
+    Ok(ret)
+}
+
+/// Rewards are kept per account, so without a `public_key` every account which appears in the
+/// delegations is reported.
+pub fn rewards<S>(
+    reader: &PopStateReader<S>,
+    maybe_account: Option<PublicKey>,
+) -> Result<ipc::PopRewards, String>
+where
+    S: StateProvider,
+    EngineError: From<S::Error>,
+    S::Error: Into<execution::Error> + Debug,
+{
+    let accounts: Vec<PublicKey> = match maybe_account {
+        Some(account) => vec![account],
+        None => {
+            let delegations = reader.delegations()?;
+            let mut accounts: Vec<PublicKey> = delegations
+                .iter()
+                .flat_map(|(key, _)| vec![key.delegator, key.validator])
+                .collect();
+            accounts.sort();
+            accounts.dedup();
+            accounts
+        }
+    };
+
+    let mut ret = ipc::PopRewards::new();
+    for account in accounts {
+        let mut pb_reward = ipc::PopReward::new();
+        pb_reward.set_public_key(account.as_bytes().to_vec());
+        pb_reward.set_reward(reader.reward_amount(&account)?.into());
+        pb_reward.set_commission(reader.commission_amount(&account)?.into());
+        ret.mut_rewards().push(pb_reward);
+    }
+    Ok(ret)
+}
+
+pub fn pending_requests<S>(
+    reader: &PopStateReader<S>,
+    maybe_account: Option<PublicKey>,
+) -> Result<ipc::PopPendingRequests, String>
+where
+    S: StateProvider,
+    EngineError: From<S::Error>,
+    S::Error: Into<execution::Error> + Debug,
+{
+    let is_requested_by = |public_key: &PublicKey| maybe_account.map_or(true, |a| a == *public_key);
+    let mut ret = ipc::PopPendingRequests::new();
+
+    let unbonds: DurationQueue<UnbondRequest> = reader.read_local(keys::UNBOND_REQUEST_QUEUE)?;
+    for entry in unbonds.0 {
+        let request = entry.item;
+        if !is_requested_by(&request.requester) {
+            continue;
+        }
+        let mut pb_request = ipc::PopUnbondRequest::new();
+        pb_request.set_requester(request.requester.as_bytes().to_vec());
+        if let Some(amount) = request.maybe_amount {
+            pb_request.set_amount(amount.into());
+        }
+        pb_request.set_timestamp(entry.timestamp.into());
+        ret.mut_unbonds().push(pb_request);
+    }
+
+    let undelegations: DurationQueue<UndelegateRequest> =
+        reader.read_local(keys::UNDELEGATE_REQUEST_QUEUE)?;
+    for entry in undelegations.0 {
+        let request = entry.item;
+        if !is_requested_by(&request.delegator) {
+            continue;
+        }
+        let mut pb_request = ipc::PopUndelegateRequest::new();
+        pb_request.set_delegator(request.delegator.as_bytes().to_vec());
+        pb_request.set_validator(request.validator.as_bytes().to_vec());
+        if let Some(amount) = request.maybe_amount {
+            pb_request.set_amount(amount.into());
+        }
+        pb_request.set_timestamp(entry.timestamp.into());
+        ret.mut_undelegations().push(pb_request);
+    }
+
+    let redelegations: DurationQueue<RedelegateRequest> =
+        reader.read_local(keys::REDELEGATE_REQUEST_QUEUE)?;
+    for entry in redelegations.0 {
+        let request = entry.item;
+        if !is_requested_by(&request.delegator) {
+            continue;
+        }
+        let mut pb_request = ipc::PopRedelegateRequest::new();
+        pb_request.set_delegator(request.delegator.as_bytes().to_vec());
+        pb_request.set_src_validator(request.src_validator.as_bytes().to_vec());
+        pb_request.set_dest_validator(request.dest_validator.as_bytes().to_vec());
+        if let Some(amount) = request.maybe_amount {
+            pb_request.set_amount(amount.into());
+        }
+        pb_request.set_timestamp(entry.timestamp.into());
+        ret.mut_redelegations().push(pb_request);
+    }
+
+    Ok(ret)
+}
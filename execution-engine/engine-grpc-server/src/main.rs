@@ -107,6 +107,28 @@ const ARG_ENABLE_BONDING: &str = "enable-bonding";
 const ARG_ENABLE_BONDING_SHORT: &str = "b";
 const ARG_ENABLE_BONDING_HELP: &str = "Enable bonding";
 
+// parallel execution
+const ARG_PARALLEL_EXECUTION_THREADS: &str = "parallel-execution-threads";
+const ARG_PARALLEL_EXECUTION_THREADS_DEFAULT: &str = "1";
+const ARG_PARALLEL_EXECUTION_THREADS_VALUE: &str = "NUM";
+const ARG_PARALLEL_EXECUTION_THREADS_HELP: &str =
+    "Number of threads on which the deploys of a request are executed speculatively";
+const ARG_PARALLEL_EXECUTION_THREADS_EXPECT: &str =
+    "expected valid parallel execution thread count";
+
+// debug log
+const ARG_DISABLE_DEBUG_LOG: &str = "disable-debug-log";
+const ARG_DISABLE_DEBUG_LOG_HELP: &str =
+    "Discard the messages printed by contracts instead of returning them with deploy results";
+
+// module cache
+const ARG_MODULE_CACHE_SIZE: &str = "module-cache-size";
+const ARG_MODULE_CACHE_SIZE_DEFAULT: &str = "256";
+const ARG_MODULE_CACHE_SIZE_VALUE: &str = "NUM";
+const ARG_MODULE_CACHE_SIZE_HELP: &str =
+    "Number of deserialized stored contracts kept in memory between deploys, 0 to disable caching";
+const ARG_MODULE_CACHE_SIZE_EXPECT: &str = "expected valid module cache size";
+
 // runnable
 const SIGINT_HANDLE_EXPECT: &str = "Error setting Ctrl-C handler";
 const RUNNABLE_CHECK_INTERVAL_SECONDS: u64 = 3;
@@ -239,6 +261,27 @@ fn get_args() -> ArgMatches<'static> {
                 .long(ARG_ENABLE_BONDING)
                 .help(ARG_ENABLE_BONDING_HELP),
         )
+        .arg(
+            Arg::with_name(ARG_PARALLEL_EXECUTION_THREADS)
+                .long(ARG_PARALLEL_EXECUTION_THREADS)
+                .takes_value(true)
+                .default_value(ARG_PARALLEL_EXECUTION_THREADS_DEFAULT)
+                .value_name(ARG_PARALLEL_EXECUTION_THREADS_VALUE)
+                .help(ARG_PARALLEL_EXECUTION_THREADS_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_DISABLE_DEBUG_LOG)
+                .long(ARG_DISABLE_DEBUG_LOG)
+                .help(ARG_DISABLE_DEBUG_LOG_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_MODULE_CACHE_SIZE)
+                .long(ARG_MODULE_CACHE_SIZE)
+                .takes_value(true)
+                .default_value(ARG_MODULE_CACHE_SIZE_DEFAULT)
+                .value_name(ARG_MODULE_CACHE_SIZE_VALUE)
+                .help(ARG_MODULE_CACHE_SIZE_HELP),
+        )
         .arg(
             Arg::with_name(ARG_SOCKET)
                 .required(true)
@@ -304,9 +347,23 @@ fn get_engine_config(arg_matches: &ArgMatches) -> EngineConfig {
     // feature flags go here
     let use_system_contracts = arg_matches.is_present(ARG_USE_SYSTEM_CONTRACTS);
     let enable_bonding = arg_matches.is_present(ARG_ENABLE_BONDING);
+    let parallel_execution_threads = arg_matches
+        .value_of(ARG_PARALLEL_EXECUTION_THREADS)
+        .map(str::parse)
+        .expect(ARG_PARALLEL_EXECUTION_THREADS_EXPECT)
+        .expect(ARG_PARALLEL_EXECUTION_THREADS_EXPECT);
+    let disable_debug_log = arg_matches.is_present(ARG_DISABLE_DEBUG_LOG);
+    let module_cache_size = arg_matches
+        .value_of(ARG_MODULE_CACHE_SIZE)
+        .map(str::parse)
+        .expect(ARG_MODULE_CACHE_SIZE_EXPECT)
+        .expect(ARG_MODULE_CACHE_SIZE_EXPECT);
     EngineConfig::new()
         .with_use_system_contracts(use_system_contracts)
         .with_enable_bonding(enable_bonding)
+        .with_parallel_execution_threads(parallel_execution_threads)
+        .with_disable_debug_log(disable_debug_log)
+        .with_module_cache_size(module_cache_size)
 }
 
 /// Builds and returns a gRPC server.
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 
 use types::{
     bytesrepr::{self, Error, FromBytes, ToBytes},
-    Key, ProtocolVersion,
+    EntryPoints, Key, ProtocolVersion,
 };
 
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -10,6 +10,7 @@ pub struct Contract {
     bytes: Vec<u8>,
     named_keys: BTreeMap<String, Key>,
     protocol_version: ProtocolVersion,
+    entry_points: EntryPoints,
 }
 
 impl Contract {
@@ -22,9 +23,17 @@ impl Contract {
             bytes,
             named_keys,
             protocol_version,
+            entry_points: EntryPoints::new(),
         }
     }
 
+    /// Declares the entry points the contract can be called through. A contract without entry
+    /// points is called through its `call` function, with any arguments.
+    pub fn with_entry_points(mut self, entry_points: EntryPoints) -> Self {
+        self.entry_points = entry_points;
+        self
+    }
+
     pub fn named_keys_append(&mut self, keys: &mut BTreeMap<String, Key>) {
         self.named_keys.append(keys);
     }
@@ -49,6 +58,10 @@ impl Contract {
         self.protocol_version
     }
 
+    pub fn entry_points(&self) -> &EntryPoints {
+        &self.entry_points
+    }
+
     pub fn take_named_keys(self) -> BTreeMap<String, Key> {
         self.named_keys
     }
@@ -60,6 +73,7 @@ impl ToBytes for Contract {
         result.append(&mut self.bytes.to_bytes()?);
         result.append(&mut self.named_keys.to_bytes()?);
         result.append(&mut self.protocol_version.to_bytes()?);
+        result.append(&mut self.entry_points.to_bytes()?);
         Ok(result)
     }
 
@@ -67,6 +81,7 @@ impl ToBytes for Contract {
         self.bytes.serialized_length()
             + self.named_keys.serialized_length()
             + self.protocol_version.serialized_length()
+            + self.entry_points.serialized_length()
     }
 }
 
@@ -75,13 +90,15 @@ impl FromBytes for Contract {
         let (bytes, rem1) = Vec::<u8>::from_bytes(bytes)?;
         let (named_keys, rem2) = BTreeMap::<String, Key>::from_bytes(rem1)?;
         let (protocol_version, rem3) = ProtocolVersion::from_bytes(rem2)?;
+        let (entry_points, rem4) = EntryPoints::from_bytes(rem3)?;
         Ok((
             Contract {
                 bytes,
                 named_keys,
                 protocol_version,
+                entry_points,
             },
-            rem3,
+            rem4,
         ))
     }
 }
@@ -89,15 +106,20 @@ impl FromBytes for Contract {
 pub mod gens {
     use proptest::{collection::vec, prelude::*};
 
-    use types::gens::{named_keys_arb, protocol_version_arb};
+    use types::gens::{entry_points_arb, named_keys_arb, protocol_version_arb};
 
     use super::Contract;
 
     pub fn contract_arb() -> impl Strategy<Value = Contract> {
         protocol_version_arb().prop_flat_map(move |protocol_version_arb| {
             named_keys_arb(20).prop_flat_map(move |urefs| {
-                vec(any::<u8>(), 1..1000)
-                    .prop_map(move |body| Contract::new(body, urefs.clone(), protocol_version_arb))
+                entry_points_arb(3).prop_flat_map(move |entry_points| {
+                    let urefs = urefs.clone();
+                    vec(any::<u8>(), 1..1000).prop_map(move |body| {
+                        Contract::new(body, urefs.clone(), protocol_version_arb)
+                            .with_entry_points(entry_points.clone())
+                    })
+                })
             })
         })
     }
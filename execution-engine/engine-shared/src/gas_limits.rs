@@ -0,0 +1,109 @@
+use types::bytesrepr::{self, FromBytes, ToBytes, U64_SERIALIZED_LENGTH};
+
+const NUM_FIELDS: usize = 3;
+pub const GAS_LIMITS_SERIALIZED_LENGTH: usize = NUM_FIELDS * U64_SERIALIZED_LENGTH;
+
+/// The default for [`GasLimits::max_payment_cost`].
+pub const DEFAULT_MAX_PAYMENT_COST: u64 = 1_000_000_000_000_000;
+
+/// Bounds on the gas deploys and blocks can spend, associated with a protocol version.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GasLimits {
+    /// Most motes the payment code of a deploy can spend, which also bounds its gas
+    pub max_payment_cost: u64,
+    /// Most gas the session code of a deploy can spend, whatever its payment
+    pub max_session_gas: u64,
+    /// Gas the deploys of a block can spend before the remaining ones are left out of it
+    pub max_block_gas: u64,
+}
+
+/// Provides the limits used before they were configurable: the former `MAX_PAYMENT` and no
+/// session or block gas limits.
+impl Default for GasLimits {
+    fn default() -> Self {
+        GasLimits {
+            max_payment_cost: DEFAULT_MAX_PAYMENT_COST,
+            max_session_gas: std::u64::MAX,
+            max_block_gas: std::u64::MAX,
+        }
+    }
+}
+
+impl ToBytes for GasLimits {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.append(&mut self.max_payment_cost.to_bytes()?);
+        ret.append(&mut self.max_session_gas.to_bytes()?);
+        ret.append(&mut self.max_block_gas.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        GAS_LIMITS_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for GasLimits {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (max_payment_cost, rem) = FromBytes::from_bytes(bytes)?;
+        let (max_session_gas, rem) = FromBytes::from_bytes(rem)?;
+        let (max_block_gas, rem) = FromBytes::from_bytes(rem)?;
+        Ok((
+            GasLimits {
+                max_payment_cost,
+                max_session_gas,
+                max_block_gas,
+            },
+            rem,
+        ))
+    }
+}
+
+pub mod gens {
+    use proptest::{num, prop_compose};
+
+    use super::GasLimits;
+
+    prop_compose! {
+        pub fn gas_limits_arb()(
+            max_payment_cost in num::u64::ANY,
+            max_session_gas in num::u64::ANY,
+            max_block_gas in num::u64::ANY,
+        ) -> GasLimits {
+            GasLimits {
+                max_payment_cost,
+                max_session_gas,
+                max_block_gas,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::bytesrepr;
+
+    use super::{gens, GasLimits};
+
+    #[test]
+    fn should_serialize_and_deserialize() {
+        let gas_limits = GasLimits {
+            max_payment_cost: 1_000,
+            max_session_gas: 2_000,
+            max_block_gas: 3_000,
+        };
+        bytesrepr::test_serialization_roundtrip(&gas_limits);
+        bytesrepr::test_serialization_roundtrip(&GasLimits::default());
+    }
+
+    proptest! {
+        #[test]
+        fn should_serialize_and_deserialize_with_arbitrary_values(
+            gas_limits in gens::gas_limits_arb()
+        ) {
+            bytesrepr::test_serialization_roundtrip(&gas_limits);
+        }
+    }
+}
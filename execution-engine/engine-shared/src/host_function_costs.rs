@@ -0,0 +1,361 @@
+use types::bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH};
+
+const NUM_HOST_FUNCTIONS: usize = 46;
+pub const HOST_FUNCTION_COST_SERIALIZED_LENGTH: usize = 2 * U32_SERIALIZED_LENGTH;
+pub const HOST_FUNCTION_COSTS_SERIALIZED_LENGTH: usize =
+    NUM_HOST_FUNCTIONS * HOST_FUNCTION_COST_SERIALIZED_LENGTH;
+
+const DEFAULT_EMIT_EVENT_COST_PER_BYTE: u32 = 10;
+const DEFAULT_RANDOM_BYTES_COST_PER_BYTE: u32 = 10;
+const DEFAULT_HASH_COST_PER_BYTE: u32 = 10;
+const DEFAULT_VERIFY_ED25519_COST_PER_BYTE: u32 = 100;
+const DEFAULT_PRINT_COST_PER_BYTE: u32 = 10;
+
+/// The gas charged for a call to a host function.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct HostFunctionCost {
+    /// Charged for every call
+    pub base: u32,
+    /// Charged per byte of the sizes passed to the function
+    pub per_byte: u32,
+}
+
+impl HostFunctionCost {
+    pub fn new(base: u32, per_byte: u32) -> Self {
+        HostFunctionCost { base, per_byte }
+    }
+}
+
+impl ToBytes for HostFunctionCost {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.append(&mut self.base.to_bytes()?);
+        ret.append(&mut self.per_byte.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        HOST_FUNCTION_COST_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for HostFunctionCost {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (base, rem) = FromBytes::from_bytes(bytes)?;
+        let (per_byte, rem) = FromBytes::from_bytes(rem)?;
+        Ok((HostFunctionCost { base, per_byte }, rem))
+    }
+}
+
+/// The gas charged for calls to each host function, on top of the wasm opcodes executed by the
+/// contract.
+///
+/// The default table charges nothing for the host functions which were free before it was
+/// introduced.  Emitting an event is charged per byte of its topic and payload, random bytes per
+/// byte returned, hashing per byte of input, signature verification per byte of the public key,
+/// message and signature, and printing per byte of the message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HostFunctionCosts {
+    pub write: HostFunctionCost,
+    pub write_local: HostFunctionCost,
+    pub read_value: HostFunctionCost,
+    pub read_value_local: HostFunctionCost,
+    pub add: HostFunctionCost,
+    pub add_local: HostFunctionCost,
+    pub new_uref: HostFunctionCost,
+    pub ret: HostFunctionCost,
+    pub call_contract: HostFunctionCost,
+    pub get_arg: HostFunctionCost,
+    pub get_key: HostFunctionCost,
+    pub has_key: HostFunctionCost,
+    pub put_key: HostFunctionCost,
+    pub store_function: HostFunctionCost,
+    pub store_function_at_hash: HostFunctionCost,
+    pub is_valid_uref: HostFunctionCost,
+    pub revert: HostFunctionCost,
+    pub add_associated_key: HostFunctionCost,
+    pub remove_associated_key: HostFunctionCost,
+    pub update_associated_key: HostFunctionCost,
+    pub set_action_threshold: HostFunctionCost,
+    pub load_named_keys: HostFunctionCost,
+    pub remove_key: HostFunctionCost,
+    pub get_caller: HostFunctionCost,
+    pub get_blocktime: HostFunctionCost,
+    pub create_purse: HostFunctionCost,
+    pub transfer_to_account: HostFunctionCost,
+    pub transfer_from_purse_to_account: HostFunctionCost,
+    pub transfer_from_purse_to_purse: HostFunctionCost,
+    pub get_balance: HostFunctionCost,
+    pub get_phase: HostFunctionCost,
+    pub upgrade_contract_at_uref: HostFunctionCost,
+    pub get_system_contract: HostFunctionCost,
+    pub get_main_purse: HostFunctionCost,
+    pub get_arg_size: HostFunctionCost,
+    pub read_host_buffer: HostFunctionCost,
+    pub emit_event: HostFunctionCost,
+    pub blake2b: HostFunctionCost,
+    pub sha256: HostFunctionCost,
+    pub keccak256: HostFunctionCost,
+    pub verify_ed25519: HostFunctionCost,
+    pub get_block_height: HostFunctionCost,
+    pub get_proposer: HostFunctionCost,
+    pub get_deploy_hash: HostFunctionCost,
+    pub random_bytes: HostFunctionCost,
+    pub print: HostFunctionCost,
+}
+
+impl Default for HostFunctionCosts {
+    fn default() -> Self {
+        HostFunctionCosts {
+            write: HostFunctionCost::default(),
+            write_local: HostFunctionCost::default(),
+            read_value: HostFunctionCost::default(),
+            read_value_local: HostFunctionCost::default(),
+            add: HostFunctionCost::default(),
+            add_local: HostFunctionCost::default(),
+            new_uref: HostFunctionCost::default(),
+            ret: HostFunctionCost::default(),
+            call_contract: HostFunctionCost::default(),
+            get_arg: HostFunctionCost::default(),
+            get_key: HostFunctionCost::default(),
+            has_key: HostFunctionCost::default(),
+            put_key: HostFunctionCost::default(),
+            store_function: HostFunctionCost::default(),
+            store_function_at_hash: HostFunctionCost::default(),
+            is_valid_uref: HostFunctionCost::default(),
+            revert: HostFunctionCost::default(),
+            add_associated_key: HostFunctionCost::default(),
+            remove_associated_key: HostFunctionCost::default(),
+            update_associated_key: HostFunctionCost::default(),
+            set_action_threshold: HostFunctionCost::default(),
+            load_named_keys: HostFunctionCost::default(),
+            remove_key: HostFunctionCost::default(),
+            get_caller: HostFunctionCost::default(),
+            get_blocktime: HostFunctionCost::default(),
+            create_purse: HostFunctionCost::default(),
+            transfer_to_account: HostFunctionCost::default(),
+            transfer_from_purse_to_account: HostFunctionCost::default(),
+            transfer_from_purse_to_purse: HostFunctionCost::default(),
+            get_balance: HostFunctionCost::default(),
+            get_phase: HostFunctionCost::default(),
+            upgrade_contract_at_uref: HostFunctionCost::default(),
+            get_system_contract: HostFunctionCost::default(),
+            get_main_purse: HostFunctionCost::default(),
+            get_arg_size: HostFunctionCost::default(),
+            read_host_buffer: HostFunctionCost::default(),
+            emit_event: HostFunctionCost::new(0, DEFAULT_EMIT_EVENT_COST_PER_BYTE),
+            blake2b: HostFunctionCost::new(0, DEFAULT_HASH_COST_PER_BYTE),
+            sha256: HostFunctionCost::new(0, DEFAULT_HASH_COST_PER_BYTE),
+            keccak256: HostFunctionCost::new(0, DEFAULT_HASH_COST_PER_BYTE),
+            verify_ed25519: HostFunctionCost::new(0, DEFAULT_VERIFY_ED25519_COST_PER_BYTE),
+            get_block_height: HostFunctionCost::default(),
+            get_proposer: HostFunctionCost::default(),
+            get_deploy_hash: HostFunctionCost::default(),
+            random_bytes: HostFunctionCost::new(0, DEFAULT_RANDOM_BYTES_COST_PER_BYTE),
+            print: HostFunctionCost::new(0, DEFAULT_PRINT_COST_PER_BYTE),
+        }
+    }
+}
+
+impl ToBytes for HostFunctionCosts {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.append(&mut self.write.to_bytes()?);
+        ret.append(&mut self.write_local.to_bytes()?);
+        ret.append(&mut self.read_value.to_bytes()?);
+        ret.append(&mut self.read_value_local.to_bytes()?);
+        ret.append(&mut self.add.to_bytes()?);
+        ret.append(&mut self.add_local.to_bytes()?);
+        ret.append(&mut self.new_uref.to_bytes()?);
+        ret.append(&mut self.ret.to_bytes()?);
+        ret.append(&mut self.call_contract.to_bytes()?);
+        ret.append(&mut self.get_arg.to_bytes()?);
+        ret.append(&mut self.get_key.to_bytes()?);
+        ret.append(&mut self.has_key.to_bytes()?);
+        ret.append(&mut self.put_key.to_bytes()?);
+        ret.append(&mut self.store_function.to_bytes()?);
+        ret.append(&mut self.store_function_at_hash.to_bytes()?);
+        ret.append(&mut self.is_valid_uref.to_bytes()?);
+        ret.append(&mut self.revert.to_bytes()?);
+        ret.append(&mut self.add_associated_key.to_bytes()?);
+        ret.append(&mut self.remove_associated_key.to_bytes()?);
+        ret.append(&mut self.update_associated_key.to_bytes()?);
+        ret.append(&mut self.set_action_threshold.to_bytes()?);
+        ret.append(&mut self.load_named_keys.to_bytes()?);
+        ret.append(&mut self.remove_key.to_bytes()?);
+        ret.append(&mut self.get_caller.to_bytes()?);
+        ret.append(&mut self.get_blocktime.to_bytes()?);
+        ret.append(&mut self.create_purse.to_bytes()?);
+        ret.append(&mut self.transfer_to_account.to_bytes()?);
+        ret.append(&mut self.transfer_from_purse_to_account.to_bytes()?);
+        ret.append(&mut self.transfer_from_purse_to_purse.to_bytes()?);
+        ret.append(&mut self.get_balance.to_bytes()?);
+        ret.append(&mut self.get_phase.to_bytes()?);
+        ret.append(&mut self.upgrade_contract_at_uref.to_bytes()?);
+        ret.append(&mut self.get_system_contract.to_bytes()?);
+        ret.append(&mut self.get_main_purse.to_bytes()?);
+        ret.append(&mut self.get_arg_size.to_bytes()?);
+        ret.append(&mut self.read_host_buffer.to_bytes()?);
+        ret.append(&mut self.emit_event.to_bytes()?);
+        ret.append(&mut self.blake2b.to_bytes()?);
+        ret.append(&mut self.sha256.to_bytes()?);
+        ret.append(&mut self.keccak256.to_bytes()?);
+        ret.append(&mut self.verify_ed25519.to_bytes()?);
+        ret.append(&mut self.get_block_height.to_bytes()?);
+        ret.append(&mut self.get_proposer.to_bytes()?);
+        ret.append(&mut self.get_deploy_hash.to_bytes()?);
+        ret.append(&mut self.random_bytes.to_bytes()?);
+        ret.append(&mut self.print.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        HOST_FUNCTION_COSTS_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for HostFunctionCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let rem = bytes;
+        let (write, rem) = FromBytes::from_bytes(rem)?;
+        let (write_local, rem) = FromBytes::from_bytes(rem)?;
+        let (read_value, rem) = FromBytes::from_bytes(rem)?;
+        let (read_value_local, rem) = FromBytes::from_bytes(rem)?;
+        let (add, rem) = FromBytes::from_bytes(rem)?;
+        let (add_local, rem) = FromBytes::from_bytes(rem)?;
+        let (new_uref, rem) = FromBytes::from_bytes(rem)?;
+        let (ret, rem) = FromBytes::from_bytes(rem)?;
+        let (call_contract, rem) = FromBytes::from_bytes(rem)?;
+        let (get_arg, rem) = FromBytes::from_bytes(rem)?;
+        let (get_key, rem) = FromBytes::from_bytes(rem)?;
+        let (has_key, rem) = FromBytes::from_bytes(rem)?;
+        let (put_key, rem) = FromBytes::from_bytes(rem)?;
+        let (store_function, rem) = FromBytes::from_bytes(rem)?;
+        let (store_function_at_hash, rem) = FromBytes::from_bytes(rem)?;
+        let (is_valid_uref, rem) = FromBytes::from_bytes(rem)?;
+        let (revert, rem) = FromBytes::from_bytes(rem)?;
+        let (add_associated_key, rem) = FromBytes::from_bytes(rem)?;
+        let (remove_associated_key, rem) = FromBytes::from_bytes(rem)?;
+        let (update_associated_key, rem) = FromBytes::from_bytes(rem)?;
+        let (set_action_threshold, rem) = FromBytes::from_bytes(rem)?;
+        let (load_named_keys, rem) = FromBytes::from_bytes(rem)?;
+        let (remove_key, rem) = FromBytes::from_bytes(rem)?;
+        let (get_caller, rem) = FromBytes::from_bytes(rem)?;
+        let (get_blocktime, rem) = FromBytes::from_bytes(rem)?;
+        let (create_purse, rem) = FromBytes::from_bytes(rem)?;
+        let (transfer_to_account, rem) = FromBytes::from_bytes(rem)?;
+        let (transfer_from_purse_to_account, rem) = FromBytes::from_bytes(rem)?;
+        let (transfer_from_purse_to_purse, rem) = FromBytes::from_bytes(rem)?;
+        let (get_balance, rem) = FromBytes::from_bytes(rem)?;
+        let (get_phase, rem) = FromBytes::from_bytes(rem)?;
+        let (upgrade_contract_at_uref, rem) = FromBytes::from_bytes(rem)?;
+        let (get_system_contract, rem) = FromBytes::from_bytes(rem)?;
+        let (get_main_purse, rem) = FromBytes::from_bytes(rem)?;
+        let (get_arg_size, rem) = FromBytes::from_bytes(rem)?;
+        let (read_host_buffer, rem) = FromBytes::from_bytes(rem)?;
+        let (emit_event, rem) = FromBytes::from_bytes(rem)?;
+        let (blake2b, rem) = FromBytes::from_bytes(rem)?;
+        let (sha256, rem) = FromBytes::from_bytes(rem)?;
+        let (keccak256, rem) = FromBytes::from_bytes(rem)?;
+        let (verify_ed25519, rem) = FromBytes::from_bytes(rem)?;
+        let (get_block_height, rem) = FromBytes::from_bytes(rem)?;
+        let (get_proposer, rem) = FromBytes::from_bytes(rem)?;
+        let (get_deploy_hash, rem) = FromBytes::from_bytes(rem)?;
+        let (random_bytes, rem) = FromBytes::from_bytes(rem)?;
+        let (print, rem) = FromBytes::from_bytes(rem)?;
+        let host_function_costs = HostFunctionCosts {
+            write,
+            write_local,
+            read_value,
+            read_value_local,
+            add,
+            add_local,
+            new_uref,
+            ret,
+            call_contract,
+            get_arg,
+            get_key,
+            has_key,
+            put_key,
+            store_function,
+            store_function_at_hash,
+            is_valid_uref,
+            revert,
+            add_associated_key,
+            remove_associated_key,
+            update_associated_key,
+            set_action_threshold,
+            load_named_keys,
+            remove_key,
+            get_caller,
+            get_blocktime,
+            create_purse,
+            transfer_to_account,
+            transfer_from_purse_to_account,
+            transfer_from_purse_to_purse,
+            get_balance,
+            get_phase,
+            upgrade_contract_at_uref,
+            get_system_contract,
+            get_main_purse,
+            get_arg_size,
+            read_host_buffer,
+            emit_event,
+            blake2b,
+            sha256,
+            keccak256,
+            verify_ed25519,
+            get_block_height,
+            get_proposer,
+            get_deploy_hash,
+            random_bytes,
+            print,
+        };
+        Ok((host_function_costs, rem))
+    }
+}
+
+pub mod gens {
+    use proptest::{collection::vec, num, prelude::Strategy};
+
+    use types::bytesrepr;
+
+    use super::{HostFunctionCosts, HOST_FUNCTION_COSTS_SERIALIZED_LENGTH};
+
+    /// Every sequence of bytes of the right length is a valid serialized cost table.
+    pub fn host_function_costs_arb() -> impl Strategy<Value = HostFunctionCosts> {
+        vec(num::u8::ANY, HOST_FUNCTION_COSTS_SERIALIZED_LENGTH).prop_map(|bytes| {
+            bytesrepr::deserialize(bytes).expect("should deserialize host function costs")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::bytesrepr;
+
+    use super::{gens, HostFunctionCost, HostFunctionCosts};
+
+    #[test]
+    fn should_serialize_and_deserialize() {
+        let host_function_costs = HostFunctionCosts {
+            write: HostFunctionCost::new(100, 10),
+            call_contract: HostFunctionCost::new(1_000, 1),
+            random_bytes: HostFunctionCost::new(0, 10),
+            ..Default::default()
+        };
+        bytesrepr::test_serialization_roundtrip(&host_function_costs);
+        bytesrepr::test_serialization_roundtrip(&HostFunctionCosts::default());
+    }
+
+    proptest! {
+        #[test]
+        fn should_serialize_and_deserialize_with_arbitrary_values(
+            host_function_costs in gens::host_function_costs_arb()
+        ) {
+            bytesrepr::test_serialization_roundtrip(&host_function_costs);
+        }
+    }
+}
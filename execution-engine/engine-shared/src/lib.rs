@@ -5,6 +5,8 @@ pub mod additive_map;
 pub mod gas;
 pub mod account;
 pub mod contract;
+pub mod gas_limits;
+pub mod host_function_costs;
 pub mod logging;
 pub mod motes;
 pub mod newtypes;
@@ -25,6 +25,12 @@ pub fn wasm_costs_mock() -> WasmCosts {
         max_stack_height: 64 * 1024,
         opcodes_mul: 3,
         opcodes_div: 8,
+        storage_per_byte: 1,
+        max_module_size: 8 * 1024 * 1024,
+        max_functions: 10_000,
+        max_globals: 256,
+        max_table_size: 10_000,
+        max_memory_pages: 64,
     }
 }
 
@@ -40,5 +46,11 @@ pub fn wasm_costs_free() -> WasmCosts {
         max_stack_height: 64 * 1024,
         opcodes_mul: 1,
         opcodes_div: 1,
+        storage_per_byte: 0,
+        max_module_size: 0,
+        max_functions: 0,
+        max_globals: 0,
+        max_table_size: 0,
+        max_memory_pages: 64,
     }
 }
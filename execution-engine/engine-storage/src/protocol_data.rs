@@ -1,17 +1,33 @@
-use engine_wasm_prep::wasm_costs::{WasmCosts, WASM_COSTS_SERIALIZED_LENGTH};
+use engine_shared::{
+    gas_limits::{GasLimits, GAS_LIMITS_SERIALIZED_LENGTH},
+    host_function_costs::{HostFunctionCosts, HOST_FUNCTION_COSTS_SERIALIZED_LENGTH},
+};
+use engine_wasm_prep::wasm_costs::{
+    WasmCosts, LEGACY_WASM_COSTS_SERIALIZED_LENGTH, WASM_COSTS_SERIALIZED_LENGTH,
+};
 use types::{
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{self, FromBytes, ToBytes, U64_SERIALIZED_LENGTH},
     AccessRights, URef, UREF_SERIALIZED_LENGTH,
 };
 
-const PROTOCOL_DATA_SERIALIZED_LENGTH: usize =
-    WASM_COSTS_SERIALIZED_LENGTH + 3 * UREF_SERIALIZED_LENGTH;
+const PROTOCOL_DATA_SERIALIZED_LENGTH: usize = WASM_COSTS_SERIALIZED_LENGTH
+    + U64_SERIALIZED_LENGTH
+    + GAS_LIMITS_SERIALIZED_LENGTH
+    + HOST_FUNCTION_COSTS_SERIALIZED_LENGTH
+    + 3 * UREF_SERIALIZED_LENGTH;
+/// The serialized length of protocol data in the layout it had before the minimum gas price was
+/// added: the wasm costs followed by the system contracts' URefs.
+const LEGACY_PROTOCOL_DATA_SERIALIZED_LENGTH: usize =
+    LEGACY_WASM_COSTS_SERIALIZED_LENGTH + 3 * UREF_SERIALIZED_LENGTH;
 const DEFAULT_UREF_ADDRESS: [u8; 32] = [0; 32];
 
 /// Represents a protocol's data. Intended to be associated with a given protocol version.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ProtocolData {
     wasm_costs: WasmCosts,
+    min_gas_price: u64,
+    gas_limits: GasLimits,
+    host_function_costs: HostFunctionCosts,
     mint: URef,
     proof_of_stake: URef,
     standard_payment: URef,
@@ -25,6 +41,9 @@ impl Default for ProtocolData {
     fn default() -> ProtocolData {
         ProtocolData {
             wasm_costs: WasmCosts::default(),
+            min_gas_price: 0,
+            gas_limits: GasLimits::default(),
+            host_function_costs: HostFunctionCosts::default(),
             mint: URef::new(DEFAULT_UREF_ADDRESS, AccessRights::READ),
             proof_of_stake: URef::new(DEFAULT_UREF_ADDRESS, AccessRights::READ),
             standard_payment: URef::new(DEFAULT_UREF_ADDRESS, AccessRights::READ),
@@ -33,15 +52,22 @@ impl Default for ProtocolData {
 }
 
 impl ProtocolData {
-    /// Creates a new [`ProtocolData`] value from a given [`WasmCosts`] value.
+    /// Creates a new [`ProtocolData`] value from given [`WasmCosts`], minimum gas price,
+    /// [`GasLimits`] and [`HostFunctionCosts`] values.
     pub fn new(
         wasm_costs: WasmCosts,
+        min_gas_price: u64,
+        gas_limits: GasLimits,
+        host_function_costs: HostFunctionCosts,
         mint: URef,
         proof_of_stake: URef,
         standard_payment: URef,
     ) -> Self {
         ProtocolData {
             wasm_costs,
+            min_gas_price,
+            gas_limits,
+            host_function_costs,
             mint,
             proof_of_stake,
             standard_payment,
@@ -64,11 +90,17 @@ impl ProtocolData {
     /// Used during `commit_genesis` before all system contracts' URefs are known.
     pub fn partial_without_standard_payment(
         wasm_costs: WasmCosts,
+        min_gas_price: u64,
+        gas_limits: GasLimits,
+        host_function_costs: HostFunctionCosts,
         mint: URef,
         proof_of_stake: URef,
     ) -> Self {
         ProtocolData {
             wasm_costs,
+            min_gas_price,
+            gas_limits,
+            host_function_costs,
             mint,
             proof_of_stake,
             ..Default::default()
@@ -80,6 +112,21 @@ impl ProtocolData {
         &self.wasm_costs
     }
 
+    /// Gets the lowest gas price a deploy may offer to be executed.
+    pub fn min_gas_price(&self) -> u64 {
+        self.min_gas_price
+    }
+
+    /// Gets the [`GasLimits`] value from a given [`ProtocolData`] value.
+    pub fn gas_limits(&self) -> &GasLimits {
+        &self.gas_limits
+    }
+
+    /// Gets the [`HostFunctionCosts`] value from a given [`ProtocolData`] value.
+    pub fn host_function_costs(&self) -> &HostFunctionCosts {
+        &self.host_function_costs
+    }
+
     pub fn mint(&self) -> URef {
         self.mint
     }
@@ -106,12 +153,39 @@ impl ProtocolData {
         }
         vec
     }
+
+    /// Deserializes protocol data in the layout it had before the minimum gas price was added.
+    fn legacy_from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (wasm_costs, rem) = WasmCosts::legacy_from_bytes(bytes)?;
+        let (mint, rem) = URef::from_bytes(rem)?;
+        let (proof_of_stake, rem) = URef::from_bytes(rem)?;
+        let (standard_payment, rem) = URef::from_bytes(rem)?;
+        Ok((
+            ProtocolData {
+                wasm_costs,
+                // No minimum gas price was enforced
+                min_gas_price: 0,
+                // The former payment cost cap, with no session or block gas limits
+                gas_limits: GasLimits::default(),
+                // Host functions were free; price them at the defaults rather than for free
+                host_function_costs: HostFunctionCosts::default(),
+                mint,
+                proof_of_stake,
+                standard_payment,
+                ..Default::default()
+            },
+            rem,
+        ))
+    }
 }
 
 impl ToBytes for ProtocolData {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut ret = bytesrepr::unchecked_allocate_buffer(self);
         ret.append(&mut self.wasm_costs.to_bytes()?);
+        ret.append(&mut self.min_gas_price.to_bytes()?);
+        ret.append(&mut self.gas_limits.to_bytes()?);
+        ret.append(&mut self.host_function_costs.to_bytes()?);
         ret.append(&mut self.mint.to_bytes()?);
         ret.append(&mut self.proof_of_stake.to_bytes()?);
         ret.append(&mut self.standard_payment.to_bytes()?);
@@ -125,13 +199,23 @@ impl ToBytes for ProtocolData {
 
 impl FromBytes for ProtocolData {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        // Protocol data is persisted on its own, so the length of the bytes tells the layouts apart
+        if bytes.len() == LEGACY_PROTOCOL_DATA_SERIALIZED_LENGTH {
+            return ProtocolData::legacy_from_bytes(bytes);
+        }
         let (wasm_costs, rem) = WasmCosts::from_bytes(bytes)?;
+        let (min_gas_price, rem) = u64::from_bytes(rem)?;
+        let (gas_limits, rem) = GasLimits::from_bytes(rem)?;
+        let (host_function_costs, rem) = HostFunctionCosts::from_bytes(rem)?;
         let (mint, rem) = URef::from_bytes(rem)?;
         let (proof_of_stake, rem) = URef::from_bytes(rem)?;
         let (standard_payment, rem) = URef::from_bytes(rem)?;
         Ok((
             ProtocolData {
                 wasm_costs,
+                min_gas_price,
+                gas_limits,
+                host_function_costs,
                 mint,
                 proof_of_stake,
                 standard_payment,
@@ -143,8 +227,11 @@ impl FromBytes for ProtocolData {
 
 #[cfg(test)]
 pub(crate) mod gens {
-    use proptest::prop_compose;
+    use proptest::{num, prop_compose};
 
+    use engine_shared::{
+        gas_limits::gens as gas_limits_gens, host_function_costs::gens as host_function_costs_gens,
+    };
     use engine_wasm_prep::wasm_costs::gens as wasm_costs_gens;
     use types::gens;
 
@@ -153,12 +240,18 @@ pub(crate) mod gens {
     prop_compose! {
         pub fn protocol_data_arb()(
             wasm_costs in wasm_costs_gens::wasm_costs_arb(),
+            min_gas_price in num::u64::ANY,
+            gas_limits in gas_limits_gens::gas_limits_arb(),
+            host_function_costs in host_function_costs_gens::host_function_costs_arb(),
             mint in gens::uref_arb(),
             proof_of_stake in gens::uref_arb(),
             standard_payment in gens::uref_arb(),
         ) -> ProtocolData {
             ProtocolData {
                 wasm_costs,
+                min_gas_price,
+                gas_limits,
+                host_function_costs,
                 mint,
                 proof_of_stake,
                 standard_payment,
@@ -171,11 +264,17 @@ pub(crate) mod gens {
 mod tests {
     use proptest::proptest;
 
+    use engine_shared::{gas_limits::GasLimits, host_function_costs::HostFunctionCosts};
     use engine_wasm_prep::wasm_costs::WasmCosts;
-    use types::{bytesrepr, AccessRights, URef};
+    use types::{
+        bytesrepr::{self, ToBytes},
+        AccessRights, URef,
+    };
 
     use super::{gens, ProtocolData};
 
+    const MIN_GAS_PRICE: u64 = 10;
+
     fn wasm_costs_mock() -> WasmCosts {
         WasmCosts {
             regular: 1,
@@ -188,6 +287,12 @@ mod tests {
             max_stack_height: 64 * 1024,
             opcodes_mul: 3,
             opcodes_div: 8,
+            storage_per_byte: 1,
+            max_module_size: 8 * 1024 * 1024,
+            max_functions: 10_000,
+            max_globals: 256,
+            max_table_size: 10_000,
+            max_memory_pages: 64,
         }
     }
 
@@ -203,6 +308,12 @@ mod tests {
             max_stack_height: 64 * 1024,
             opcodes_mul: 1,
             opcodes_div: 1,
+            storage_per_byte: 0,
+            max_module_size: 0,
+            max_functions: 0,
+            max_globals: 0,
+            max_table_size: 0,
+            max_memory_pages: 64,
         }
     }
 
@@ -215,6 +326,9 @@ mod tests {
             let standard_payment_reference = URef::new([2u8; 32], AccessRights::READ_ADD_WRITE);
             ProtocolData::new(
                 costs,
+                MIN_GAS_PRICE,
+                GasLimits::default(),
+                HostFunctionCosts::default(),
                 mint_reference,
                 proof_of_stake_reference,
                 standard_payment_reference,
@@ -227,6 +341,9 @@ mod tests {
             let standard_payment_reference = URef::new([2u8; 32], AccessRights::READ_ADD_WRITE);
             ProtocolData::new(
                 costs,
+                MIN_GAS_PRICE,
+                GasLimits::default(),
+                HostFunctionCosts::default(),
                 mint_reference,
                 proof_of_stake_reference,
                 standard_payment_reference,
@@ -245,6 +362,9 @@ mod tests {
             let costs = wasm_costs_mock();
             ProtocolData::new(
                 costs,
+                MIN_GAS_PRICE,
+                GasLimits::default(),
+                HostFunctionCosts::default(),
                 mint_reference,
                 proof_of_stake_reference,
                 standard_payment_reference,
@@ -274,6 +394,9 @@ mod tests {
             let costs = wasm_costs_mock();
             ProtocolData::new(
                 costs,
+                MIN_GAS_PRICE,
+                GasLimits::default(),
+                HostFunctionCosts::default(),
                 mint_reference,
                 proof_of_stake_reference,
                 standard_payment_reference,
@@ -291,6 +414,63 @@ mod tests {
         assert_eq!(actual[1], standard_payment_reference);
     }
 
+    #[test]
+    fn should_deserialize_legacy_layout() {
+        let costs = wasm_costs_mock();
+        let mint_reference = URef::new([0u8; 32], AccessRights::READ_ADD_WRITE);
+        let proof_of_stake_reference = URef::new([1u8; 32], AccessRights::READ_ADD_WRITE);
+        let standard_payment_reference = URef::new([2u8; 32], AccessRights::READ_ADD_WRITE);
+
+        let mut bytes = Vec::new();
+        for field in &[
+            costs.regular,
+            costs.div,
+            costs.mul,
+            costs.mem,
+            costs.initial_mem,
+            costs.grow_mem,
+            costs.memcpy,
+            costs.max_stack_height,
+            costs.opcodes_mul,
+            costs.opcodes_div,
+        ] {
+            bytes.append(&mut field.to_bytes().unwrap());
+        }
+        bytes.append(&mut mint_reference.to_bytes().unwrap());
+        bytes.append(&mut proof_of_stake_reference.to_bytes().unwrap());
+        bytes.append(&mut standard_payment_reference.to_bytes().unwrap());
+
+        let protocol_data: ProtocolData = bytesrepr::deserialize(bytes).unwrap();
+        let expected_costs = WasmCosts {
+            regular: costs.regular,
+            div: costs.div,
+            mul: costs.mul,
+            mem: costs.mem,
+            initial_mem: costs.initial_mem,
+            grow_mem: costs.grow_mem,
+            memcpy: costs.memcpy,
+            max_stack_height: costs.max_stack_height,
+            opcodes_mul: costs.opcodes_mul,
+            opcodes_div: costs.opcodes_div,
+            storage_per_byte: 0,
+            max_module_size: 0,
+            max_functions: 0,
+            max_globals: 0,
+            max_table_size: 0,
+            max_memory_pages: 64,
+        };
+        assert_eq!(*protocol_data.wasm_costs(), expected_costs);
+        assert_eq!(protocol_data.min_gas_price(), 0);
+        assert_eq!(*protocol_data.gas_limits(), GasLimits::default());
+        assert_eq!(
+            *protocol_data.host_function_costs(),
+            HostFunctionCosts::default()
+        );
+        assert_eq!(protocol_data.mint(), mint_reference);
+        assert_eq!(protocol_data.proof_of_stake(), proof_of_stake_reference);
+        assert_eq!(protocol_data.standard_payment(), standard_payment_reference);
+    }
+
     proptest! {
         #[test]
         fn should_serialize_and_deserialize_with_arbitrary_values(
@@ -2,10 +2,12 @@ use std::{collections::BTreeSet, path::Path};
 
 use contract::args_parser::ArgsParser;
 use engine_core::{
-    engine_state::{deploy_item::DeployItem, executable_deploy_item::ExecutableDeployItem},
+    engine_state::{
+        deploy_item::DeployItem, executable_deploy_item::ExecutableDeployItem, CONV_RATE,
+    },
     DeployHash,
 };
-use types::{account::PublicKey, bytesrepr::ToBytes, URef};
+use types::{account::PublicKey, bytesrepr::ToBytes, RuntimeArgs, URef, DEFAULT_ENTRY_POINT};
 
 use crate::internal::utils;
 
@@ -35,8 +37,11 @@ impl DeployItemBuilder {
 
     pub fn with_payment_bytes(mut self, module_bytes: Vec<u8>, args: impl ArgsParser) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.payment_code =
-            Some(ExecutableDeployItem::ModuleBytes { module_bytes, args });
+        self.deploy_item.payment_code = Some(ExecutableDeployItem::ModuleBytes {
+            module_bytes,
+            args,
+            named_args: Vec::new(),
+        });
         self
     }
 
@@ -51,8 +56,12 @@ impl DeployItemBuilder {
 
     pub fn with_stored_payment_hash(mut self, hash: Vec<u8>, args: impl ArgsParser) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.payment_code =
-            Some(ExecutableDeployItem::StoredContractByHash { hash, args });
+        self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredContractByHash {
+            hash,
+            entry_point: DEFAULT_ENTRY_POINT.to_string(),
+            args,
+            named_args: Vec::new(),
+        });
         self
     }
 
@@ -64,7 +73,9 @@ impl DeployItemBuilder {
         let args = Self::serialize_args(args);
         self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredContractByURef {
             uref: uref_addr,
+            entry_point: DEFAULT_ENTRY_POINT.to_string(),
             args,
+            named_args: Vec::new(),
         });
         self
     }
@@ -73,7 +84,9 @@ impl DeployItemBuilder {
         let args = Self::serialize_args(args);
         self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredContractByURef {
             uref: uref.addr().to_vec(),
+            entry_point: DEFAULT_ENTRY_POINT.to_string(),
             args,
+            named_args: Vec::new(),
         });
         self
     }
@@ -82,15 +95,20 @@ impl DeployItemBuilder {
         let args = Self::serialize_args(args);
         self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredContractByName {
             name: uref_name.to_owned(),
+            entry_point: DEFAULT_ENTRY_POINT.to_string(),
             args,
+            named_args: Vec::new(),
         });
         self
     }
 
     pub fn with_session_bytes(mut self, module_bytes: Vec<u8>, args: impl ArgsParser) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.session_code =
-            Some(ExecutableDeployItem::ModuleBytes { module_bytes, args });
+        self.deploy_item.session_code = Some(ExecutableDeployItem::ModuleBytes {
+            module_bytes,
+            args,
+            named_args: Vec::new(),
+        });
         self
     }
 
@@ -101,8 +119,12 @@ impl DeployItemBuilder {
 
     pub fn with_stored_session_hash(mut self, hash: Vec<u8>, args: impl ArgsParser) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.session_code =
-            Some(ExecutableDeployItem::StoredContractByHash { hash, args });
+        self.deploy_item.session_code = Some(ExecutableDeployItem::StoredContractByHash {
+            hash,
+            entry_point: DEFAULT_ENTRY_POINT.to_string(),
+            args,
+            named_args: Vec::new(),
+        });
         self
     }
 
@@ -114,7 +136,9 @@ impl DeployItemBuilder {
         let args = Self::serialize_args(args);
         self.deploy_item.session_code = Some(ExecutableDeployItem::StoredContractByURef {
             uref: uref_addr,
+            entry_point: DEFAULT_ENTRY_POINT.to_string(),
             args,
+            named_args: Vec::new(),
         });
         self
     }
@@ -123,7 +147,9 @@ impl DeployItemBuilder {
         let args = Self::serialize_args(args);
         self.deploy_item.session_code = Some(ExecutableDeployItem::StoredContractByURef {
             uref: uref.addr().to_vec(),
+            entry_point: DEFAULT_ENTRY_POINT.to_string(),
             args,
+            named_args: Vec::new(),
         });
         self
     }
@@ -132,11 +158,67 @@ impl DeployItemBuilder {
         let args = Self::serialize_args(args);
         self.deploy_item.session_code = Some(ExecutableDeployItem::StoredContractByName {
             name: uref_name.to_owned(),
+            entry_point: DEFAULT_ENTRY_POINT.to_string(),
+            args,
+            named_args: Vec::new(),
+        });
+        self
+    }
+
+    pub fn with_stored_session_hash_entry_point(
+        mut self,
+        hash: Vec<u8>,
+        entry_point: &str,
+        args: impl ArgsParser,
+    ) -> Self {
+        let args = Self::serialize_args(args);
+        self.deploy_item.session_code = Some(ExecutableDeployItem::StoredContractByHash {
+            hash,
+            entry_point: entry_point.to_owned(),
+            args,
+            named_args: Vec::new(),
+        });
+        self
+    }
+
+    pub fn with_stored_session_named_key_entry_point(
+        mut self,
+        uref_name: &str,
+        entry_point: &str,
+        args: impl ArgsParser,
+    ) -> Self {
+        let args = Self::serialize_args(args);
+        self.deploy_item.session_code = Some(ExecutableDeployItem::StoredContractByName {
+            name: uref_name.to_owned(),
+            entry_point: entry_point.to_owned(),
             args,
+            named_args: Vec::new(),
         });
         self
     }
 
+    /// Passes `named_args` to the payment code, which must already be set.
+    pub fn with_payment_named_args(mut self, named_args: RuntimeArgs) -> Self {
+        let payment_code = self
+            .deploy_item
+            .payment_code
+            .as_mut()
+            .expect("payment code should be set before its named args");
+        Self::set_named_args(payment_code, named_args);
+        self
+    }
+
+    /// Passes `named_args` to the session code, which must already be set.
+    pub fn with_session_named_args(mut self, named_args: RuntimeArgs) -> Self {
+        let session_code = self
+            .deploy_item
+            .session_code
+            .as_mut()
+            .expect("session code should be set before its named args");
+        Self::set_named_args(session_code, named_args);
+        self
+    }
+
     pub fn with_authorization_keys<T: Clone + Into<PublicKey>>(
         mut self,
         authorization_keys: &[T],
@@ -173,6 +255,20 @@ impl DeployItemBuilder {
         }
     }
 
+    fn set_named_args(code: &mut ExecutableDeployItem, named_args: RuntimeArgs) {
+        let serialized_named_args = named_args
+            .into_bytes()
+            .expect("should serialize named args");
+        match code {
+            ExecutableDeployItem::ModuleBytes { named_args, .. }
+            | ExecutableDeployItem::StoredContractByHash { named_args, .. }
+            | ExecutableDeployItem::StoredContractByName { named_args, .. }
+            | ExecutableDeployItem::StoredContractByURef { named_args, .. } => {
+                *named_args = serialized_named_args
+            }
+        }
+    }
+
     fn serialize_args(args: impl ArgsParser) -> Vec<u8> {
         args.parse()
             .expect("should convert to `Vec<CLValue>`")
@@ -184,7 +280,7 @@ impl DeployItemBuilder {
 impl Default for DeployItemBuilder {
     fn default() -> Self {
         let mut deploy_item: DeployItemData = Default::default();
-        deploy_item.gas_price = 1;
+        deploy_item.gas_price = CONV_RATE;
         DeployItemBuilder { deploy_item }
     }
 }
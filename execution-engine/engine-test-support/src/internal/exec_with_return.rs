@@ -4,19 +4,19 @@ use contract::args_parser::ArgsParser;
 use engine_core::{
     engine_state::{
         executable_deploy_item::ExecutableDeployItem, execution_effect::ExecutionEffect,
-        EngineConfig, EngineState,
+        EngineConfig, EngineState, DEFAULT_MIN_GAS_PRICE,
     },
     execution::{self, AddressGenerator},
+    resolvers,
     runtime::{self, Runtime},
     runtime_context::RuntimeContext,
 };
 use engine_grpc_server::engine_server::ipc_grpc::ExecutionEngineService;
 use engine_shared::{gas::Gas, newtypes::CorrelationId};
 use engine_storage::{global_state::StateProvider, protocol_data::ProtocolData};
-use engine_wasm_prep::Preprocessor;
 use types::{
     account::PublicKey, bytesrepr::FromBytes, BlockTime, CLTyped, CLValue, Key, Phase,
-    ProtocolVersion, URef, U512,
+    ProtocolVersion, RuntimeArgs, URef, U512,
 };
 
 use crate::internal::{utils, WasmTestBuilder, DEFAULT_WASM_COSTS};
@@ -85,7 +85,15 @@ where
         let mint = builder.get_mint_contract_uref();
         let pos = builder.get_mint_contract_uref();
         let standard_payment = builder.get_standard_payment_contract_uref();
-        ProtocolData::new(*DEFAULT_WASM_COSTS, mint, pos, standard_payment)
+        ProtocolData::new(
+            *DEFAULT_WASM_COSTS,
+            DEFAULT_MIN_GAS_PRICE,
+            Default::default(),
+            Default::default(),
+            mint,
+            pos,
+            standard_payment,
+        )
     };
 
     let context = RuntimeContext::new(
@@ -93,10 +101,13 @@ where
         &mut named_keys,
         access_rights,
         arguments,
+        RuntimeArgs::new(),
         BTreeSet::new(),
         &account,
         base_key,
         BlockTime::new(block_time),
+        0,
+        None,
         deploy_hash,
         gas_limit,
         gas_counter,
@@ -116,7 +127,7 @@ where
 
     let wasm_costs = *DEFAULT_WASM_COSTS;
 
-    let preprocessor = Preprocessor::new(wasm_costs);
+    let preprocessor = resolvers::create_preprocessor(wasm_costs);
     let parity_module = builder
         .get_engine_state()
         .get_module(
@@ -129,8 +140,12 @@ where
         )
         .expect("should get wasm module");
 
-    let (instance, memory) = runtime::instance_and_memory(parity_module.clone(), protocol_version)
-        .expect("should be able to make wasm instance from module");
+    let (instance, memory) = runtime::instance_and_memory(
+        parity_module.clone(),
+        protocol_version,
+        protocol_data.wasm_costs().max_memory_pages,
+    )
+    .expect("should be able to make wasm instance from module");
 
     let mut runtime = Runtime::new(config, Default::default(), memory, parity_module, context);
 
@@ -4,7 +4,7 @@ use rand::Rng;
 
 use contract::args_parser::ArgsParser;
 use engine_core::engine_state::{deploy_item::DeployItem, execute_request::ExecuteRequest};
-use types::{account::PublicKey, ProtocolVersion};
+use types::{account::PublicKey, ProtocolVersion, RuntimeArgs};
 
 use crate::internal::{DeployItemBuilder, DEFAULT_BLOCK_TIME, DEFAULT_PAYMENT};
 
@@ -36,11 +36,26 @@ impl ExecuteRequestBuilder {
         self
     }
 
+    pub fn with_block_height(mut self, block_height: u64) -> Self {
+        self.execute_request.block_height = block_height;
+        self
+    }
+
+    pub fn with_proposer(mut self, proposer: PublicKey) -> Self {
+        self.execute_request.proposer = Some(proposer);
+        self
+    }
+
     pub fn with_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
         self.execute_request.protocol_version = protocol_version;
         self
     }
 
+    pub fn with_trace(mut self, trace: bool) -> Self {
+        self.execute_request.trace = trace;
+        self
+    }
+
     pub fn build(self) -> ExecuteRequest {
         self.execute_request
     }
@@ -64,6 +79,26 @@ impl ExecuteRequestBuilder {
         ExecuteRequestBuilder::new().push_deploy(deploy)
     }
 
+    pub fn standard_with_named_args(
+        public_key: PublicKey,
+        session_file: &str,
+        session_named_args: RuntimeArgs,
+    ) -> Self {
+        let mut rng = rand::thread_rng();
+        let deploy_hash: [u8; 32] = rng.gen();
+
+        let deploy = DeployItemBuilder::new()
+            .with_address(public_key)
+            .with_session_code(session_file, ())
+            .with_session_named_args(session_named_args)
+            .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+            .with_authorization_keys(&[public_key])
+            .with_deploy_hash(deploy_hash)
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy)
+    }
+
     pub fn contract_call_by_hash(
         sender: PublicKey,
         contract_hash: [u8; 32],
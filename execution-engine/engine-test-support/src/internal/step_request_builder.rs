@@ -1,9 +1,11 @@
 use engine_grpc_server::engine_server::{ipc::StepRequest, state};
-use types::{BlockTime, ProtocolVersion};
+use types::{account::PublicKey, BlockTime, ProtocolVersion};
 
 pub struct StepRequestBuilder {
     parent_state_hash: Vec<u8>,
     blocktime: u64,
+    block_height: u64,
+    proposer: PublicKey,
     protocol_version: state::ProtocolVersion,
 }
 
@@ -22,6 +24,16 @@ impl StepRequestBuilder {
         self
     }
 
+    pub fn with_block_height(mut self, block_height: u64) -> Self {
+        self.block_height = block_height;
+        self
+    }
+
+    pub fn with_proposer(mut self, proposer: PublicKey) -> Self {
+        self.proposer = proposer;
+        self
+    }
+
     pub fn with_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
         self.protocol_version = protocol_version.into();
         self
@@ -32,6 +44,8 @@ impl StepRequestBuilder {
         step_request.set_parent_state_hash(self.parent_state_hash);
         step_request.set_protocol_version(self.protocol_version);
         step_request.set_block_time(self.blocktime);
+        step_request.set_block_height(self.block_height);
+        step_request.set_proposer(self.proposer.value().to_vec());
         step_request
     }
 }
@@ -41,6 +55,8 @@ impl Default for StepRequestBuilder {
         StepRequestBuilder {
             parent_state_hash: Default::default(),
             blocktime: Default::default(),
+            block_height: Default::default(),
+            proposer: PublicKey::ed25519_from([0u8; 32]),
             protocol_version: ProtocolVersion::V1_0_0.into(),
         }
     }
@@ -1,10 +1,12 @@
 use engine_grpc_server::engine_server::{
     ipc::{
-        ChainSpec_ActivationPoint, ChainSpec_CostTable_WasmCosts, ChainSpec_UpgradePoint,
-        DeployCode, UpgradeRequest,
+        ChainSpec_ActivationPoint, ChainSpec_CostTable_HostFunctionCosts,
+        ChainSpec_CostTable_WasmCosts, ChainSpec_GasLimits, ChainSpec_UpgradePoint, DeployCode,
+        UpgradeRequest,
     },
     state,
 };
+use engine_shared::{gas_limits::GasLimits, host_function_costs::HostFunctionCosts};
 use engine_wasm_prep::wasm_costs::WasmCosts;
 use types::ProtocolVersion;
 
@@ -14,6 +16,9 @@ pub struct UpgradeRequestBuilder {
     new_protocol_version: state::ProtocolVersion,
     upgrade_installer: DeployCode,
     new_costs: Option<ChainSpec_CostTable_WasmCosts>,
+    new_host_function_costs: Option<ChainSpec_CostTable_HostFunctionCosts>,
+    new_min_gas_price: u64,
+    new_gas_limits: Option<ChainSpec_GasLimits>,
     activation_point: ChainSpec_ActivationPoint,
 }
 
@@ -54,10 +59,31 @@ impl UpgradeRequestBuilder {
         new_costs.set_max_stack_height(wasm_costs.max_stack_height);
         new_costs.set_mem(wasm_costs.mem);
         new_costs.set_memcpy(wasm_costs.memcpy);
+        new_costs.set_storage_per_byte(wasm_costs.storage_per_byte);
+        new_costs.set_max_module_size(wasm_costs.max_module_size);
+        new_costs.set_max_functions(wasm_costs.max_functions);
+        new_costs.set_max_globals(wasm_costs.max_globals);
+        new_costs.set_max_table_size(wasm_costs.max_table_size);
+        new_costs.set_max_memory_pages(wasm_costs.max_memory_pages);
         self.new_costs = Some(new_costs);
         self
     }
 
+    pub fn with_new_host_function_costs(mut self, host_function_costs: HostFunctionCosts) -> Self {
+        self.new_host_function_costs = Some(host_function_costs.into());
+        self
+    }
+
+    pub fn with_new_min_gas_price(mut self, min_gas_price: u64) -> Self {
+        self.new_min_gas_price = min_gas_price;
+        self
+    }
+
+    pub fn with_new_gas_limits(mut self, gas_limits: GasLimits) -> Self {
+        self.new_gas_limits = Some(gas_limits.into());
+        self
+    }
+
     pub fn with_activation_point(mut self, rank: u64) -> Self {
         self.activation_point = {
             let mut ret = ChainSpec_ActivationPoint::new();
@@ -70,14 +96,19 @@ impl UpgradeRequestBuilder {
     pub fn build(self) -> UpgradeRequest {
         let mut upgrade_point = ChainSpec_UpgradePoint::new();
         upgrade_point.set_activation_point(self.activation_point);
-        match self.new_costs {
-            None => {}
-            Some(new_costs) => {
-                let mut cost_table =
-                    engine_grpc_server::engine_server::ipc::ChainSpec_CostTable::new();
+        if self.new_costs.is_some() || self.new_host_function_costs.is_some() {
+            let mut cost_table = engine_grpc_server::engine_server::ipc::ChainSpec_CostTable::new();
+            if let Some(new_costs) = self.new_costs {
                 cost_table.set_wasm(new_costs);
-                upgrade_point.set_new_costs(cost_table);
             }
+            if let Some(new_host_function_costs) = self.new_host_function_costs {
+                cost_table.set_host(new_host_function_costs);
+            }
+            upgrade_point.set_new_costs(cost_table);
+        }
+        upgrade_point.set_new_min_gas_price(self.new_min_gas_price);
+        if let Some(new_gas_limits) = self.new_gas_limits {
+            upgrade_point.set_new_gas_limits(new_gas_limits);
         }
         upgrade_point.set_protocol_version(self.new_protocol_version);
         upgrade_point.set_upgrade_installer(self.upgrade_installer);
@@ -97,6 +128,9 @@ impl Default for UpgradeRequestBuilder {
             new_protocol_version: Default::default(),
             upgrade_installer: Default::default(),
             new_costs: None,
+            new_host_function_costs: None,
+            new_min_gas_price: 0,
+            new_gas_limits: None,
             activation_point: Default::default(),
         }
     }
@@ -8,13 +8,18 @@ use std::{
     sync::Arc,
 };
 
+use contract::args_parser::ArgsParser;
 use grpc::RequestOptions;
 use lmdb::DatabaseFlags;
 use log::LevelFilter;
 
 use engine_core::{
     engine_state::{
-        execute_request::ExecuteRequest, execution_result::ExecutionResult, genesis::GenesisConfig,
+        execute_block::{ExecuteBlockRequest, ExecuteBlockResult},
+        execute_request::ExecuteRequest,
+        execution_result::ExecutionResult,
+        genesis::GenesisConfig,
+        pop_query::{PopQueryRequest, PopQueryResult},
         EngineConfig, EngineState, SYSTEM_ACCOUNT_ADDR,
     },
     execution,
@@ -47,11 +52,11 @@ use engine_storage::{
 };
 use types::{
     account::PublicKey,
-    bytesrepr::{self, ToBytes},
-    CLValue, Key, URef, U512,
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLTyped, CLValue, Key, URef, U512,
 };
 
-use crate::internal::utils;
+use crate::internal::{utils, DEFAULT_PROTOCOL_VERSION};
 
 /// LMDB initial map size is calculated based on DEFAULT_LMDB_PAGES and systems page size.
 ///
@@ -292,7 +297,7 @@ impl LmdbWasmTestBuilder {
 
 impl<S> WasmTestBuilder<S>
 where
-    S: StateProvider,
+    S: StateProvider + Sync,
     S::Error: Into<execution::Error>,
     EngineState<S>: ExecutionEngineService,
 {
@@ -389,6 +394,29 @@ where
         bytesrepr::deserialize(query_response.take_success()).map_err(|err| format!("{}", err))
     }
 
+    /// Calls a read-only method of the PoP contract on the latest post-state hash.
+    pub fn query_pop<T: FromBytes + CLTyped>(&self, args: impl ArgsParser) -> Result<T, String> {
+        let post_state = self
+            .post_state_hash
+            .as_ref()
+            .expect("builder must have a post-state hash")
+            .as_slice()
+            .try_into()
+            .expect("expected a valid hash");
+        let args = ArgsParser::parse(args).map_err(|error| format!("{:?}", error))?;
+        let pop_query_request = PopQueryRequest::new(post_state, *DEFAULT_PROTOCOL_VERSION, args);
+
+        match self
+            .engine_state
+            .run_pop_query(CorrelationId::new(), pop_query_request)
+            .map_err(|error| format!("{:?}", error))?
+        {
+            PopQueryResult::Success(ret) => Ok(ret),
+            PopQueryResult::RootNotFound => Err(String::from("root not found")),
+            PopQueryResult::InvalidMethod => Err(String::from("invalid query method")),
+        }
+    }
+
     pub fn exec(&mut self, mut exec_request: ExecuteRequest) -> &mut Self {
         let exec_request = {
             let hash = self
@@ -416,6 +444,80 @@ where
         self
     }
 
+    /// Runs the deploys of `exec_request` as a single block, optionally followed by `step`, and
+    /// moves the post-state hash to the committed result.
+    pub fn exec_block(&mut self, exec_request: ExecuteRequest, run_step: bool) -> &mut Self {
+        let execution_results = match self.run_execute_block(exec_request, run_step, true) {
+            ExecuteBlockResult::Success {
+                post_state_hash,
+                execution_results,
+                ..
+            } => {
+                self.post_state_hash = Some(post_state_hash.to_vec());
+                execution_results
+            }
+            result => panic!("Execute block failure: {}", result),
+        };
+        self.transforms.extend(
+            execution_results
+                .iter()
+                .map(|res| res.effect().transforms.clone()),
+        );
+        self.exec_responses
+            .push(execution_results.into_iter().map(Rc::new).collect());
+        self
+    }
+
+    /// Runs the deploys of `exec_request` as a single block without committing it.  The effects
+    /// of the whole block can then be committed with `commit`.
+    pub fn exec_block_uncommitted(
+        &mut self,
+        exec_request: ExecuteRequest,
+        run_step: bool,
+    ) -> &mut Self {
+        let (effect, execution_results) =
+            match self.run_execute_block(exec_request, run_step, false) {
+                ExecuteBlockResult::Uncommitted {
+                    effect,
+                    execution_results,
+                    ..
+                } => (effect, execution_results),
+                result => panic!("Execute block failure: {}", result),
+            };
+        self.transforms.push(effect.transforms);
+        self.exec_responses
+            .push(execution_results.into_iter().map(Rc::new).collect());
+        self
+    }
+
+    fn run_execute_block(
+        &self,
+        mut exec_request: ExecuteRequest,
+        run_step: bool,
+        commit: bool,
+    ) -> ExecuteBlockResult {
+        let parent_state_hash = self
+            .post_state_hash
+            .clone()
+            .expect("expected post_state_hash")
+            .as_slice()
+            .try_into()
+            .expect("expected a valid hash");
+        let block_request = ExecuteBlockRequest::new(
+            parent_state_hash,
+            exec_request.block_time,
+            exec_request.block_height,
+            exec_request.proposer,
+            exec_request.take_deploys(),
+            exec_request.protocol_version,
+            run_step,
+            commit,
+        );
+        self.engine_state
+            .run_execute_block(CorrelationId::new(), block_request)
+            .unwrap_or_else(|error| panic!("Execute block error: {:?}", error))
+    }
+
     /// Commit effects of previous exec call on the latest post-state hash.
     pub fn commit(&mut self) -> &mut Self {
         let prestate_hash = self
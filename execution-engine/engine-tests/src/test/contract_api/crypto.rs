@@ -0,0 +1,128 @@
+use engine_shared::{
+    gas::Gas,
+    host_function_costs::{HostFunctionCost, HostFunctionCosts},
+    stored_value::StoredValue,
+};
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, bytesrepr::FromBytes, CLTyped, Key, U512};
+
+const CONTRACT_HOST_CRYPTO: &str = "host_crypto.wasm";
+const BLAKE2B_KEY: &str = "blake2b";
+const SHA256_KEY: &str = "sha256";
+const KECCAK256_KEY: &str = "keccak256";
+const SIGNATURE_VALID_KEY: &str = "signature_valid";
+
+// Test 1 from RFC 8032, section 7.1: a signature of the empty message.
+const ED25519_PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+const ED25519_SIGNATURE: &str =
+    "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+
+fn decode(hex: &str) -> Vec<u8> {
+    base16::decode(hex).expect("should decode hex")
+}
+
+fn run_host_crypto(data: Vec<u8>) -> InMemoryWasmTestBuilder {
+    run_host_crypto_with_costs(data, HostFunctionCosts::default())
+}
+
+fn run_host_crypto_with_costs(
+    data: Vec<u8>,
+    host_function_costs: HostFunctionCosts,
+) -> InMemoryWasmTestBuilder {
+    let genesis_config = DEFAULT_GENESIS_CONFIG
+        .clone()
+        .with_host_function_costs(host_function_costs);
+    let public_key = {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&decode(ED25519_PUBLIC_KEY));
+        PublicKey::ed25519_from(bytes)
+    };
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_HOST_CRYPTO,
+        (data, public_key, decode(ED25519_SIGNATURE)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&genesis_config)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn get_value<T: CLTyped + FromBytes>(builder: &InMemoryWasmTestBuilder, name: &str) -> T {
+    match builder.query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[name]) {
+        Ok(StoredValue::CLValue(cl_value)) => cl_value.into_t().expect("should have value"),
+        other => panic!("should find {} but got {:?}", name, other),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_hash_and_verify_signature_with_host_functions() {
+    let builder = run_host_crypto(Vec::new());
+
+    let blake2b: Vec<u8> = get_value(&builder, BLAKE2B_KEY);
+    assert_eq!(
+        blake2b,
+        decode("0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8")
+    );
+    let sha256: Vec<u8> = get_value(&builder, SHA256_KEY);
+    assert_eq!(
+        sha256,
+        decode("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+    );
+    let keccak256: Vec<u8> = get_value(&builder, KECCAK256_KEY);
+    assert_eq!(
+        keccak256,
+        decode("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+    );
+    let signature_valid: bool = get_value(&builder, SIGNATURE_VALID_KEY);
+    assert!(signature_valid);
+}
+
+#[ignore]
+#[test]
+fn should_not_verify_signature_of_other_message() {
+    let builder = run_host_crypto(b"abc".to_vec());
+
+    let sha256: Vec<u8> = get_value(&builder, SHA256_KEY);
+    assert_eq!(
+        sha256,
+        decode("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+    );
+    let signature_valid: bool = get_value(&builder, SIGNATURE_VALID_KEY);
+    assert!(!signature_valid);
+}
+
+#[ignore]
+#[test]
+fn should_charge_hashing_and_signature_verification_per_byte_from_host_function_costs() {
+    let data = b"abc".to_vec();
+    let default_costs = HostFunctionCosts::default();
+    let free_crypto = HostFunctionCosts {
+        blake2b: HostFunctionCost::default(),
+        sha256: HostFunctionCost::default(),
+        keccak256: HostFunctionCost::default(),
+        verify_ed25519: HostFunctionCost::default(),
+        ..default_costs
+    };
+    let cost: Gas = run_host_crypto_with_costs(data.clone(), default_costs).exec_costs(0)[0];
+    let free_crypto_cost: Gas =
+        run_host_crypto_with_costs(data.clone(), free_crypto).exec_costs(0)[0];
+
+    let hash_cost_per_byte = default_costs.blake2b.per_byte
+        + default_costs.sha256.per_byte
+        + default_costs.keccak256.per_byte;
+    let signature_size =
+        decode(ED25519_PUBLIC_KEY).len() + data.len() + decode(ED25519_SIGNATURE).len();
+    let expected_difference = U512::from(hash_cost_per_byte) * U512::from(data.len())
+        + U512::from(default_costs.verify_ed25519.per_byte) * U512::from(signature_size);
+    assert_eq!(cost.value() - free_crypto_cost.value(), expected_difference);
+}
@@ -0,0 +1,99 @@
+use contract::args_parser::ArgsParser;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PAYMENT, STANDARD_PAYMENT_CONTRACT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+
+const CONTRACT_ENTRY_POINTS: &str = "entry_points.wasm";
+const CONTRACT_NAME: &str = "entry_points";
+const REVERT_WITH: &str = "revert_with";
+
+/// Stores the contract and calls it at `entry_point` with `args`.
+fn call_entry_point(entry_point: &str, args: impl ArgsParser) -> InMemoryWasmTestBuilder {
+    let store_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_ENTRY_POINTS, ()).build();
+
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_stored_session_named_key_entry_point(CONTRACT_NAME, entry_point, args)
+        .with_payment_code(STANDARD_PAYMENT_CONTRACT, (*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([42; 32])
+        .build();
+    let call_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(store_request)
+        .expect_success()
+        .commit()
+        .exec(call_request);
+    builder
+}
+
+fn assert_error_contains(builder: &InMemoryWasmTestBuilder, pattern: &str) {
+    let message = builder.exec_error_message(1).expect("should fail");
+    assert!(
+        message.contains(pattern),
+        "Error message {:?} does not contain expected pattern {:?}",
+        message,
+        pattern
+    );
+}
+
+#[ignore]
+#[test]
+fn should_call_entry_point() {
+    let builder = call_entry_point(REVERT_WITH, (7u32,));
+    // User error codes are offset by 65536.
+    assert_error_contains(&builder, "Revert(65543)");
+}
+
+#[ignore]
+#[test]
+fn should_reject_args_not_matching_entry_point() {
+    let builder = call_entry_point(REVERT_WITH, (7u64,));
+    assert_error_contains(&builder, "InvalidEntryPointArgs");
+
+    let builder = call_entry_point(REVERT_WITH, ());
+    assert_error_contains(&builder, "InvalidEntryPointArgs");
+}
+
+#[ignore]
+#[test]
+fn should_reject_undeclared_entry_point() {
+    let builder = call_entry_point("not_an_entry_point", ());
+    assert_error_contains(&builder, "EntryPointNotFound");
+
+    // Contracts declaring entry points can't be called at `call` unless they declare it.
+    let builder = call_entry_point("call", ());
+    assert_error_contains(&builder, "EntryPointNotFound");
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_store_contract_declaring_unexported_entry_point() {
+    let store_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ENTRY_POINTS,
+        (String::from("not_exported"),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(store_request)
+        .commit();
+
+    let message = builder.exec_error_message(0).expect("should fail");
+    assert!(
+        message.contains("FunctionNotFound(\"not_exported\")"),
+        "Error message {:?} does not name the unexported entry point",
+        message
+    );
+}
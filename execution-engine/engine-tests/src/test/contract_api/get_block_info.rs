@@ -0,0 +1,57 @@
+use engine_core::engine_state::deploy_item::DeployItem;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::account::PublicKey;
+
+const CONTRACT_GET_BLOCK_INFO: &str = "get_block_info.wasm";
+const BLOCK_HEIGHT: u64 = 42;
+const PROPOSER: PublicKey = PublicKey::ed25519_from([7u8; 32]);
+const DEPLOY_HASH: [u8; 32] = [3u8; 32];
+
+fn get_block_info_deploy(known_proposer: Option<PublicKey>) -> DeployItem {
+    DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            CONTRACT_GET_BLOCK_INFO,
+            (BLOCK_HEIGHT, known_proposer, DEPLOY_HASH),
+        )
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(DEPLOY_HASH)
+        .build()
+}
+
+#[ignore]
+#[test]
+fn should_run_get_block_info_contract() {
+    let exec_request =
+        ExecuteRequestBuilder::from_deploy_item(get_block_info_deploy(Some(PROPOSER)))
+            .with_block_height(BLOCK_HEIGHT)
+            .with_proposer(PROPOSER)
+            .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_get_no_proposer_when_request_has_none() {
+    let exec_request = ExecuteRequestBuilder::from_deploy_item(get_block_info_deploy(None))
+        .with_block_height(BLOCK_HEIGHT)
+        .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+}
@@ -0,0 +1,141 @@
+use engine_test_support::{
+    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, RuntimeArgs, U512};
+
+#[derive(Debug)]
+#[repr(u16)]
+enum GetNamedArgContractError {
+    MissingArgument0 = 0,
+    MissingArgument1,
+    InvalidArgument0,
+    InvalidArgument1,
+}
+
+const CONTRACT_GET_NAMED_ARG: &str = "get_named_arg.wasm";
+const CONTRACT_GET_NAMED_ARG_OVERSIZED: &str = "get_named_arg_oversized.wasm";
+const ARG_VALUE0: &str = "value0";
+const ARG_VALUE1: &str = "value1";
+const ARG_VALUE: &str = "value";
+const ARG0_VALUE: &str = "Hello, world!";
+const ARG1_VALUE: u64 = 42;
+
+/// Calls get_named_arg contract and returns Ok(()) in case no error, or String which is the error
+/// message returned by the engine
+fn call_get_named_arg(named_args: RuntimeArgs) -> Result<(), String> {
+    call_contract_with_named_args(CONTRACT_GET_NAMED_ARG, named_args)
+}
+
+/// Calls the contract in `contract_file` in the same way as [`call_get_named_arg`]
+fn call_contract_with_named_args(
+    contract_file: &str,
+    named_args: RuntimeArgs,
+) -> Result<(), String> {
+    let exec_request = ExecuteRequestBuilder::standard_with_named_args(
+        DEFAULT_ACCOUNT_ADDR,
+        contract_file,
+        named_args,
+    )
+    .build();
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    if !result.builder().is_error() {
+        return Ok(());
+    }
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    let error_message = utils::get_error_message(response);
+
+    Err(error_message)
+}
+
+#[ignore]
+#[test]
+fn should_use_passed_named_argument() {
+    let mut named_args = RuntimeArgs::new();
+    named_args
+        .insert(ARG_VALUE0, String::from(ARG0_VALUE))
+        .unwrap();
+    named_args
+        .insert(ARG_VALUE1, U512::from(ARG1_VALUE))
+        .unwrap();
+    call_get_named_arg(named_args)
+        .expect("Should successfuly call get_named_arg with 2 valid args");
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_missing_named_arg() {
+    assert!(call_get_named_arg(RuntimeArgs::new())
+        .expect_err("should fail")
+        .contains(&format!(
+            "Revert({})",
+            u32::from(ApiError::User(
+                GetNamedArgContractError::MissingArgument0 as u16
+            ))
+        )));
+
+    let mut named_args = RuntimeArgs::new();
+    named_args
+        .insert(ARG_VALUE0, String::from(ARG0_VALUE))
+        .unwrap();
+    assert!(call_get_named_arg(named_args)
+        .expect_err("should fail")
+        .contains(&format!(
+            "Revert({})",
+            u32::from(ApiError::User(
+                GetNamedArgContractError::MissingArgument1 as u16
+            ))
+        )));
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_invalid_named_argument() {
+    let mut named_args = RuntimeArgs::new();
+    named_args.insert(ARG_VALUE0, U512::from(123)).unwrap();
+    assert!(call_get_named_arg(named_args)
+        .expect_err("should fail")
+        .contains(&format!(
+            "Revert({})",
+            u32::from(ApiError::User(
+                GetNamedArgContractError::InvalidArgument0 as u16
+            ))
+        )));
+
+    let mut named_args = RuntimeArgs::new();
+    named_args
+        .insert(ARG_VALUE0, String::from(ARG0_VALUE))
+        .unwrap();
+    named_args
+        .insert(ARG_VALUE1, String::from("this is expected to be U512"))
+        .unwrap();
+    assert!(call_get_named_arg(named_args)
+        .expect_err("should fail")
+        .contains(&format!(
+            "Revert({})",
+            u32::from(ApiError::User(
+                GetNamedArgContractError::InvalidArgument1 as u16
+            ))
+        )));
+}
+
+#[ignore]
+#[test]
+fn should_fill_oversized_buffer_up_to_named_arg_size() {
+    let mut named_args = RuntimeArgs::new();
+    named_args
+        .insert(ARG_VALUE, String::from(ARG0_VALUE))
+        .unwrap();
+    call_contract_with_named_args(CONTRACT_GET_NAMED_ARG_OVERSIZED, named_args)
+        .expect("should read named arg into a buffer larger than it");
+}
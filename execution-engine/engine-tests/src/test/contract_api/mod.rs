@@ -1,6 +1,10 @@
 mod account;
 mod create_purse;
+mod crypto;
+mod entry_points;
 mod get_arg;
+mod get_named_arg;
+mod get_block_info;
 mod get_blocktime;
 mod get_caller;
 mod get_phase;
@@ -8,6 +12,8 @@ mod list_named_keys;
 mod local_state;
 mod main_purse;
 mod mint_purse;
+mod print;
+mod random_bytes;
 mod revert;
 mod subcall;
 mod transfer;
@@ -0,0 +1,127 @@
+use std::convert::TryInto;
+
+use grpc::RequestOptions;
+
+use engine_core::engine_state::{engine_config::EngineConfig, execute_request::ExecuteRequest};
+use engine_grpc_server::engine_server::{ipc, ipc_grpc::ExecutionEngineService};
+use engine_shared::{
+    gas::Gas,
+    host_function_costs::{HostFunctionCost, HostFunctionCosts},
+};
+use engine_storage::global_state::in_memory::InMemoryGlobalState;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::U512;
+
+const CONTRACT_PRINT_DEBUG_LOG: &str = "print_debug_log.wasm";
+const MESSAGE: &str = "Hello, world!";
+
+fn builder(disable_debug_log: bool) -> InMemoryWasmTestBuilder {
+    builder_with_costs(disable_debug_log, HostFunctionCosts::default())
+}
+
+fn builder_with_costs(
+    disable_debug_log: bool,
+    host_function_costs: HostFunctionCosts,
+) -> InMemoryWasmTestBuilder {
+    let genesis_config = DEFAULT_GENESIS_CONFIG
+        .clone()
+        .with_host_function_costs(host_function_costs);
+    let engine_config = EngineConfig::new()
+        .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
+        .with_enable_bonding(cfg!(feature = "enable-bonding"))
+        .with_disable_debug_log(disable_debug_log);
+    let global_state = InMemoryGlobalState::empty().expect("should create global state");
+    let empty_root_hash = global_state.empty_root_hash.to_vec();
+    let mut builder = InMemoryWasmTestBuilder::new(global_state, engine_config, empty_root_hash);
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+fn print_request() -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_PRINT_DEBUG_LOG,
+        (String::from(MESSAGE),),
+    )
+    .build()
+}
+
+/// Executes the contract through the gRPC service and returns the debug log of its
+/// `DeployResult`.
+fn print_debug_log(builder: &InMemoryWasmTestBuilder) -> Vec<String> {
+    let mut exec_request = print_request();
+    exec_request.parent_state_hash = builder
+        .get_post_state_hash()
+        .as_slice()
+        .try_into()
+        .expect("should be a valid hash");
+
+    let mut response = builder
+        .get_engine_state()
+        .execute(
+            RequestOptions::new(),
+            ipc::ExecuteRequest::from(exec_request),
+        )
+        .wait_drop_metadata()
+        .expect("should get response");
+    assert!(response.has_success(), "{:?}", response);
+    let mut deploy_results = response.take_success().take_deploy_results();
+    assert_eq!(deploy_results.len(), 1);
+    let mut execution_result = deploy_results[0].take_execution_result();
+    assert!(!execution_result.has_error(), "{:?}", execution_result);
+    execution_result.take_debug_log().into_vec()
+}
+
+#[ignore]
+#[test]
+fn should_return_printed_messages_in_debug_log() {
+    let mut builder = builder(false);
+    builder.exec(print_request()).expect_success();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert_eq!(response[0].debug_log(), &[String::from(MESSAGE)]);
+
+    assert_eq!(print_debug_log(&builder), vec![String::from(MESSAGE)]);
+}
+
+#[ignore]
+#[test]
+fn should_omit_debug_log_when_disabled() {
+    let mut builder = builder(true);
+    builder.exec(print_request()).expect_success();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert!(response[0].debug_log().is_empty());
+
+    assert!(print_debug_log(&builder).is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_charge_print_per_byte_whether_or_not_debug_log_is_disabled() {
+    let default_costs = HostFunctionCosts::default();
+    let free_print = HostFunctionCosts {
+        print: HostFunctionCost::default(),
+        ..default_costs
+    };
+    let expected_difference = U512::from(default_costs.print.per_byte) * U512::from(MESSAGE.len());
+
+    for disable_debug_log in &[false, true] {
+        let cost: Gas = builder_with_costs(*disable_debug_log, default_costs)
+            .exec(print_request())
+            .expect_success()
+            .exec_costs(0)[0];
+        let free_print_cost: Gas = builder_with_costs(*disable_debug_log, free_print)
+            .exec(print_request())
+            .expect_success()
+            .exec_costs(0)[0];
+        assert_eq!(cost.value() - free_print_cost.value(), expected_difference);
+    }
+}
@@ -0,0 +1,121 @@
+use engine_shared::{
+    gas::Gas,
+    host_function_costs::{HostFunctionCost, HostFunctionCosts},
+    stored_value::StoredValue,
+};
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{Key, URef, U512};
+
+const CONTRACT_RANDOM_BYTES: &str = "random_bytes.wasm";
+const RANDOM_BYTES_KEY: &str = "random_bytes";
+const UREF_KEY: &str = "uref";
+const RANDOM_BYTES_LENGTH: usize = 32;
+const DEPLOY_HASH_1: [u8; 32] = [1u8; 32];
+const DEPLOY_HASH_2: [u8; 32] = [2u8; 32];
+
+fn run_random_bytes(deploy_hash: [u8; 32], draw_random_bytes: bool) -> InMemoryWasmTestBuilder {
+    run_random_bytes_with_costs(deploy_hash, draw_random_bytes, HostFunctionCosts::default())
+}
+
+fn run_random_bytes_with_costs(
+    deploy_hash: [u8; 32],
+    draw_random_bytes: bool,
+    host_function_costs: HostFunctionCosts,
+) -> InMemoryWasmTestBuilder {
+    let genesis_config = DEFAULT_GENESIS_CONFIG
+        .clone()
+        .with_host_function_costs(host_function_costs);
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(CONTRACT_RANDOM_BYTES, (draw_random_bytes,))
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(deploy_hash)
+        .build();
+    let exec_request = ExecuteRequestBuilder::from_deploy_item(deploy).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&genesis_config)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn get_random_bytes(builder: &InMemoryWasmTestBuilder) -> Vec<u8> {
+    match builder.query(
+        None,
+        Key::Account(DEFAULT_ACCOUNT_ADDR),
+        &[RANDOM_BYTES_KEY],
+    ) {
+        Ok(StoredValue::CLValue(cl_value)) => cl_value.into_t().expect("should have bytes"),
+        other => panic!("should find random bytes but got {:?}", other),
+    }
+}
+
+fn get_uref(builder: &InMemoryWasmTestBuilder) -> URef {
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let key = account
+        .named_keys()
+        .get(UREF_KEY)
+        .expect("should have uref key");
+    *key.as_uref().expect("should be uref")
+}
+
+#[ignore]
+#[test]
+fn should_return_same_random_bytes_for_same_deploy() {
+    let random_bytes_1 = get_random_bytes(&run_random_bytes(DEPLOY_HASH_1, true));
+    let random_bytes_2 = get_random_bytes(&run_random_bytes(DEPLOY_HASH_1, true));
+    assert_eq!(random_bytes_1.len(), RANDOM_BYTES_LENGTH);
+    assert_eq!(random_bytes_1, random_bytes_2);
+}
+
+#[ignore]
+#[test]
+fn should_return_different_random_bytes_for_different_deploys() {
+    let random_bytes_1 = get_random_bytes(&run_random_bytes(DEPLOY_HASH_1, true));
+    let random_bytes_2 = get_random_bytes(&run_random_bytes(DEPLOY_HASH_2, true));
+    assert_ne!(random_bytes_1, random_bytes_2);
+}
+
+#[ignore]
+#[test]
+fn should_not_change_uref_addresses_when_drawing_random_bytes() {
+    let uref_with_random_bytes = get_uref(&run_random_bytes(DEPLOY_HASH_1, true));
+    let uref_without_random_bytes = get_uref(&run_random_bytes(DEPLOY_HASH_1, false));
+    assert_eq!(
+        uref_with_random_bytes.addr(),
+        uref_without_random_bytes.addr()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_charge_random_bytes_per_byte_from_host_function_costs() {
+    let default_costs = HostFunctionCosts::default();
+    let free_random_bytes = HostFunctionCosts {
+        random_bytes: HostFunctionCost::default(),
+        ..default_costs
+    };
+    let cost: Gas =
+        run_random_bytes_with_costs(DEPLOY_HASH_1, true, default_costs).exec_costs(0)[0];
+    let free_random_bytes_cost: Gas =
+        run_random_bytes_with_costs(DEPLOY_HASH_1, true, free_random_bytes).exec_costs(0)[0];
+
+    let expected_difference =
+        U512::from(default_costs.random_bytes.per_byte) * U512::from(RANDOM_BYTES_LENGTH);
+    assert_eq!(
+        cost.value() - free_random_bytes_cost.value(),
+        expected_difference
+    );
+}
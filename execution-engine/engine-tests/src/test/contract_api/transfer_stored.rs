@@ -86,11 +86,7 @@ fn should_transfer_to_account_stored() {
 
     let mut tally = U512::from(transferred_amount) + modified_balance_bravo;
 
-    if cfg!(feature = "use-system-contracts") {
-        tally += *DEFAULT_PAYMENT * 2;
-    } else {
-        tally += motes_alpha.value() + motes_bravo.value();
-    }
+    tally += motes_alpha.value() + motes_bravo.value();
 
     assert!(
         modified_balance_alpha < initial_balance,
@@ -0,0 +1,100 @@
+use engine_core::engine_state::{call_trace::CallTrace, execution_result::ExecutionResult};
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{Key, Phase};
+
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const CONTRACT_DO_NOTHING_STORED: &str = "do_nothing_stored.wasm";
+const CONTRACT_DO_NOTHING_STORED_CALLER: &str = "do_nothing_stored_caller.wasm";
+const DO_NOTHING_STORED_NAME: &str = "do_nothing_stored";
+const DESTINATION_UREF: &str = "uref";
+const PURSE_1: &str = "purse_1";
+
+fn session_trace(execution_result: &ExecutionResult) -> &CallTrace {
+    execution_result
+        .trace()
+        .iter()
+        .find(|call_trace| call_trace.phase() == Phase::Session)
+        .expect("should have traced the session code")
+}
+
+#[ignore]
+#[test]
+fn should_not_trace_without_trace_flag() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DO_NOTHING, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert!(response[0].trace().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_trace_calls_to_stored_contracts() {
+    let store_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING_STORED,
+        (DESTINATION_UREF,),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(store_request)
+        .expect_success()
+        .commit();
+
+    let do_nothing_stored_uref = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(DO_NOTHING_STORED_NAME)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should have do_nothing_stored uref");
+
+    let call_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING_STORED_CALLER,
+        (do_nothing_stored_uref, PURSE_1),
+    )
+    .with_trace(true)
+    .build();
+
+    builder.exec(call_request).expect_success().commit();
+
+    let response = builder
+        .get_exec_response(1)
+        .expect("should have exec response");
+    let session = session_trace(&response[0]);
+
+    assert_eq!(session.key(), Key::Account(DEFAULT_ACCOUNT_ADDR));
+    assert_eq!(session.method(), None, "first session arg is not a string");
+    assert!(session.error().is_none());
+    assert_eq!(session.host_functions().get("call_contract"), Some(&1));
+    assert_eq!(session.host_functions().get("get_arg"), Some(&2));
+
+    assert_eq!(session.calls().len(), 1);
+    let call = &session.calls()[0];
+    assert_eq!(
+        call.key().as_uref().map(|uref| uref.addr()),
+        Some(do_nothing_stored_uref.addr())
+    );
+    assert_eq!(call.phase(), Phase::Session);
+    assert_eq!(call.method(), Some(PURSE_1));
+    assert!(call.args_size() > 0);
+    assert!(call.error().is_none());
+    assert!(call.calls().is_empty());
+    assert!(call.gas_used() <= session.gas_used());
+}
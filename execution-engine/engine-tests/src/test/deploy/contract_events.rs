@@ -0,0 +1,120 @@
+use engine_shared::{
+    gas::Gas,
+    host_function_costs::{HostFunctionCost, HostFunctionCosts},
+};
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr::ToBytes, CLValue, Key, U512};
+
+const CONTRACT_EMIT_EVENT: &str = "emit_event.wasm";
+const TOPIC_MESSAGE: &str = "message";
+const TOPIC_AMOUNT: &str = "amount";
+const MESSAGE: &str = "Hello, world!";
+const AMOUNT: u64 = 42;
+
+#[ignore]
+#[test]
+fn should_return_emitted_events_in_order() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_EMIT_EVENT,
+        (String::from(MESSAGE), U512::from(AMOUNT)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    let events = response[0].events();
+    assert_eq!(events.len(), 2);
+
+    assert_eq!(events[0].emitter(), Key::Account(DEFAULT_ACCOUNT_ADDR));
+    assert_eq!(events[0].topic(), TOPIC_MESSAGE);
+    let message: String = events[0]
+        .payload()
+        .clone()
+        .into_t()
+        .expect("should be a string");
+    assert_eq!(message, MESSAGE);
+
+    assert_eq!(events[1].emitter(), Key::Account(DEFAULT_ACCOUNT_ADDR));
+    assert_eq!(events[1].topic(), TOPIC_AMOUNT);
+    let amount: U512 = events[1]
+        .payload()
+        .clone()
+        .into_t()
+        .expect("should be a U512");
+    assert_eq!(amount, U512::from(AMOUNT));
+}
+
+#[ignore]
+#[test]
+fn should_discard_events_of_failed_deploy() {
+    // the contract reverts for the missing amount after emitting the message
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_EMIT_EVENT,
+        (String::from(MESSAGE),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert!(response[0].is_failure());
+    assert!(response[0].events().is_empty());
+}
+
+fn emit_event_cost(host_function_costs: HostFunctionCosts) -> Gas {
+    let genesis_config = DEFAULT_GENESIS_CONFIG
+        .clone()
+        .with_host_function_costs(host_function_costs);
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_EMIT_EVENT,
+        (String::from(MESSAGE), U512::from(AMOUNT)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&genesis_config)
+        .exec(exec_request)
+        .expect_success();
+    builder.exec_costs(0)[0]
+}
+
+#[ignore]
+#[test]
+fn should_charge_events_per_byte_from_host_function_costs() {
+    let default_costs = HostFunctionCosts::default();
+    let free_events = HostFunctionCosts {
+        emit_event: HostFunctionCost::default(),
+        ..default_costs
+    };
+    let cost = emit_event_cost(default_costs);
+    let free_events_cost = emit_event_cost(free_events);
+
+    // Both events are charged for their serialized topic and payload.
+    let message = CLValue::from_t(String::from(MESSAGE)).expect("should create CLValue");
+    let amount = CLValue::from_t(U512::from(AMOUNT)).expect("should create CLValue");
+    let bytes = String::from(TOPIC_MESSAGE).serialized_length()
+        + message.serialized_length()
+        + String::from(TOPIC_AMOUNT).serialized_length()
+        + amount.serialized_length();
+    let expected_difference = U512::from(default_costs.emit_event.per_byte) * U512::from(bytes);
+    assert_eq!(cost.value() - free_events_cost.value(), expected_difference);
+}
@@ -0,0 +1,167 @@
+use std::convert::TryInto;
+
+use engine_core::engine_state::{
+    deploy_item::DeployItem,
+    estimate::{EstimateRequest, EstimateResult},
+};
+use engine_shared::{gas::Gas, newtypes::CorrelationId};
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_BLOCK_TIME,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_REVERT: &str = "revert.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const TRANSFER_AMOUNT: u64 = 1_000_000;
+
+fn transfer_deploy() -> DeployItem {
+    DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            CONTRACT_TRANSFER_TO_ACCOUNT,
+            (ACCOUNT_1_ADDR, U512::from(TRANSFER_AMOUNT)),
+        )
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([1u8; 32])
+        .build()
+}
+
+fn estimate(builder: &InMemoryWasmTestBuilder, gas_limit: Option<Gas>) -> EstimateResult {
+    estimate_deploy(builder, transfer_deploy(), gas_limit)
+}
+
+fn estimate_deploy(
+    builder: &InMemoryWasmTestBuilder,
+    deploy_item: DeployItem,
+    gas_limit: Option<Gas>,
+) -> EstimateResult {
+    let parent_state_hash = builder
+        .get_post_state_hash()
+        .as_slice()
+        .try_into()
+        .expect("should be a valid hash");
+    let estimate_request = EstimateRequest::new(
+        parent_state_hash,
+        DEFAULT_BLOCK_TIME,
+        0,
+        DEFAULT_ACCOUNT_ADDR,
+        deploy_item,
+        *DEFAULT_PROTOCOL_VERSION,
+        gas_limit,
+    );
+    builder
+        .get_engine_state()
+        .run_estimate(CorrelationId::new(), estimate_request)
+        .expect("should estimate")
+}
+
+#[ignore]
+#[test]
+fn should_estimate_gas_of_a_deploy_without_committing() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let post_state_hash = builder.get_post_state_hash();
+
+    let (payment, session, finalize) = match estimate(&builder, None) {
+        EstimateResult::Success {
+            payment,
+            session: Some(session),
+            finalize: Some(finalize),
+        } => (payment, session, finalize),
+        result => panic!("should estimate session and finalize: {}", result),
+    };
+    assert!(payment.is_success(), "{:?}", payment);
+    assert!(session.is_success(), "{:?}", session);
+    assert!(finalize.is_success(), "{:?}", finalize);
+    assert!(builder.get_account(ACCOUNT_1_ADDR).is_none());
+    assert_eq!(builder.get_post_state_hash(), post_state_hash);
+
+    let exec_request = ExecuteRequestBuilder::new()
+        .push_deploy(transfer_deploy())
+        .build();
+    builder.exec(exec_request).expect_success();
+
+    assert_eq!(builder.exec_costs(0)[0], payment.cost() + session.cost());
+}
+
+#[ignore]
+#[test]
+fn should_report_session_out_of_gas_with_gas_limit() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let session_cost = match estimate(&builder, None) {
+        EstimateResult::Success {
+            session: Some(session),
+            ..
+        } => session.cost(),
+        result => panic!("should estimate session: {}", result),
+    };
+
+    let gas_limit = Gas::new(session_cost.value() - 1);
+    match estimate(&builder, Some(gas_limit)) {
+        EstimateResult::Success {
+            session: Some(session),
+            finalize: Some(finalize),
+            ..
+        } => {
+            assert!(session.is_failure());
+            assert!(session.cost() <= gas_limit);
+            // the payment is still finalized
+            assert!(finalize.is_success(), "{:?}", finalize);
+        }
+        result => panic!("should estimate session: {}", result),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_cap_gas_limit_at_max_session_gas() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    // A gas limit above the protocol's session gas limit doesn't buy more gas.
+    let max_session_gas = Gas::new(U512::from(
+        DEFAULT_GENESIS_CONFIG.gas_limits().max_session_gas,
+    ));
+    let unlimited = estimate(&builder, Some(Gas::new(U512::from(std::u64::MAX))));
+    let capped = estimate(&builder, Some(max_session_gas));
+    assert_eq!(unlimited.to_string(), capped.to_string());
+}
+
+#[ignore]
+#[test]
+fn should_not_run_session_after_payment_failure() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let deploy_item = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            CONTRACT_TRANSFER_TO_ACCOUNT,
+            (ACCOUNT_1_ADDR, U512::from(TRANSFER_AMOUNT)),
+        )
+        .with_payment_code(CONTRACT_REVERT, ())
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([1u8; 32])
+        .build();
+
+    match estimate_deploy(&builder, deploy_item, None) {
+        EstimateResult::Success {
+            payment,
+            session,
+            finalize,
+        } => {
+            assert!(payment.is_failure(), "{:?}", payment);
+            assert!(session.is_none());
+            assert!(finalize.is_none());
+        }
+        EstimateResult::RootNotFound(_) => panic!("should find root"),
+    }
+}
@@ -0,0 +1,336 @@
+use std::convert::TryInto;
+
+use lazy_static::lazy_static;
+use num_traits::identities::Zero;
+
+use engine_core::engine_state::{
+    deploy_item::DeployItem,
+    engine_config::EngineConfig,
+    execute_block::{ExecuteBlockRequest, ExecuteBlockResult},
+    genesis::GenesisAccount,
+    Error, CONV_RATE,
+};
+use engine_shared::{motes::Motes, newtypes::CorrelationId};
+use engine_storage::global_state::in_memory::InMemoryGlobalState;
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const ACCOUNT_3_ADDR: PublicKey = PublicKey::ed25519_from([3u8; 32]);
+const PROPOSER: PublicKey = PublicKey::ed25519_from([7u8; 32]);
+const PARALLEL_EXECUTION_THREADS: usize = 4;
+
+lazy_static! {
+    static ref TRANSFER_1_AMOUNT: U512 = (U512::from(25_000_000) + 100) * CONV_RATE;
+    static ref TRANSFER_2_AMOUNT: U512 = U512::from(750);
+}
+
+fn transfer_deploy(
+    sender: PublicKey,
+    target: PublicKey,
+    amount: U512,
+    deploy_hash: [u8; 32],
+) -> DeployItem {
+    DeployItemBuilder::new()
+        .with_address(sender)
+        .with_session_code(CONTRACT_TRANSFER_TO_ACCOUNT, (target, amount))
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[sender])
+        .with_deploy_hash(deploy_hash)
+        .build()
+}
+
+fn dependent_transfers_request() -> ExecuteRequestBuilder {
+    // The second deploy is sent from the account created by the first one.
+    ExecuteRequestBuilder::new()
+        .push_deploy(transfer_deploy(
+            DEFAULT_ACCOUNT_ADDR,
+            ACCOUNT_1_ADDR,
+            *TRANSFER_1_AMOUNT,
+            [1u8; 32],
+        ))
+        .push_deploy(transfer_deploy(
+            ACCOUNT_1_ADDR,
+            ACCOUNT_2_ADDR,
+            *TRANSFER_2_AMOUNT,
+            [2u8; 32],
+        ))
+}
+
+fn parallel_builder() -> InMemoryWasmTestBuilder {
+    let engine_config = EngineConfig::new()
+        .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
+        .with_enable_bonding(cfg!(feature = "enable-bonding"))
+        .with_parallel_execution_threads(PARALLEL_EXECUTION_THREADS);
+    let global_state = InMemoryGlobalState::empty().expect("should create global state");
+    let empty_root_hash = global_state.empty_root_hash.to_vec();
+    InMemoryWasmTestBuilder::new(global_state, engine_config, empty_root_hash)
+}
+
+fn block_request() -> ExecuteRequestBuilder {
+    // The third deploy reads the account written by the second one.
+    dependent_transfers_request().push_deploy(transfer_deploy(
+        ACCOUNT_1_ADDR,
+        ACCOUNT_3_ADDR,
+        *TRANSFER_2_AMOUNT,
+        [3u8; 32],
+    ))
+}
+
+fn assert_same_results(serial: &InMemoryWasmTestBuilder, parallel: &InMemoryWasmTestBuilder) {
+    let serial_results = serial
+        .get_exec_response(0)
+        .expect("should have exec response");
+    let parallel_results = parallel
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert_eq!(serial_results.len(), parallel_results.len());
+    for (serial_result, parallel_result) in serial_results.iter().zip(parallel_results) {
+        assert_eq!(serial_result.is_failure(), parallel_result.is_failure());
+        assert_eq!(serial_result.cost(), parallel_result.cost());
+        assert_eq!(serial_result.effect(), parallel_result.effect());
+    }
+}
+
+#[ignore]
+#[test]
+fn should_run_deploys_of_a_block_on_chained_state() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let genesis_hash = builder.get_post_state_hash();
+
+    builder.exec_block(dependent_transfers_request().build(), false);
+
+    let exec_response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert_eq!(exec_response.len(), 2);
+    for result in exec_response {
+        assert!(!result.is_failure(), "{:?}", result);
+    }
+
+    assert_ne!(builder.get_post_state_hash(), genesis_hash);
+
+    let account_2 = builder
+        .get_account(ACCOUNT_2_ADDR)
+        .expect("should get account 2");
+    assert_eq!(
+        builder.get_purse_balance(account_2.main_purse()),
+        *TRANSFER_2_AMOUNT
+    );
+}
+
+#[ignore]
+#[test]
+fn should_return_effects_of_a_block_without_committing() {
+    let mut committed = InMemoryWasmTestBuilder::default();
+    committed
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec_block(dependent_transfers_request().build(), false);
+
+    let mut uncommitted = InMemoryWasmTestBuilder::default();
+    uncommitted.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let genesis_hash = uncommitted.get_post_state_hash();
+
+    uncommitted.exec_block_uncommitted(dependent_transfers_request().build(), false);
+
+    let exec_response = uncommitted
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert_eq!(exec_response.len(), 2);
+    for result in exec_response {
+        assert!(!result.is_failure(), "{:?}", result);
+    }
+
+    // nothing was written
+    assert_eq!(uncommitted.get_post_state_hash(), genesis_hash);
+    assert!(uncommitted.get_account(ACCOUNT_2_ADDR).is_none());
+
+    // committing the returned effects leads to the same state as committing the block
+    uncommitted.commit();
+    assert_eq!(
+        uncommitted.get_post_state_hash(),
+        committed.get_post_state_hash()
+    );
+    assert!(uncommitted.get_account(ACCOUNT_2_ADDR).is_some());
+}
+
+#[ignore]
+#[test]
+fn should_not_see_effects_of_previous_deploys_in_execute() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    builder.exec(dependent_transfers_request().build());
+
+    let exec_response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    assert!(!exec_response[0].is_failure());
+    assert!(exec_response[1].is_failure());
+}
+
+#[ignore]
+#[test]
+fn should_run_step_after_deploys_of_a_block() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    builder.exec_block(
+        dependent_transfers_request()
+            .with_proposer(PROPOSER)
+            .build(),
+        true,
+    );
+
+    let exec_response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    for result in exec_response {
+        assert!(!result.is_failure(), "{:?}", result);
+    }
+
+    assert!(builder.get_account(ACCOUNT_2_ADDR).is_some());
+}
+
+#[ignore]
+#[test]
+fn should_not_run_step_without_proposer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let genesis_hash = builder.get_post_state_hash();
+
+    let mut exec_request = dependent_transfers_request().build();
+    let block_request = ExecuteBlockRequest::new(
+        genesis_hash
+            .as_slice()
+            .try_into()
+            .expect("should be a valid hash"),
+        exec_request.block_time,
+        exec_request.block_height,
+        None,
+        exec_request.take_deploys(),
+        exec_request.protocol_version,
+        true,
+        true,
+    );
+
+    let result = builder
+        .get_engine_state()
+        .run_execute_block(CorrelationId::new(), block_request);
+    match result {
+        Err(Error::MissingProposer) => {}
+        Err(error) => panic!("Unexpected error: {:?}", error),
+        Ok(result) => panic!("Expected the block to be refused, got: {}", result),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_run_deploys_of_a_block_in_parallel_as_if_serially() {
+    let mut serial = InMemoryWasmTestBuilder::default();
+    serial
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec_block(block_request().build(), false);
+
+    let mut parallel = parallel_builder();
+    parallel
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec_block(block_request().build(), false);
+
+    assert_same_results(&serial, &parallel);
+    assert_eq!(serial.get_post_state_hash(), parallel.get_post_state_hash());
+    assert!(parallel.get_account(ACCOUNT_2_ADDR).is_some());
+    assert!(parallel.get_account(ACCOUNT_3_ADDR).is_some());
+}
+
+#[ignore]
+#[test]
+fn should_execute_deploys_in_parallel() {
+    let mut serial = InMemoryWasmTestBuilder::default();
+    serial
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(block_request().build());
+
+    let mut parallel = parallel_builder();
+    parallel
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(block_request().build());
+
+    assert_same_results(&serial, &parallel);
+}
+
+#[ignore]
+#[test]
+fn should_not_reexecute_independent_deploys() {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(GenesisAccount::new(
+            ACCOUNT_1_ADDR,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::zero(),
+        ));
+        tmp
+    };
+    let mut builder = parallel_builder();
+    builder.run_genesis(&utils::create_genesis_config(accounts, Default::default()));
+
+    // Both deploys pay into the same system purses, but are otherwise independent.
+    let mut exec_request = ExecuteRequestBuilder::new()
+        .push_deploy(transfer_deploy(
+            DEFAULT_ACCOUNT_ADDR,
+            ACCOUNT_2_ADDR,
+            *TRANSFER_2_AMOUNT,
+            [1u8; 32],
+        ))
+        .push_deploy(transfer_deploy(
+            ACCOUNT_1_ADDR,
+            ACCOUNT_3_ADDR,
+            *TRANSFER_2_AMOUNT,
+            [2u8; 32],
+        ))
+        .build();
+    let block_request = ExecuteBlockRequest::new(
+        builder
+            .get_post_state_hash()
+            .as_slice()
+            .try_into()
+            .expect("should be a valid hash"),
+        exec_request.block_time,
+        exec_request.block_height,
+        exec_request.proposer,
+        exec_request.take_deploys(),
+        exec_request.protocol_version,
+        false,
+        true,
+    );
+
+    match builder
+        .get_engine_state()
+        .run_execute_block(CorrelationId::new(), block_request)
+        .expect("should execute block")
+    {
+        ExecuteBlockResult::Success {
+            execution_results,
+            reexecuted_deploys,
+            ..
+        } => {
+            assert_eq!(execution_results.len(), 2);
+            for result in execution_results {
+                assert!(!result.is_failure(), "{:?}", result);
+            }
+            assert_eq!(reexecuted_deploys, 0);
+        }
+        result => panic!("Execute block failure: {}", result),
+    }
+}
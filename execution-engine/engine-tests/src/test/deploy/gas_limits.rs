@@ -0,0 +1,115 @@
+use engine_core::{
+    engine_state::{execution_result::ExecutionResult, Error},
+    execution,
+};
+use engine_shared::gas_limits::GasLimits;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+
+fn do_nothing_request(deploy_hashes: &[[u8; 32]]) -> ExecuteRequestBuilder {
+    deploy_hashes
+        .iter()
+        .fold(ExecuteRequestBuilder::new(), |builder, deploy_hash| {
+            let deploy = DeployItemBuilder::new()
+                .with_address(DEFAULT_ACCOUNT_ADDR)
+                .with_session_code(CONTRACT_DO_NOTHING, ())
+                .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+                .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+                .with_deploy_hash(*deploy_hash)
+                .build();
+            builder.push_deploy(deploy)
+        })
+}
+
+fn run_genesis(gas_limits: GasLimits) -> InMemoryWasmTestBuilder {
+    let genesis_config = DEFAULT_GENESIS_CONFIG.clone().with_gas_limits(gas_limits);
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_reject_deploys_exceeding_block_gas_limit() {
+    let deploy_cost = {
+        let mut builder = run_genesis(GasLimits::default());
+        builder
+            .exec(do_nothing_request(&[[1u8; 32]]).build())
+            .expect_success();
+        builder.exec_costs(0)[0]
+    };
+
+    // The block has room for exactly one deploy.
+    let gas_limits = GasLimits {
+        max_block_gas: deploy_cost.value().as_u64(),
+        ..Default::default()
+    };
+    let mut builder = run_genesis(gas_limits);
+    builder.exec(do_nothing_request(&[[1u8; 32], [2u8; 32]]).build());
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    assert!(response[0].is_success());
+    assert_eq!(response[0].cost(), deploy_cost);
+    assert!(response[1].has_precondition_failure());
+    match response[1].as_ref() {
+        ExecutionResult::Failure {
+            error: Error::BlockGasLimitExceeded,
+            ..
+        } => {}
+        other => panic!("should exceed block gas limit but got {:?}", other),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_keep_result_of_deploy_crossing_block_gas_limit() {
+    let mut builder = run_genesis(GasLimits {
+        max_block_gas: 1,
+        ..Default::default()
+    });
+    builder.exec(do_nothing_request(&[[1u8; 32], [2u8; 32]]).build());
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    assert!(response[0].is_success());
+    assert!(response[0].cost().value() > 1.into());
+    match response[1].as_ref() {
+        ExecutionResult::Failure {
+            error: Error::BlockGasLimitExceeded,
+            ..
+        } => {}
+        other => panic!("should exceed block gas limit but got {:?}", other),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_cap_session_gas() {
+    let gas_limits = GasLimits {
+        max_session_gas: 1,
+        ..Default::default()
+    };
+    let mut builder = run_genesis(gas_limits);
+    builder.exec(do_nothing_request(&[[1u8; 32]]).build());
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    match response[0].as_ref() {
+        ExecutionResult::Failure {
+            error: Error::Exec(execution::Error::GasLimit),
+            ..
+        } => {}
+        other => panic!("should run out of session gas but got {:?}", other),
+    }
+}
@@ -0,0 +1,111 @@
+use engine_core::engine_state::{CONV_RATE, DEFAULT_MIN_GAS_PRICE};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use types::U512;
+
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const CONTRACT_ENDLESS_LOOP: &str = "endless_loop.wasm";
+
+fn run_do_nothing(gas_price: u64) -> InMemoryWasmTestBuilder {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(CONTRACT_DO_NOTHING, ())
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([1u8; 32])
+        .with_gas_price(gas_price)
+        .build();
+    let exec_request = ExecuteRequestBuilder::from_deploy_item(deploy).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+fn get_spent_motes(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - builder.get_purse_balance(account.main_purse())
+}
+
+#[ignore]
+#[test]
+fn should_charge_motes_at_deploy_gas_price() {
+    let mut builder_1 = run_do_nothing(CONV_RATE);
+    let mut builder_2 = run_do_nothing(2 * CONV_RATE);
+    builder_1.expect_success();
+    builder_2.expect_success();
+
+    let gas_cost = builder_1.exec_costs(0)[0];
+    assert_eq!(gas_cost, builder_2.exec_costs(0)[0]);
+
+    let spent_1 = get_spent_motes(&builder_1);
+    let spent_2 = get_spent_motes(&builder_2);
+    assert_eq!(
+        spent_1,
+        Motes::from_gas(gas_cost, CONV_RATE)
+            .expect("should have motes")
+            .value()
+    );
+    assert_eq!(spent_2, spent_1 * 2);
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_below_min_gas_price() {
+    let builder = run_do_nothing(DEFAULT_MIN_GAS_PRICE - 1);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+
+    let precondition_failure = utils::get_precondition_failure(response);
+    assert_eq!(
+        precondition_failure,
+        format!(
+            "Gas price too low: minimum {}, actual {}",
+            DEFAULT_MIN_GAS_PRICE,
+            DEFAULT_MIN_GAS_PRICE - 1
+        )
+    );
+}
+
+#[ignore]
+#[test]
+fn should_cap_payment_gas_at_low_gas_price() {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(CONTRACT_DO_NOTHING, ())
+        .with_payment_code(CONTRACT_ENDLESS_LOOP, ())
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([1u8; 32])
+        .with_gas_price(DEFAULT_MIN_GAS_PRICE)
+        .build();
+    let exec_request = ExecuteRequestBuilder::from_deploy_item(deploy).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    let error_message = utils::get_error_message(response);
+    assert!(
+        error_message.contains("GasLimit"),
+        "expected gas limit error, got: {}",
+        error_message
+    );
+}
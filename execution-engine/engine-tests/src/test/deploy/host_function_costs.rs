@@ -0,0 +1,51 @@
+use engine_shared::{
+    gas::Gas,
+    host_function_costs::{HostFunctionCost, HostFunctionCosts},
+};
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{U512, UREF_SERIALIZED_LENGTH};
+
+const CONTRACT_CREATE_PURSE_01: &str = "create_purse_01.wasm";
+const TEST_PURSE_NAME: &str = "test_purse";
+const CREATE_PURSE_BASE_COST: u32 = 1_000_000;
+const CREATE_PURSE_PER_BYTE_COST: u32 = 1_000;
+
+fn create_purse_cost(host_function_costs: HostFunctionCosts) -> Gas {
+    let genesis_config = DEFAULT_GENESIS_CONFIG
+        .clone()
+        .with_host_function_costs(host_function_costs);
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CREATE_PURSE_01,
+        (TEST_PURSE_NAME,),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&genesis_config)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder.exec_costs(0)[0]
+}
+
+#[ignore]
+#[test]
+fn should_charge_host_function_costs() {
+    let default_cost = create_purse_cost(HostFunctionCosts::default());
+
+    let host_function_costs = HostFunctionCosts {
+        create_purse: HostFunctionCost::new(CREATE_PURSE_BASE_COST, CREATE_PURSE_PER_BYTE_COST),
+        ..Default::default()
+    };
+    let cost = create_purse_cost(host_function_costs);
+
+    // `create_purse` is called once, with room for a serialized `URef`.
+    let expected_difference = U512::from(CREATE_PURSE_BASE_COST)
+        + U512::from(CREATE_PURSE_PER_BYTE_COST) * U512::from(UREF_SERIALIZED_LENGTH);
+    assert_eq!(cost.value() - default_cost.value(), expected_difference);
+}
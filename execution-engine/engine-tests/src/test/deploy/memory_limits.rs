@@ -0,0 +1,60 @@
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PAYMENT, DEFAULT_WASM_COSTS, STANDARD_PAYMENT_CONTRACT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+
+/// Returns a session contract which starts with one page of memory and grows it by `pages`.
+fn grow_memory_contract(pages: u32) -> Vec<u8> {
+    let wat = format!(
+        r#"
+(module
+    (memory (;0;) 1)
+    (export "memory" (memory 0))
+    (func (;0;)
+      i32.const {}
+      memory.grow
+      drop)
+    (export "call" (func 0)))
+"#,
+        pages
+    );
+    wabt::wat2wasm(wat).expect("should parse")
+}
+
+fn grow_memory(pages: u32) -> InMemoryWasmTestBuilder {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_bytes(grow_memory_contract(pages), ())
+        .with_payment_code(STANDARD_PAYMENT_CONTRACT, (*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([42; 32])
+        .build();
+    let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_grow_memory_up_to_limit() {
+    grow_memory(DEFAULT_WASM_COSTS.max_memory_pages - 1).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_grow_memory_past_limit() {
+    let builder = grow_memory(DEFAULT_WASM_COSTS.max_memory_pages);
+    let message = builder.exec_error_message(0).expect("should fail");
+    assert!(
+        message.contains("MemoryLimitExceeded"),
+        "Error message {:?} does not contain expected pattern",
+        message
+    );
+}
@@ -1,3 +1,12 @@
+mod call_trace;
+mod contract_events;
+mod estimate;
+mod execute_block;
+mod gas_limits;
+mod gas_price;
+mod host_function_costs;
+mod memory_limits;
 mod non_standard_payment;
 mod preconditions;
+mod storage_costs;
 mod stored_contracts;
@@ -0,0 +1,68 @@
+use engine_core::engine_state::genesis::GenesisConfig;
+use engine_shared::gas::Gas;
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG, DEFAULT_WASM_COSTS,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use engine_wasm_prep::wasm_costs::WasmCosts;
+use types::U512;
+
+const CONTRACT_STORE_BYTES: &str = "store_bytes.wasm";
+const STORAGE_PER_BYTE_COST: u32 = 100;
+const LENGTH: u32 = 1_000;
+
+fn store_bytes_cost(storage_per_byte: u32, length: u32) -> Gas {
+    let genesis_config = GenesisConfig::new(
+        DEFAULT_GENESIS_CONFIG.name().to_string(),
+        DEFAULT_GENESIS_CONFIG.timestamp(),
+        DEFAULT_GENESIS_CONFIG.protocol_version(),
+        DEFAULT_GENESIS_CONFIG.mint_installer_bytes().to_vec(),
+        DEFAULT_GENESIS_CONFIG
+            .proof_of_stake_installer_bytes()
+            .to_vec(),
+        DEFAULT_GENESIS_CONFIG
+            .standard_payment_installer_bytes()
+            .to_vec(),
+        DEFAULT_GENESIS_CONFIG.accounts().to_vec(),
+        DEFAULT_GENESIS_CONFIG.state_infos().to_vec(),
+        WasmCosts {
+            storage_per_byte,
+            ..*DEFAULT_WASM_COSTS
+        },
+    );
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_STORE_BYTES, (length,))
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&genesis_config)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder.exec_costs(0)[0]
+}
+
+/// Returns the gas charged for storage when storing `length` bytes.
+fn storage_cost(length: u32) -> U512 {
+    store_bytes_cost(STORAGE_PER_BYTE_COST, length).value() - store_bytes_cost(0, length).value()
+}
+
+#[ignore]
+#[test]
+fn should_charge_for_stored_bytes() {
+    let empty_storage_cost = storage_cost(0);
+    assert!(
+        !empty_storage_cost.is_zero(),
+        "storing an empty value and its named key should not be free"
+    );
+
+    let storage_cost = storage_cost(LENGTH);
+    assert_eq!(
+        storage_cost - empty_storage_cost,
+        U512::from(STORAGE_PER_BYTE_COST) * U512::from(LENGTH),
+        "each extra stored byte should cost the storage price"
+    );
+}
@@ -101,11 +101,7 @@ fn should_exec_non_stored_code() {
     let motes = Motes::from_gas(gas, CONV_RATE).expect("should have motes");
     let mut tally = U512::from(transferred_amount) + modified_balance;
 
-    if cfg!(feature = "use-system-contracts") {
-        tally += payment_purse_amount;
-    } else {
-        tally += motes.value();
-    }
+    tally += motes.value();
 
     assert_eq!(
         initial_balance, tally,
@@ -190,11 +186,7 @@ fn should_exec_stored_code_by_hash() {
 
     let mut tally = U512::from(transferred_amount) + modified_balance_bravo;
 
-    if cfg!(feature = "use-system-contracts") {
-        tally += payment_purse_amount * 2;
-    } else {
-        tally += motes_alpha.value() + motes_bravo.value();
-    }
+    tally += motes_alpha.value() + motes_bravo.value();
 
     assert!(
         modified_balance_alpha < initial_balance,
@@ -285,11 +277,7 @@ fn should_exec_stored_code_by_named_hash() {
 
     let mut tally = U512::from(transferred_amount) + modified_balance_bravo;
 
-    if cfg!(feature = "use-system-contracts") {
-        tally += payment_purse_amount * 2;
-    } else {
-        tally += motes_alpha.value() + motes_bravo.value();
-    }
+    tally += motes_alpha.value() + motes_bravo.value();
 
     assert!(
         modified_balance_alpha < initial_balance,
@@ -391,11 +379,7 @@ fn should_exec_stored_code_by_named_uref() {
 
     let mut tally = U512::from(transferred_amount) + modified_balance_bravo;
 
-    if cfg!(feature = "use-system-contracts") {
-        tally += payment_purse_amount * 2;
-    } else {
-        tally += motes_alpha.value() + motes_bravo.value();
-    }
+    tally += motes_alpha.value() + motes_bravo.value();
 
     assert!(
         modified_balance_alpha < initial_balance,
@@ -510,11 +494,7 @@ fn should_exec_payment_and_session_stored_code() {
 
     let mut tally = U512::from(transferred_amount) + modified_balance;
 
-    if cfg!(feature = "use-system-contracts") {
-        tally += *DEFAULT_PAYMENT + payment_purse_amount * 2;
-    } else {
-        tally += motes_alpha.value() + motes_bravo.value() + motes_charlie.value();
-    }
+    tally += motes_alpha.value() + motes_bravo.value() + motes_charlie.value();
 
     assert_eq!(
         initial_balance, tally,
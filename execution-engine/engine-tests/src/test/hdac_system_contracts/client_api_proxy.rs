@@ -1,12 +1,17 @@
 use num_traits::identities::Zero;
 use std::convert::TryFrom;
 
+use contract::args_parser::ArgsParser;
 use engine_core::engine_state::{
     genesis::{GenesisAccount, POS_BONDING_PURSE},
-    SYSTEM_ACCOUNT_ADDR,
+    CONV_RATE, SYSTEM_ACCOUNT_ADDR,
 };
 use engine_shared::{motes::Motes, stored_value::StoredValue};
-use types::{account::PublicKey, bytesrepr::ToBytes, CLValue, Key, URef, U512};
+use types::{
+    account::PublicKey,
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLValue, Key, URef, U512,
+};
 
 use engine_test_support::{
     internal::{
@@ -19,6 +24,7 @@ use engine_test_support::{
 const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
 
 const TRANSFER_TO_ACCOUNT_METHOD: &str = "transfer_to_account";
+const TRANSFER_TO_ACCOUNT_WITH_ID_METHOD: &str = "transfer_to_account_with_id";
 const BOND_METHOD: &str = "bond";
 const UNBOND_METHOD: &str = "unbond";
 const DELEGATE_METHOD: &str = "delegate";
@@ -26,6 +32,7 @@ const UNDELEGATE_METHOD: &str = "undelegate";
 const REDELEGATE_METHOD: &str = "redelegate";
 const VOTE_METHOD: &str = "vote";
 const UNVOTE_METHOD: &str = "unvote";
+const BATCH_METHOD: &str = "batch";
 
 fn assert_bond_amount(
     pop_uref: &URef,
@@ -73,6 +80,13 @@ fn assert_vote_amount(
     assert_eq!(got, amount, "vote amount assertion failure for {:?}", voter);
 }
 
+fn batch_action(args: impl ArgsParser) -> Vec<u8> {
+    ArgsParser::parse(args)
+        .expect("args should convert to `Vec<CLValue>`")
+        .into_bytes()
+        .expect("args should serialize")
+}
+
 fn get_client_api_proxy_hash(builder: &InMemoryWasmTestBuilder) -> [u8; 32] {
     // query client_api_proxy_hash from SYSTEM_ACCOUNT
     let system_account = match builder
@@ -127,6 +141,90 @@ fn should_invoke_successful_transfer_to_account() {
     assert_eq!(balance, transferred_amount);
 }
 
+#[ignore]
+#[test]
+fn should_invoke_successful_transfer_to_account_with_id() {
+    const TRANSFER_ID: u64 = 42;
+
+    let transferred_amount = U512::from(1000);
+    let memo = Some(String::from("invoice #42"));
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG).commit();
+
+    let client_api_proxy_hash = get_client_api_proxy_hash(&builder);
+
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        DEFAULT_ACCOUNT_ADDR,
+        client_api_proxy_hash,
+        (
+            TRANSFER_TO_ACCOUNT_WITH_ID_METHOD,
+            ACCOUNT_1_ADDR,
+            transferred_amount,
+            TRANSFER_ID,
+            memo.clone(),
+        ),
+    )
+    .build();
+
+    builder.exec(exec_request).expect_success().commit();
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should get account 1");
+    assert_eq!(
+        builder.get_purse_balance(account_1.main_purse()),
+        transferred_amount
+    );
+
+    // the transfer record is kept in the sender's local storage under the sender and the transfer
+    // id
+    let key = {
+        let mut ret = Vec::with_capacity(DEFAULT_ACCOUNT_ADDR.as_bytes().len() + 8);
+        ret.extend(DEFAULT_ACCOUNT_ADDR.as_bytes());
+        ret.extend(&TRANSFER_ID.to_le_bytes());
+        Key::local(DEFAULT_ACCOUNT_ADDR.value(), &ret.to_bytes().unwrap())
+    };
+    let record: CLValue = builder
+        .query(None, key, &[])
+        .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
+        .expect("should have transfer record");
+    let bytes = record.inner_bytes();
+    let (source, bytes) = PublicKey::from_bytes(bytes).expect("should parse source");
+    let (target, bytes) = PublicKey::from_bytes(bytes).expect("should parse target");
+    let (amount, bytes) = U512::from_bytes(bytes).expect("should parse amount");
+    let (id, bytes) = u64::from_bytes(bytes).expect("should parse id");
+    let recorded_memo: Option<String> =
+        bytesrepr::deserialize(bytes.to_vec()).expect("should parse memo");
+    assert_eq!(source, DEFAULT_ACCOUNT_ADDR);
+    assert_eq!(target, ACCOUNT_1_ADDR);
+    assert_eq!(amount, transferred_amount);
+    assert_eq!(id, TRANSFER_ID);
+    assert_eq!(recorded_memo, memo);
+
+    // the same id can't be used twice by the same sender
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        DEFAULT_ACCOUNT_ADDR,
+        client_api_proxy_hash,
+        (
+            TRANSFER_TO_ACCOUNT_WITH_ID_METHOD,
+            ACCOUNT_1_ADDR,
+            transferred_amount,
+            TRANSFER_ID,
+            None::<String>,
+        ),
+    )
+    .build();
+
+    builder.exec(exec_request).commit();
+
+    assert!(builder.is_error(), "duplicate transfer id should fail");
+    assert_eq!(
+        builder.get_purse_balance(account_1.main_purse()),
+        transferred_amount
+    );
+}
+
 #[ignore]
 #[test]
 fn should_invoke_successful_standard_payment() {
@@ -170,7 +268,9 @@ fn should_invoke_successful_standard_payment() {
         "balance should be less than initial balance"
     );
 
-    let total_consumed = *DEFAULT_PAYMENT + U512::from(transferred_amount);
+    let spent_amount = Motes::from_gas(transfer_result.builder().exec_costs(0)[0], CONV_RATE)
+        .expect("should have motes");
+    let total_consumed = spent_amount.value() + U512::from(transferred_amount);
     let tally = total_consumed + modified_balance;
 
     assert_eq!(
@@ -508,3 +608,85 @@ fn should_invoke_successful_vote_and_unvote() {
         &builder,
     );
 }
+
+#[ignore]
+#[test]
+fn should_invoke_successful_batch() {
+    const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+    const BOND_AMOUNT: u64 = 1_000_000;
+
+    let account_1_amount = U512::from(1000);
+    let account_2_amount = U512::from(2000);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG).commit();
+
+    let client_api_proxy_hash = get_client_api_proxy_hash(&builder);
+
+    // transfer to two accounts and bond in a single deploy
+    let actions = vec![
+        batch_action((TRANSFER_TO_ACCOUNT_METHOD, ACCOUNT_1_ADDR, account_1_amount)),
+        batch_action((TRANSFER_TO_ACCOUNT_METHOD, ACCOUNT_2_ADDR, account_2_amount)),
+        batch_action((BOND_METHOD, U512::from(BOND_AMOUNT))),
+    ];
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        DEFAULT_ACCOUNT_ADDR,
+        client_api_proxy_hash,
+        (BATCH_METHOD, actions),
+    )
+    .build();
+
+    builder.exec(exec_request).expect_success().commit();
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should get account 1");
+    let account_2 = builder
+        .get_account(ACCOUNT_2_ADDR)
+        .expect("should get account 2");
+    assert_eq!(
+        builder.get_purse_balance(account_1.main_purse()),
+        account_1_amount
+    );
+    assert_eq!(
+        builder.get_purse_balance(account_2.main_purse()),
+        account_2_amount
+    );
+
+    let pop_uref = builder.get_pos_contract_uref();
+    assert_bond_amount(
+        &pop_uref,
+        &DEFAULT_ACCOUNT_ADDR,
+        BOND_AMOUNT.into(),
+        &builder,
+    );
+}
+
+#[ignore]
+#[test]
+fn should_revert_whole_batch_if_an_action_fails() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG).commit();
+
+    let client_api_proxy_hash = get_client_api_proxy_hash(&builder);
+
+    // the default account has nothing bonded, so the unbond fails after the transfer succeeded
+    let actions = vec![
+        batch_action((TRANSFER_TO_ACCOUNT_METHOD, ACCOUNT_1_ADDR, U512::from(1000))),
+        batch_action((UNBOND_METHOD, Some(U512::from(1)))),
+    ];
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        DEFAULT_ACCOUNT_ADDR,
+        client_api_proxy_hash,
+        (BATCH_METHOD, actions),
+    )
+    .build();
+
+    builder.exec(exec_request).commit();
+
+    assert!(builder.is_error(), "batch should fail");
+    assert!(
+        builder.get_account(ACCOUNT_1_ADDR).is_none(),
+        "transfer in a failed batch should be reverted"
+    );
+}
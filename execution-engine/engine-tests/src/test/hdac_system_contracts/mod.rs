@@ -8,3 +8,4 @@ mod standard_payment_install;
 mod system_contract_urefs_access_rights;
 mod system_contracts_access;
 mod upgrade;
+mod vesting;
@@ -1,6 +1,9 @@
 use std::convert::TryFrom;
 
-use engine_core::engine_state::genesis::{GenesisAccount, POS_BONDING_PURSE};
+use engine_core::engine_state::{
+    genesis::{GenesisAccount, POS_BONDING_PURSE},
+    CONV_RATE,
+};
 use engine_shared::motes::Motes;
 use engine_test_support::{
     internal::{
@@ -43,6 +46,13 @@ fn get_pos_bonding_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
     builder.get_purse_balance(purse_id)
 }
 
+fn get_spent_amount(builder: &InMemoryWasmTestBuilder, index: usize) -> U512 {
+    let gas = builder.exec_costs(index)[0];
+    Motes::from_gas(gas, CONV_RATE)
+        .expect("should have motes")
+        .value()
+}
+
 fn assert_bond_amount(
     pop_uref: &URef,
     address: &PublicKey,
@@ -111,6 +121,7 @@ fn should_run_successful_bond_and_unbond() {
         .expect_success()
         .commit()
         .finish();
+    let mut default_account_spent = get_spent_amount(&builder, 0);
 
     // #2 assert default_account's bond amount
     assert_bond_amount(
@@ -159,6 +170,7 @@ fn should_run_successful_bond_and_unbond() {
         .expect_success()
         .commit()
         .finish();
+    default_account_spent += get_spent_amount(&builder, 0);
 
     let account_1 = builder
         .get_account(ACCOUNT_1_ADDR)
@@ -203,7 +215,7 @@ fn should_run_successful_bond_and_unbond() {
     // #8 assert account1's balance after unbond request.
     assert_eq!(
         account_1_bal_after,
-        account_1_bal_before - *DEFAULT_PAYMENT + ACCOUNT_1_UNBOND_1,
+        account_1_bal_before - get_spent_amount(&builder, 0) + ACCOUNT_1_UNBOND_1,
     );
 
     // #9 assert bonding purse balance;
@@ -235,12 +247,13 @@ fn should_run_successful_bond_and_unbond() {
         .commit()
         .step(StepRequestBuilder::default().build())
         .finish();
+    default_account_spent += get_spent_amount(&builder, 0);
 
     // #12 assert default_account's balance after unbond
     assert_eq!(
         builder.get_purse_balance(default_account.main_purse()),
         U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE)
-            - *DEFAULT_PAYMENT * 3
+            - default_account_spent
             - account_1_seed_amount
             - GENESIS_ACCOUNT_UNBOND_2,
     );
@@ -280,7 +293,7 @@ fn should_run_successful_bond_and_unbond() {
     // #15 assert account1's balance
     assert_eq!(
         account_1_bal_after,
-        account_1_bal_before - *DEFAULT_PAYMENT + ACCOUNT_1_UNBOND_2,
+        account_1_bal_before - get_spent_amount(&builder, 0) + ACCOUNT_1_UNBOND_2,
     );
 
     // #16 assert bonding purse balance;
@@ -309,13 +322,14 @@ fn should_run_successful_bond_and_unbond() {
         .commit()
         .step(StepRequestBuilder::default().build())
         .finish();
+    default_account_spent += get_spent_amount(&builder, 0);
 
     // #19 assert default_account's balance after unbond all
     assert_eq!(
         result
             .builder()
             .get_purse_balance(default_account.main_purse()),
-        U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - *DEFAULT_PAYMENT * 4 - account_1_seed_amount
+        U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - default_account_spent - account_1_seed_amount
     );
 
     // #20 assert bonding purse balance;
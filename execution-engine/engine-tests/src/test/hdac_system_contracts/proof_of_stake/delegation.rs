@@ -98,6 +98,27 @@ fn should_run_successful_delegate_and_undelegate() {
         .commit()
         .finish();
 
+    // the delegation should be announced by an event
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    let events = response[0].events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].topic(), DELEGATE_METHOD);
+    let payload: (PublicKey, PublicKey, U512) = events[0]
+        .payload()
+        .clone()
+        .into_t()
+        .expect("should be a delegation");
+    assert_eq!(
+        payload,
+        (
+            ACCOUNT_2_ADDR,
+            ACCOUNT_1_ADDR,
+            U512::from(ACCOUNT_2_DELEGATE_AMOUNT)
+        )
+    );
+
     let pos_contract = builder
         .get_contract(pos_uref.remove_access_rights())
         .expect("should have contract");
@@ -1,10 +1,14 @@
-use engine_core::engine_state::genesis::{
-    POS_COMMUNITY_PURSE, POS_PAYMENT_PURSE, POS_REWARDS_PURSE,
+use std::convert::TryInto;
+
+use engine_core::engine_state::{
+    genesis::{POS_PAYMENT_PURSE, POS_REWARDS_PURSE},
+    CONV_RATE,
 };
+use engine_shared::{account::Account, motes::Motes};
 use engine_test_support::{
     internal::{
-        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
-        DEFAULT_PAYMENT,
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
@@ -13,6 +17,8 @@ use types::{account::PublicKey, Key, URef, U512};
 const CONTRACT_FINALIZE_PAYMENT: &str = "pos_finalize_payment.wasm";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
 const FINALIZE_PAYMENT: &str = "pos_finalize_payment.wasm";
+const LOCAL_REFUND_PURSE: &str = "local_refund_purse";
+const POS_REFUND_PURSE_NAME: &str = "pos_refund_purse";
 
 const SYSTEM_ADDR: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 const ACCOUNT_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
@@ -73,7 +79,7 @@ fn finalize_payment_should_not_be_run_by_non_system_accounts() {
 
 #[ignore]
 #[test]
-fn finalize_payment_should_reward_to_specified_purse() {
+fn finalize_payment_should_refund_to_specified_purse() {
     let mut builder = InMemoryWasmTestBuilder::default();
     let payment_amount = *DEFAULT_PAYMENT;
     let refund_purse_flag: u8 = 1;
@@ -86,8 +92,14 @@ fn finalize_payment_should_reward_to_specified_purse() {
 
     let payment_pre_balance = get_pos_payment_purse_balance(&builder);
     let rewards_pre_balance = get_pos_rewards_purse_balance(&builder);
-    let community_pre_balance = get_pos_community_purse_balance(&builder);
+    let refund_pre_balance =
+        get_named_account_balance(&builder, DEFAULT_ACCOUNT_ADDR, LOCAL_REFUND_PURSE)
+            .unwrap_or_else(U512::zero);
 
+    assert!(
+        get_pos_refund_purse(&builder).is_none(),
+        "refund_purse should start unset"
+    );
     assert!(
         payment_pre_balance.is_zero(),
         "payment purse should start with zero balance"
@@ -108,30 +120,84 @@ fn finalize_payment_should_reward_to_specified_purse() {
     };
     builder.exec(exec_request).expect_success().commit();
 
+    let spent_amount = get_spent_amount(&builder, 0);
+
     let payment_post_balance = get_pos_payment_purse_balance(&builder);
     let rewards_post_balance = get_pos_rewards_purse_balance(&builder);
-    let community_post_balance = get_pos_community_purse_balance(&builder);
+    let refund_post_balance =
+        get_named_account_balance(&builder, DEFAULT_ACCOUNT_ADDR, LOCAL_REFUND_PURSE)
+            .expect("should have refund balance");
 
-    let expected_reward_amount = rewards_pre_balance + *DEFAULT_PAYMENT;
+    let expected_reward_amount = rewards_pre_balance + spent_amount;
     assert_eq!(
         expected_reward_amount, rewards_post_balance,
         "validators should get reward paid; expected: {}, actual: {}",
         expected_reward_amount, rewards_post_balance
     );
 
-    let expected_community_amount = community_pre_balance + *DEFAULT_PAYMENT - rewards_post_balance;
+    // user gets refund
     assert_eq!(
-        expected_community_amount, community_post_balance,
-        "community purse should get paid; expected: {}, actual: {}",
-        expected_community_amount, community_post_balance
+        refund_pre_balance + payment_amount - spent_amount,
+        refund_post_balance,
+        "user should get refund"
     );
 
+    assert!(
+        get_pos_refund_purse(&builder).is_none(),
+        "refund_purse always ends unset"
+    );
     assert!(
         payment_post_balance.is_zero(),
         "payment purse should ends with zero balance"
     );
 }
 
+#[ignore]
+#[test]
+fn finalize_payment_should_refund_to_account_without_refund_purse() {
+    let mut builder = initialize();
+    let payment_amount = *DEFAULT_PAYMENT;
+    let refund_purse_flag: u8 = 0;
+    let args: (U512, u8, Option<U512>, Option<PublicKey>) =
+        (payment_amount, refund_purse_flag, None, None);
+
+    let account_pre_balance = get_account_balance(&builder, ACCOUNT_ADDR);
+    let rewards_pre_balance = get_pos_rewards_purse_balance(&builder);
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(ACCOUNT_ADDR)
+            .with_deploy_hash([2; 32])
+            .with_session_code("do_nothing.wasm", ())
+            .with_payment_code(FINALIZE_PAYMENT, args)
+            .with_authorization_keys(&[ACCOUNT_ADDR])
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+    builder.exec(exec_request).expect_success().commit();
+
+    let spent_amount = get_spent_amount(&builder, 2);
+
+    let account_post_balance = get_account_balance(&builder, ACCOUNT_ADDR);
+    let rewards_post_balance = get_pos_rewards_purse_balance(&builder);
+
+    assert!(
+        spent_amount < payment_amount,
+        "deploy should not spend the whole payment"
+    );
+    assert_eq!(
+        account_pre_balance - spent_amount,
+        account_post_balance,
+        "account should only pay for the amount spent"
+    );
+    assert_eq!(rewards_pre_balance + spent_amount, rewards_post_balance);
+    assert!(
+        get_pos_payment_purse_balance(&builder).is_zero(),
+        "payment purse should ends with zero balance"
+    );
+}
+
 // ------------- utility functions -------------------- //
 
 fn get_pos_payment_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
@@ -146,10 +212,12 @@ fn get_pos_rewards_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
     builder.get_purse_balance(purse_id)
 }
 
-fn get_pos_community_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
-    let purse_id = get_pos_purse_id_by_name(builder, POS_COMMUNITY_PURSE)
-        .expect("should find PoS community purse");
-    builder.get_purse_balance(purse_id)
+fn get_pos_refund_purse(builder: &InMemoryWasmTestBuilder) -> Option<Key> {
+    let pos_contract = builder.get_pos_contract();
+    pos_contract
+        .named_keys()
+        .get(POS_REFUND_PURSE_NAME)
+        .cloned()
 }
 
 fn get_pos_purse_id_by_name(builder: &InMemoryWasmTestBuilder, purse_name: &str) -> Option<URef> {
@@ -161,3 +229,42 @@ fn get_pos_purse_id_by_name(builder: &InMemoryWasmTestBuilder, purse_name: &str)
         .and_then(Key::as_uref)
         .cloned()
 }
+
+fn get_spent_amount(builder: &InMemoryWasmTestBuilder, index: usize) -> U512 {
+    let response = builder
+        .get_exec_response(index)
+        .expect("there should be a response");
+
+    let success_result = utils::get_success_result(response);
+    Motes::from_gas(success_result.cost(), CONV_RATE)
+        .expect("should have motes")
+        .value()
+}
+
+fn get_account_balance(builder: &InMemoryWasmTestBuilder, account_address: PublicKey) -> U512 {
+    let account = builder
+        .get_account(account_address)
+        .expect("should have account");
+    builder.get_purse_balance(account.main_purse())
+}
+
+fn get_named_account_balance(
+    builder: &InMemoryWasmTestBuilder,
+    account_address: PublicKey,
+    name: &str,
+) -> Option<U512> {
+    let account_key = Key::Account(account_address);
+
+    let account: Account = builder
+        .query(None, account_key, &[])
+        .and_then(|v| v.try_into().map_err(|error| format!("{:?}", error)))
+        .expect("should find balance uref");
+
+    let purse = account
+        .named_keys()
+        .get(name)
+        .and_then(Key::as_uref)
+        .cloned();
+
+    purse.map(|uref| builder.get_purse_balance(uref))
+}
@@ -6,4 +6,7 @@ mod delegation;
 mod economy;
 mod finalize_payment;
 mod get_payment_purse;
+mod pop_state;
+mod queries;
+mod step;
 mod votes;
@@ -0,0 +1,262 @@
+use std::convert::TryFrom;
+
+use grpc::RequestOptions;
+use num_traits::identities::Zero;
+
+use engine_core::engine_state::genesis::GenesisAccount;
+use engine_grpc_server::engine_server::{ipc, ipc_grpc::ExecutionEngineService, state::BigInt};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PROTOCOL_VERSION},
+    DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_POS_DELEGATION: &str = "pos_delegation.wasm";
+
+const BOND_METHOD: &str = "bond";
+const UNBOND_METHOD: &str = "unbond";
+const DELEGATE_METHOD: &str = "delegate";
+
+const VALIDATOR_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const DELEGATOR_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const GENESIS_VALIDATOR_STAKE: u64 = 50_000;
+const BOND_AMOUNT: u64 = 1_000_000;
+const DELEGATE_AMOUNT: u64 = 32_000;
+const UNBOND_AMOUNT: u64 = 40_000;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = vec![
+        GenesisAccount::new(
+            VALIDATOR_ADDR,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        ),
+        GenesisAccount::new(
+            DELEGATOR_ADDR,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::zero(),
+        ),
+    ];
+
+    let bond_request = ExecuteRequestBuilder::standard(
+        DELEGATOR_ADDR,
+        CONTRACT_POS_DELEGATION,
+        (String::from(BOND_METHOD), U512::from(BOND_AMOUNT)),
+    )
+    .build();
+    let delegate_request = ExecuteRequestBuilder::standard(
+        DELEGATOR_ADDR,
+        CONTRACT_POS_DELEGATION,
+        (
+            String::from(DELEGATE_METHOD),
+            VALIDATOR_ADDR,
+            U512::from(DELEGATE_AMOUNT),
+        ),
+    )
+    .build();
+    let unbond_request = ExecuteRequestBuilder::standard(
+        DELEGATOR_ADDR,
+        CONTRACT_POS_DELEGATION,
+        (String::from(UNBOND_METHOD), Some(U512::from(UNBOND_AMOUNT))),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&utils::create_genesis_config(accounts, Default::default()))
+        .exec(bond_request)
+        .expect_success()
+        .commit()
+        .exec(delegate_request)
+        .expect_success()
+        .commit()
+        .exec(unbond_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn pop_state_request(
+    state_hash: Vec<u8>,
+    maybe_public_key: Option<PublicKey>,
+) -> ipc::PopStateRequest {
+    let mut request = ipc::PopStateRequest::new();
+    request.set_state_hash(state_hash);
+    request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+    if let Some(public_key) = maybe_public_key {
+        request.set_public_key(public_key.as_bytes().to_vec());
+    }
+    request
+}
+
+fn to_public_key(bytes: &[u8]) -> PublicKey {
+    PublicKey::ed25519_try_from(bytes).expect("should be a public key")
+}
+
+fn to_u512(big_int: &BigInt) -> U512 {
+    U512::try_from(big_int.clone()).expect("should be a U512")
+}
+
+#[ignore]
+#[test]
+fn should_decode_delegations() {
+    let builder = setup();
+    let engine_state = builder.get_engine_state();
+
+    let mut response = engine_state
+        .pop_delegations(
+            RequestOptions::new(),
+            pop_state_request(builder.get_post_state_hash(), None),
+        )
+        .wait_drop_metadata()
+        .expect("should get response");
+    assert!(response.has_success(), "{:?}", response);
+    let mut delegations: Vec<(PublicKey, PublicKey, U512)> = response
+        .take_success()
+        .get_delegations()
+        .iter()
+        .map(|delegation| {
+            (
+                to_public_key(delegation.get_delegator()),
+                to_public_key(delegation.get_validator()),
+                to_u512(delegation.get_amount()),
+            )
+        })
+        .collect();
+    delegations.sort();
+    let mut expected = vec![
+        (
+            VALIDATOR_ADDR,
+            VALIDATOR_ADDR,
+            U512::from(GENESIS_VALIDATOR_STAKE),
+        ),
+        (DELEGATOR_ADDR, VALIDATOR_ADDR, U512::from(DELEGATE_AMOUNT)),
+    ];
+    expected.sort();
+    assert_eq!(delegations, expected);
+
+    // restricted to the delegator
+    let mut response = engine_state
+        .pop_delegations(
+            RequestOptions::new(),
+            pop_state_request(builder.get_post_state_hash(), Some(DELEGATOR_ADDR)),
+        )
+        .wait_drop_metadata()
+        .expect("should get response");
+    assert!(response.has_success(), "{:?}", response);
+    let delegations = response.take_success();
+    assert_eq!(delegations.get_delegations().len(), 1);
+    assert_eq!(
+        to_public_key(delegations.get_delegations()[0].get_delegator()),
+        DELEGATOR_ADDR
+    );
+}
+
+#[ignore]
+#[test]
+fn should_decode_validators_and_rewards() {
+    let builder = setup();
+    let engine_state = builder.get_engine_state();
+
+    let mut response = engine_state
+        .pop_validators(
+            RequestOptions::new(),
+            pop_state_request(builder.get_post_state_hash(), None),
+        )
+        .wait_drop_metadata()
+        .expect("should get response");
+    assert!(response.has_success(), "{:?}", response);
+    let validators = response.take_success();
+    assert_eq!(validators.get_validators().len(), 1);
+    let validator = &validators.get_validators()[0];
+    assert_eq!(to_public_key(validator.get_public_key()), VALIDATOR_ADDR);
+    assert_eq!(
+        to_u512(validator.get_bonded_amount()),
+        U512::from(GENESIS_VALIDATOR_STAKE)
+    );
+    assert_eq!(
+        to_u512(validator.get_delegated_amount()),
+        U512::from(GENESIS_VALIDATOR_STAKE + DELEGATE_AMOUNT)
+    );
+    assert_eq!(to_u512(validator.get_commission()), U512::zero());
+
+    let mut response = engine_state
+        .pop_rewards(
+            RequestOptions::new(),
+            pop_state_request(builder.get_post_state_hash(), Some(DELEGATOR_ADDR)),
+        )
+        .wait_drop_metadata()
+        .expect("should get response");
+    assert!(response.has_success(), "{:?}", response);
+    let rewards = response.take_success();
+    assert_eq!(rewards.get_rewards().len(), 1);
+    assert_eq!(
+        to_public_key(rewards.get_rewards()[0].get_public_key()),
+        DELEGATOR_ADDR
+    );
+    assert_eq!(to_u512(rewards.get_rewards()[0].get_reward()), U512::zero());
+}
+
+#[ignore]
+#[test]
+fn should_decode_pending_requests() {
+    let builder = setup();
+    let engine_state = builder.get_engine_state();
+
+    let mut response = engine_state
+        .pop_pending_requests(
+            RequestOptions::new(),
+            pop_state_request(builder.get_post_state_hash(), Some(DELEGATOR_ADDR)),
+        )
+        .wait_drop_metadata()
+        .expect("should get response");
+    assert!(response.has_success(), "{:?}", response);
+    let pending_requests = response.take_success();
+    assert_eq!(pending_requests.get_unbonds().len(), 1);
+    let unbond = &pending_requests.get_unbonds()[0];
+    assert_eq!(to_public_key(unbond.get_requester()), DELEGATOR_ADDR);
+    assert_eq!(to_u512(unbond.get_amount()), U512::from(UNBOND_AMOUNT));
+    assert!(pending_requests.get_undelegations().is_empty());
+    assert!(pending_requests.get_redelegations().is_empty());
+
+    // the validator has no pending requests
+    let mut response = engine_state
+        .pop_pending_requests(
+            RequestOptions::new(),
+            pop_state_request(builder.get_post_state_hash(), Some(VALIDATOR_ADDR)),
+        )
+        .wait_drop_metadata()
+        .expect("should get response");
+    assert!(response.has_success(), "{:?}", response);
+    assert!(response.take_success().get_unbonds().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_report_missing_parent_and_invalid_request() {
+    let builder = setup();
+    let engine_state = builder.get_engine_state();
+
+    let missing_state_hash = vec![1u8; 32];
+    let response = engine_state
+        .pop_delegations(
+            RequestOptions::new(),
+            pop_state_request(missing_state_hash.clone(), None),
+        )
+        .wait_drop_metadata()
+        .expect("should get response");
+    assert!(response.has_missing_parent(), "{:?}", response);
+    assert_eq!(
+        response.get_missing_parent().get_hash(),
+        &missing_state_hash[..]
+    );
+
+    let mut request = pop_state_request(builder.get_post_state_hash(), None);
+    request.set_public_key(vec![1u8; 3]);
+    let response = engine_state
+        .pop_validators(RequestOptions::new(), request)
+        .wait_drop_metadata()
+        .expect("should get response");
+    assert!(response.has_error(), "{:?}", response);
+}
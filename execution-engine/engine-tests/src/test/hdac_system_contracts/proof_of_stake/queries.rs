@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+
+use engine_core::engine_state::genesis::GenesisAccount;
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+const GENESIS_VALIDATOR: PublicKey = PublicKey::ed25519_from([42; 32]);
+const GENESIS_VALIDATOR_STAKE: u64 = 50_000;
+const BOND_AMOUNT: u64 = 100_000;
+const UNBOND_AMOUNT: u64 = 40_000;
+
+const TEST_BOND: &str = "bond";
+const TEST_UNBOND: &str = "unbond";
+
+const METHOD_GET_DELEGATIONS: &str = "get_delegations";
+const METHOD_GET_VALIDATOR: &str = "get_validator";
+const METHOD_GET_PENDING_REQUESTS: &str = "get_pending_requests";
+const METHOD_GET_REWARD: &str = "get_reward";
+
+type PendingRequests = (
+    Vec<(Option<U512>, u64)>,
+    Vec<(PublicKey, Option<U512>, u64)>,
+    Vec<((PublicKey, PublicKey), Option<U512>, u64)>,
+);
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account = GenesisAccount::new(
+            GENESIS_VALIDATOR,
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()) * Motes::new(2.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        );
+        tmp.push(account);
+        tmp
+    };
+    let genesis_config = utils::create_genesis_config(accounts, Default::default());
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_query_genesis_validator() {
+    let builder = setup();
+
+    let (bonded, delegated, commission): (U512, U512, U512) = builder
+        .query_pop((METHOD_GET_VALIDATOR, GENESIS_VALIDATOR))
+        .expect("should query validator");
+    assert_eq!(bonded, GENESIS_VALIDATOR_STAKE.into());
+    assert_eq!(delegated, GENESIS_VALIDATOR_STAKE.into());
+    assert_eq!(commission, U512::zero());
+
+    let delegations: BTreeMap<PublicKey, U512> = builder
+        .query_pop((METHOD_GET_DELEGATIONS, GENESIS_VALIDATOR))
+        .expect("should query delegations");
+    let expected: BTreeMap<PublicKey, U512> =
+        vec![(GENESIS_VALIDATOR, U512::from(GENESIS_VALIDATOR_STAKE))]
+            .into_iter()
+            .collect();
+    assert_eq!(delegations, expected);
+
+    let reward: U512 = builder
+        .query_pop((METHOD_GET_REWARD, DEFAULT_ACCOUNT_ADDR))
+        .expect("should query reward");
+    assert_eq!(reward, U512::zero());
+}
+
+#[ignore]
+#[test]
+fn should_query_pending_requests_without_committing() {
+    let mut builder = setup();
+
+    let bond_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_BOND), U512::from(BOND_AMOUNT)),
+    )
+    .build();
+    let unbond_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_UNBOND), Some(U512::from(UNBOND_AMOUNT))),
+    )
+    .build();
+    builder
+        .exec(bond_request)
+        .expect_success()
+        .commit()
+        .exec(unbond_request)
+        .expect_success()
+        .commit();
+
+    let post_state_hash = builder.get_post_state_hash();
+
+    let (unbonds, undelegations, redelegations): PendingRequests = builder
+        .query_pop((METHOD_GET_PENDING_REQUESTS, DEFAULT_ACCOUNT_ADDR))
+        .expect("should query pending requests");
+    assert_eq!(unbonds.len(), 1);
+    assert_eq!(unbonds[0].0, Some(U512::from(UNBOND_AMOUNT)));
+    assert!(undelegations.is_empty());
+    assert!(redelegations.is_empty());
+
+    let (validator_pending, _, _): PendingRequests = builder
+        .query_pop((METHOD_GET_PENDING_REQUESTS, GENESIS_VALIDATOR))
+        .expect("should query pending requests");
+    assert!(validator_pending.is_empty());
+
+    // querying doesn't move the state forward
+    assert_eq!(builder.get_post_state_hash(), post_state_hash);
+}
+
+#[ignore]
+#[test]
+fn should_not_run_mutating_method_as_query() {
+    let builder = setup();
+
+    let error = builder
+        .query_pop::<()>((TEST_BOND, U512::from(BOND_AMOUNT)))
+        .expect_err("bond should not run as a query");
+    assert_eq!(error, "invalid query method");
+
+    let error = builder
+        .query_pop::<()>(("step",))
+        .expect_err("step should not run as a query");
+    assert_eq!(error, "invalid query method");
+}
@@ -0,0 +1,34 @@
+use std::convert::TryFrom;
+
+use engine_test_support::internal::{
+    InMemoryWasmTestBuilder, StepRequestBuilder, DEFAULT_GENESIS_CONFIG,
+};
+use types::{account::PublicKey, CLValue, Key};
+
+const BLOCK_HEIGHT: u64 = 42;
+const PROPOSER: PublicKey = PublicKey::ed25519_from([7u8; 32]);
+// Local key of the proof of profession contract under which the height is stored.
+const LAST_DISTRIBUTED_BLOCK_HEIGHT_KEY: u8 = 4;
+
+#[ignore]
+#[test]
+fn should_record_block_height_of_last_distribution() {
+    let step_request = StepRequestBuilder::default()
+        .with_block_height(BLOCK_HEIGHT)
+        .with_proposer(PROPOSER)
+        .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .step(step_request);
+
+    let pop_uref = builder.get_pos_contract_uref();
+    let key = Key::local(pop_uref.addr(), &[LAST_DISTRIBUTED_BLOCK_HEIGHT_KEY]);
+    let got: CLValue = builder
+        .query(None, key, &[])
+        .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
+        .expect("should have local value.");
+    let got: u64 = got.into_t().unwrap();
+    assert_eq!(got, BLOCK_HEIGHT);
+}
@@ -29,6 +29,12 @@ fn get_upgraded_wasm_costs() -> WasmCosts {
         max_stack_height: 64 * 1024,
         opcodes_mul: 3,
         opcodes_div: 8,
+        storage_per_byte: 1,
+        max_module_size: 8 * 1024 * 1024,
+        max_functions: 10_000,
+        max_globals: 256,
+        max_table_size: 10_000,
+        max_memory_pages: 64,
     }
 }
 
@@ -0,0 +1,195 @@
+use engine_core::engine_state::{
+    genesis::{GenesisAccount, GenesisConfig, VestingSchedule},
+    EngineConfig,
+};
+use engine_shared::motes::Motes;
+use engine_storage::global_state::in_memory::InMemoryGlobalState;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, WasmTestResult, DEFAULT_ACCOUNTS,
+    },
+    DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const TEST_BOND_FROM_MAIN_PURSE: &str = "bond-from-main-purse";
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+
+const CLIFF_TIMESTAMP: u64 = 1_000;
+const END_TIMESTAMP: u64 = 2_000;
+
+// Nine tenths of the genesis balance of account 1 is locked, the rest pays for the deploys.
+fn locked_amount() -> U512 {
+    U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) / 10 * 9
+}
+
+fn liquid_amount() -> U512 {
+    U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - locked_amount()
+}
+
+fn genesis_config_with_vesting_account(locked_amount: U512) -> GenesisConfig {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let vesting_schedule =
+            VestingSchedule::new(Motes::new(locked_amount), CLIFF_TIMESTAMP, END_TIMESTAMP);
+        let account = GenesisAccount::new(
+            ACCOUNT_1_ADDR,
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            Motes::zero(),
+        )
+        .with_vesting_schedule(vesting_schedule);
+        tmp.push(account);
+        tmp
+    };
+
+    utils::create_genesis_config(accounts, Default::default())
+}
+
+fn run_genesis_with_vesting_account() -> WasmTestResult<InMemoryGlobalState> {
+    let genesis_config = genesis_config_with_vesting_account(locked_amount());
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&genesis_config)
+        .finish()
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "locked amount exceeds balance")]
+fn should_fail_genesis_if_locked_amount_exceeds_balance() {
+    let genesis_config =
+        genesis_config_with_vesting_account(U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) + 1);
+
+    InMemoryWasmTestBuilder::default().run_genesis(&genesis_config);
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "vesting requires the mint system contract")]
+fn should_fail_genesis_with_vesting_account_without_system_contracts() {
+    let genesis_config = genesis_config_with_vesting_account(locked_amount());
+
+    let engine_config = EngineConfig::new().with_use_system_contracts(false);
+    let global_state = InMemoryGlobalState::empty().expect("should create global state");
+    let empty_root_hash = global_state.empty_root_hash.to_vec();
+    InMemoryWasmTestBuilder::new(global_state, engine_config, empty_root_hash)
+        .run_genesis(&genesis_config);
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_locked_funds_before_cliff() {
+    let result = run_genesis_with_vesting_account();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (ACCOUNT_2_ADDR, liquid_amount()),
+    )
+    .with_block_time(CLIFF_TIMESTAMP - 1)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder.exec(exec_request).commit();
+
+    assert!(
+        builder.is_error(),
+        "locked funds should not be transferable"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_transfer_released_funds_between_cliff_and_end() {
+    let result = run_genesis_with_vesting_account();
+
+    // half of the locked amount is released in the middle of the linear release period
+    let transfer_amount = liquid_amount() + locked_amount() / 4;
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (ACCOUNT_2_ADDR, transfer_amount),
+    )
+    .with_block_time((CLIFF_TIMESTAMP + END_TIMESTAMP) / 2)
+    .build();
+
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (ACCOUNT_2_ADDR, locked_amount() / 2),
+    )
+    .with_block_time((CLIFF_TIMESTAMP + END_TIMESTAMP) / 2)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder.exec(exec_request_1).expect_success().commit();
+
+    let account_2 = builder
+        .get_account(ACCOUNT_2_ADDR)
+        .expect("should get account 2");
+    assert_eq!(
+        builder.get_purse_balance(account_2.main_purse()),
+        transfer_amount
+    );
+
+    builder.exec(exec_request_2).commit();
+    assert!(
+        builder.is_error(),
+        "still locked funds should not be transferable"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_transfer_all_funds_after_end() {
+    let result = run_genesis_with_vesting_account();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (ACCOUNT_2_ADDR, locked_amount()),
+    )
+    .with_block_time(END_TIMESTAMP)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder.exec(exec_request).expect_success().commit();
+
+    let account_2 = builder
+        .get_account(ACCOUNT_2_ADDR)
+        .expect("should get account 2");
+    assert_eq!(
+        builder.get_purse_balance(account_2.main_purse()),
+        locked_amount()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_bond_locked_funds() {
+    let result = run_genesis_with_vesting_account();
+
+    let bond_amount = liquid_amount() * 2;
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_BOND_FROM_MAIN_PURSE), bond_amount),
+    )
+    .with_block_time(CLIFF_TIMESTAMP - 1)
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::from_result(result);
+    builder.exec(exec_request).expect_success().commit();
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should get account 1");
+    assert!(
+        builder.get_purse_balance(account_1.main_purse())
+            < U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - bond_amount
+    );
+}
@@ -1,5 +1,9 @@
-use engine_core::engine_state::{upgrade::ActivationPoint, Error};
+use engine_core::engine_state::{upgrade::ActivationPoint, Error, DEFAULT_MIN_GAS_PRICE};
 use engine_grpc_server::engine_server::ipc::DeployCode;
+use engine_shared::{
+    gas_limits::GasLimits,
+    host_function_costs::{HostFunctionCost, HostFunctionCosts},
+};
 #[cfg(feature = "use-system-contracts")]
 use engine_shared::{stored_value::StoredValue, transform::Transform};
 use engine_test_support::internal::{
@@ -33,6 +37,12 @@ fn get_upgraded_wasm_costs() -> WasmCosts {
         max_stack_height: 64 * 1024,
         opcodes_mul: 3,
         opcodes_div: 8,
+        storage_per_byte: 1,
+        max_module_size: 8 * 1024 * 1024,
+        max_functions: 10_000,
+        max_globals: 256,
+        max_table_size: 10_000,
+        max_memory_pages: 64,
     }
 }
 
@@ -351,6 +361,158 @@ fn should_allow_only_wasm_costs_patch_version() {
     );
 }
 
+#[ignore]
+#[test]
+fn should_upgrade_only_min_gas_price() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&*DEFAULT_CASPER_GENESIS_CONFIG);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let new_min_gas_price = DEFAULT_MIN_GAS_PRICE + 1;
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_new_min_gas_price(new_min_gas_price)
+            .build()
+    };
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+
+    assert!(upgrade_response.has_success(), "expected success");
+
+    let upgraded_protocol_data = builder
+        .get_engine_state()
+        .get_protocol_data(new_protocol_version)
+        .expect("should have result")
+        .expect("should have upgraded protocol data");
+
+    assert_eq!(
+        upgraded_protocol_data.min_gas_price(),
+        new_min_gas_price,
+        "upgraded min gas price should equal new min gas price"
+    );
+    assert_eq!(
+        *upgraded_protocol_data.wasm_costs(),
+        *DEFAULT_WASM_COSTS,
+        "wasm costs should not change"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_upgrade_only_gas_limits() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&*DEFAULT_CASPER_GENESIS_CONFIG);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let new_gas_limits = GasLimits {
+        max_payment_cost: 1_000_000,
+        max_session_gas: 2_000_000,
+        max_block_gas: 3_000_000,
+    };
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_new_gas_limits(new_gas_limits)
+            .build()
+    };
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+
+    assert!(upgrade_response.has_success(), "expected success");
+
+    let upgraded_protocol_data = builder
+        .get_engine_state()
+        .get_protocol_data(new_protocol_version)
+        .expect("should have result")
+        .expect("should have upgraded protocol data");
+
+    assert_eq!(
+        *upgraded_protocol_data.gas_limits(),
+        new_gas_limits,
+        "upgraded gas limits should equal new gas limits"
+    );
+    assert_eq!(
+        upgraded_protocol_data.min_gas_price(),
+        DEFAULT_MIN_GAS_PRICE,
+        "min gas price should not change"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_upgrade_only_host_function_costs() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&*DEFAULT_CASPER_GENESIS_CONFIG);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let new_host_function_costs = HostFunctionCosts {
+        write: HostFunctionCost::new(1_000, 10),
+        create_purse: HostFunctionCost::new(2_000, 0),
+        ..Default::default()
+    };
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_new_host_function_costs(new_host_function_costs)
+            .build()
+    };
+
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+
+    assert!(upgrade_response.has_success(), "expected success");
+
+    let upgraded_protocol_data = builder
+        .get_engine_state()
+        .get_protocol_data(new_protocol_version)
+        .expect("should have result")
+        .expect("should have upgraded protocol data");
+
+    assert_eq!(
+        *upgraded_protocol_data.host_function_costs(),
+        new_host_function_costs,
+        "upgraded host function costs should equal new host function costs"
+    );
+    assert_eq!(
+        *upgraded_protocol_data.wasm_costs(),
+        *DEFAULT_WASM_COSTS,
+        "wasm costs should not change"
+    );
+}
+
 #[ignore]
 #[test]
 fn should_allow_only_wasm_costs_minor_version() {
@@ -0,0 +1,145 @@
+use parity_wasm::{
+    builder,
+    elements::{ImportCountType, Instruction, Internal, Module, Section, ValueType},
+};
+
+/// The host function `grow_memory` instructions are replaced with.
+pub const GROW_MEMORY_FUNCTION: &str = "grow_memory";
+
+/// Replaces every `grow_memory` instruction with a call to the host function of the same name, so
+/// that growing past the memory limit fails with an error rather than leaving the contract to trap
+/// on a failed allocation.
+pub(crate) fn inject_grow_memory_host_function(module: Module) -> Module {
+    if !has_grow_memory(&module) {
+        return module;
+    }
+
+    let mut module_builder = builder::from_module(module);
+    let signature = module_builder.push_signature(
+        builder::signature()
+            .with_param(ValueType::I32)
+            .with_return_type(Some(ValueType::I32))
+            .build_sig(),
+    );
+    module_builder.push_import(
+        builder::import()
+            .module("env")
+            .field(GROW_MEMORY_FUNCTION)
+            .external()
+            .func(signature)
+            .build(),
+    );
+    let mut module = module_builder.build();
+
+    // Imported functions come first in the function index space, so the new import shifts the
+    // indices of all the functions defined by the module.
+    let grow_memory_function = module.import_count(ImportCountType::Function) as u32 - 1;
+    let shift = |function_index: &mut u32| {
+        if *function_index >= grow_memory_function {
+            *function_index += 1;
+        }
+    };
+
+    for section in module.sections_mut() {
+        match section {
+            Section::Code(code_section) => {
+                for body in code_section.bodies_mut() {
+                    for instruction in body.code_mut().elements_mut() {
+                        match instruction {
+                            Instruction::Call(function_index) => shift(function_index),
+                            Instruction::GrowMemory(_) => {
+                                *instruction = Instruction::Call(grow_memory_function)
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            Section::Export(export_section) => {
+                for export in export_section.entries_mut() {
+                    if let Internal::Function(function_index) = export.internal_mut() {
+                        shift(function_index);
+                    }
+                }
+            }
+            Section::Element(element_section) => {
+                for segment in element_section.entries_mut() {
+                    segment.members_mut().iter_mut().for_each(shift);
+                }
+            }
+            Section::Start(function_index) => shift(function_index),
+            _ => {}
+        }
+    }
+
+    module
+}
+
+fn has_grow_memory(module: &Module) -> bool {
+    module.code_section().map_or(false, |code_section| {
+        code_section.bodies().iter().any(|body| {
+            body.code()
+                .elements()
+                .iter()
+                .any(|instruction| match instruction {
+                    Instruction::GrowMemory(_) => true,
+                    _ => false,
+                })
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use parity_wasm::{
+        builder,
+        elements::{ImportCountType, Instruction, Instructions, Internal, Module},
+    };
+
+    use super::{inject_grow_memory_host_function, GROW_MEMORY_FUNCTION};
+
+    fn module(instructions: Vec<Instruction>) -> Module {
+        builder::module()
+            .function()
+            .signature()
+            .build()
+            .body()
+            .with_instructions(Instructions::new(instructions))
+            .build()
+            .build()
+            .export()
+            .field("call")
+            .internal()
+            .func(0)
+            .build()
+            .build()
+    }
+
+    #[test]
+    fn should_leave_module_without_grow_memory_unchanged() {
+        let module = module(vec![Instruction::End]);
+        assert_eq!(inject_grow_memory_host_function(module.clone()), module);
+    }
+
+    #[test]
+    fn should_replace_grow_memory_with_host_function_call() {
+        let module = inject_grow_memory_host_function(module(vec![
+            Instruction::I32Const(1),
+            Instruction::GrowMemory(0),
+            Instruction::Drop,
+            Instruction::Call(0),
+            Instruction::End,
+        ]));
+
+        assert_eq!(module.import_count(ImportCountType::Function), 1);
+        let import = &module.import_section().unwrap().entries()[0];
+        assert_eq!(import.field(), GROW_MEMORY_FUNCTION);
+
+        // The defined function moved from index 0 to 1.
+        let code = module.code_section().unwrap().bodies()[0].code().elements();
+        assert_eq!(code[1], Instruction::Call(0));
+        assert_eq!(code[3], Instruction::Call(1));
+        let export = &module.export_section().unwrap().entries()[0];
+        assert_eq!(*export.internal(), Internal::Function(1));
+    }
+}
@@ -1,20 +1,48 @@
+mod grow_memory;
+mod validation;
 pub mod wasm_costs;
 
-use std::fmt::{self, Display, Formatter};
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display, Formatter},
+};
 
 use parity_wasm::elements::{self, Module};
 use pwasm_utils::{self, stack_height};
 
+pub use crate::grow_memory::GROW_MEMORY_FUNCTION;
 use crate::wasm_costs::WasmCosts;
 
-//NOTE: size of Wasm memory page is 64 KiB
-pub const MEM_PAGES: u32 = 64;
-
 #[derive(Debug)]
 pub enum PreprocessingError {
     Deserialize(String),
     OperationForbiddenByGasRules,
     StackLimiter,
+    ModuleTooLarge {
+        size: usize,
+        max_size: u32,
+    },
+    UnknownImport {
+        module: String,
+        field: String,
+    },
+    TooManyFunctions {
+        count: usize,
+        max_functions: u32,
+    },
+    TooManyGlobals {
+        count: usize,
+        max_globals: u32,
+    },
+    TableTooLarge {
+        size: u32,
+        max_table_size: u32,
+    },
+    MemoryTooLarge {
+        initial_pages: u32,
+        max_memory_pages: u32,
+    },
+    FloatingPoint,
 }
 
 impl From<elements::Error> for PreprocessingError {
@@ -29,27 +57,50 @@ impl Display for PreprocessingError {
             PreprocessingError::Deserialize(error) => write!(f, "Deserialization error: {}", error),
             PreprocessingError::OperationForbiddenByGasRules => write!(f, "Encountered operation forbidden by gas rules. Consult instruction -> metering config map"),
             PreprocessingError::StackLimiter => write!(f, "Stack limiter error"),
+            PreprocessingError::ModuleTooLarge { size, max_size } => write!(f, "Module too large: maximum {} bytes, actual {}", max_size, size),
+            PreprocessingError::UnknownImport { module, field } => write!(f, "Unknown import: {}.{}", module, field),
+            PreprocessingError::TooManyFunctions { count, max_functions } => write!(f, "Too many functions: maximum {}, actual {}", max_functions, count),
+            PreprocessingError::TooManyGlobals { count, max_globals } => write!(f, "Too many globals: maximum {}, actual {}", max_globals, count),
+            PreprocessingError::TableTooLarge { size, max_table_size } => write!(f, "Table too large: maximum {} elements, actual {}", max_table_size, size),
+            PreprocessingError::MemoryTooLarge { initial_pages, max_memory_pages } => write!(f, "Memory too large: maximum {} pages, actual {}", max_memory_pages, initial_pages),
+            PreprocessingError::FloatingPoint => write!(f, "Floating point types and operations are not allowed"),
         }
     }
 }
 
 pub struct Preprocessor {
     wasm_costs: WasmCosts,
-    // Number of memory pages.
-    mem_pages: u32,
+    // Names under which functions can be imported, any if not set.
+    host_functions: Option<BTreeSet<String>>,
 }
 
 impl Preprocessor {
     pub fn new(wasm_costs: WasmCosts) -> Self {
         Self {
             wasm_costs,
-            mem_pages: MEM_PAGES,
+            host_functions: None,
         }
     }
 
+    /// Only accepts modules whose function imports are among `host_functions`.
+    pub fn with_host_functions<'a, I: IntoIterator<Item = &'a str>>(
+        mut self,
+        host_functions: I,
+    ) -> Self {
+        self.host_functions = Some(host_functions.into_iter().map(String::from).collect());
+        self
+    }
+
     pub fn preprocess(&self, module_bytes: &[u8]) -> Result<Module, PreprocessingError> {
         let module = deserialize(module_bytes)?;
-        let module = pwasm_utils::externalize_mem(module, None, self.mem_pages);
+        validation::validate(
+            &module,
+            module_bytes.len(),
+            &self.wasm_costs,
+            self.host_functions.as_ref(),
+        )?;
+        let module = pwasm_utils::externalize_mem(module, None, self.wasm_costs.max_memory_pages);
+        let module = grow_memory::inject_grow_memory_host_function(module);
         let module = pwasm_utils::inject_gas_counter(module, &self.wasm_costs.to_set())
             .map_err(|_| PreprocessingError::OperationForbiddenByGasRules)?;
         let module = stack_height::inject_limiter(module, self.wasm_costs.max_stack_height)
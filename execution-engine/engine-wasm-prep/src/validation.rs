@@ -0,0 +1,367 @@
+use std::collections::BTreeSet;
+
+use parity_wasm::elements::{BlockType, External, Instruction, Module, Type, ValueType};
+
+use crate::{wasm_costs::WasmCosts, PreprocessingError};
+
+/// The module host functions and memory are imported from.
+const HOST_MODULE: &str = "env";
+
+/// The name under which the memory is imported.
+const MEMORY_FIELD: &str = "memory";
+
+/// Checks a deserialized module against the limits in `wasm_costs`, where a limit of zero means
+/// unlimited. If `host_functions` is given, functions can only be imported under these names.
+pub(crate) fn validate(
+    module: &Module,
+    module_size: usize,
+    wasm_costs: &WasmCosts,
+    host_functions: Option<&BTreeSet<String>>,
+) -> Result<(), PreprocessingError> {
+    validate_module_size(module_size, wasm_costs.max_module_size)?;
+    validate_imports(module, host_functions)?;
+    validate_function_count(module, wasm_costs.max_functions)?;
+    validate_global_count(module, wasm_costs.max_globals)?;
+    validate_table_size(module, wasm_costs.max_table_size)?;
+    validate_memory_size(module, wasm_costs.max_memory_pages)?;
+    validate_no_floats(module)
+}
+
+fn exceeds(value: usize, limit: u32) -> bool {
+    limit != 0 && value > limit as usize
+}
+
+fn validate_module_size(size: usize, max_size: u32) -> Result<(), PreprocessingError> {
+    if exceeds(size, max_size) {
+        return Err(PreprocessingError::ModuleTooLarge { size, max_size });
+    }
+    Ok(())
+}
+
+fn validate_imports(
+    module: &Module,
+    host_functions: Option<&BTreeSet<String>>,
+) -> Result<(), PreprocessingError> {
+    let entries = match module.import_section() {
+        Some(import_section) => import_section.entries(),
+        None => return Ok(()),
+    };
+    for entry in entries {
+        let is_known = entry.module() == HOST_MODULE
+            && match entry.external() {
+                External::Function(_) => host_functions
+                    .map(|names| names.contains(entry.field()))
+                    .unwrap_or(true),
+                External::Memory(_) => entry.field() == MEMORY_FIELD,
+                External::Table(_) | External::Global(_) => false,
+            };
+        if !is_known {
+            return Err(PreprocessingError::UnknownImport {
+                module: entry.module().to_string(),
+                field: entry.field().to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn validate_function_count(module: &Module, max_functions: u32) -> Result<(), PreprocessingError> {
+    let count = module
+        .function_section()
+        .map(|function_section| function_section.entries().len())
+        .unwrap_or_default();
+    if exceeds(count, max_functions) {
+        return Err(PreprocessingError::TooManyFunctions {
+            count,
+            max_functions,
+        });
+    }
+    Ok(())
+}
+
+fn validate_global_count(module: &Module, max_globals: u32) -> Result<(), PreprocessingError> {
+    let count = module
+        .global_section()
+        .map(|global_section| global_section.entries().len())
+        .unwrap_or_default();
+    if exceeds(count, max_globals) {
+        return Err(PreprocessingError::TooManyGlobals { count, max_globals });
+    }
+    Ok(())
+}
+
+fn validate_table_size(module: &Module, max_table_size: u32) -> Result<(), PreprocessingError> {
+    let entries = match module.table_section() {
+        Some(table_section) => table_section.entries(),
+        None => return Ok(()),
+    };
+    for entry in entries {
+        let limits = entry.limits();
+        let size = limits.maximum().unwrap_or_else(|| limits.initial());
+        if exceeds(size as usize, max_table_size) {
+            return Err(PreprocessingError::TableTooLarge {
+                size,
+                max_table_size,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Unlike the other limits, the memory limit always applies as there is no unlimited memory.
+fn validate_memory_size(module: &Module, max_memory_pages: u32) -> Result<(), PreprocessingError> {
+    let defined = module
+        .memory_section()
+        .map(|memory_section| memory_section.entries())
+        .unwrap_or_default();
+    let imported = module
+        .import_section()
+        .map(|import_section| import_section.entries())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|entry| match entry.external() {
+            External::Memory(memory_type) => Some(memory_type),
+            _ => None,
+        });
+    for memory_type in defined.iter().chain(imported) {
+        let initial_pages = memory_type.limits().initial();
+        if initial_pages > max_memory_pages {
+            return Err(PreprocessingError::MemoryTooLarge {
+                initial_pages,
+                max_memory_pages,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Floating point results can differ between platforms, so neither float types nor float
+/// instructions are allowed anywhere in the module.
+fn validate_no_floats(module: &Module) -> Result<(), PreprocessingError> {
+    if let Some(type_section) = module.type_section() {
+        for Type::Function(function_type) in type_section.types() {
+            if function_type.params().iter().any(is_float)
+                || function_type.return_type().as_ref().map_or(false, is_float)
+            {
+                return Err(PreprocessingError::FloatingPoint);
+            }
+        }
+    }
+    if let Some(global_section) = module.global_section() {
+        for entry in global_section.entries() {
+            if is_float(&entry.global_type().content_type()) {
+                return Err(PreprocessingError::FloatingPoint);
+            }
+        }
+    }
+    if let Some(code_section) = module.code_section() {
+        for body in code_section.bodies() {
+            if body
+                .locals()
+                .iter()
+                .any(|local| is_float(&local.value_type()))
+                || body.code().elements().iter().any(is_float_instruction)
+            {
+                return Err(PreprocessingError::FloatingPoint);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_float(value_type: &ValueType) -> bool {
+    match value_type {
+        ValueType::F32 | ValueType::F64 => true,
+        _ => false,
+    }
+}
+
+fn is_float_instruction(instruction: &Instruction) -> bool {
+    use Instruction::*;
+
+    match instruction {
+        Block(BlockType::Value(value_type))
+        | Loop(BlockType::Value(value_type))
+        | If(BlockType::Value(value_type)) => is_float(value_type),
+        F32Load(..) | F64Load(..) | F32Store(..) | F64Store(..) | F32Const(_) | F64Const(_)
+        | F32Eq | F32Ne | F32Lt | F32Gt | F32Le | F32Ge | F64Eq | F64Ne | F64Lt | F64Gt | F64Le
+        | F64Ge | F32Abs | F32Neg | F32Ceil | F32Floor | F32Trunc | F32Nearest | F32Sqrt
+        | F32Add | F32Sub | F32Mul | F32Div | F32Min | F32Max | F32Copysign | F64Abs | F64Neg
+        | F64Ceil | F64Floor | F64Trunc | F64Nearest | F64Sqrt | F64Add | F64Sub | F64Mul
+        | F64Div | F64Min | F64Max | F64Copysign | I32TruncSF32 | I32TruncUF32 | I32TruncSF64
+        | I32TruncUF64 | I64TruncSF32 | I64TruncUF32 | I64TruncSF64 | I64TruncUF64
+        | F32ConvertSI32 | F32ConvertUI32 | F32ConvertSI64 | F32ConvertUI64 | F32DemoteF64
+        | F64ConvertSI32 | F64ConvertUI32 | F64ConvertSI64 | F64ConvertUI64 | F64PromoteF32
+        | I32ReinterpretF32 | I64ReinterpretF64 | F32ReinterpretI32 | F64ReinterpretI64 => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use parity_wasm::{
+        builder::{self, ModuleBuilder},
+        elements::{Instruction, Instructions, Module, ValueType},
+    };
+
+    use super::validate;
+    use crate::{wasm_costs::WasmCosts, PreprocessingError};
+
+    const HOST_FUNCTION: &str = "write";
+
+    fn wasm_costs() -> WasmCosts {
+        WasmCosts {
+            max_module_size: 1_000,
+            max_functions: 2,
+            max_globals: 2,
+            max_table_size: 10,
+            max_memory_pages: 4,
+            ..Default::default()
+        }
+    }
+
+    fn host_functions() -> BTreeSet<String> {
+        [HOST_FUNCTION.to_string()].iter().cloned().collect()
+    }
+
+    fn validate_module(module: &Module) -> Result<(), PreprocessingError> {
+        validate(module, 0, &wasm_costs(), Some(&host_functions()))
+    }
+
+    fn with_function(
+        module_builder: ModuleBuilder,
+        instructions: Vec<Instruction>,
+    ) -> ModuleBuilder {
+        module_builder
+            .function()
+            .signature()
+            .build()
+            .body()
+            .with_instructions(Instructions::new(instructions))
+            .build()
+            .build()
+    }
+
+    fn with_import(module_builder: ModuleBuilder, field: &str) -> ModuleBuilder {
+        with_function(module_builder, vec![Instruction::End])
+            .import()
+            .module("env")
+            .field(field)
+            .external()
+            .func(0)
+            .build()
+    }
+
+    #[test]
+    fn should_accept_valid_module() {
+        let module = with_function(builder::module(), vec![Instruction::End]).build();
+        assert!(validate_module(&module).is_ok());
+
+        let module = with_import(builder::module(), HOST_FUNCTION).build();
+        assert!(validate_module(&module).is_ok());
+    }
+
+    #[test]
+    fn should_reject_module_too_large() {
+        let module = builder::module().build();
+        match validate(&module, 1_001, &wasm_costs(), None) {
+            Err(PreprocessingError::ModuleTooLarge { size: 1_001, .. }) => {}
+            other => panic!("should reject module size but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_reject_unknown_import() {
+        let module = with_import(builder::module(), "not_a_host_function").build();
+        match validate_module(&module) {
+            Err(PreprocessingError::UnknownImport { field, .. }) => {
+                assert_eq!(field, "not_a_host_function")
+            }
+            other => panic!("should reject import but got {:?}", other),
+        }
+        // Without a whitelist any function of the host module can be imported.
+        assert!(validate(&module, 0, &wasm_costs(), None).is_ok());
+    }
+
+    #[test]
+    fn should_reject_too_many_functions() {
+        let module_builder = (0..3).fold(builder::module(), |module_builder, _| {
+            with_function(module_builder, vec![Instruction::End])
+        });
+        match validate_module(&module_builder.build()) {
+            Err(PreprocessingError::TooManyFunctions { count: 3, .. }) => {}
+            other => panic!("should reject function count but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_reject_too_many_globals() {
+        let module_builder = (0..3).fold(builder::module(), |module_builder, _| {
+            module_builder
+                .global()
+                .with_type(ValueType::I32)
+                .init_expr(Instruction::I32Const(0))
+                .build()
+        });
+        match validate_module(&module_builder.build()) {
+            Err(PreprocessingError::TooManyGlobals { count: 3, .. }) => {}
+            other => panic!("should reject global count but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_reject_table_too_large() {
+        let module = builder::module().table().with_min(11).build().build();
+        match validate_module(&module) {
+            Err(PreprocessingError::TableTooLarge { size: 11, .. }) => {}
+            other => panic!("should reject table size but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_reject_memory_too_large() {
+        let module = builder::module().memory().with_min(4).build().build();
+        assert!(validate_module(&module).is_ok());
+
+        let module = builder::module().memory().with_min(5).build().build();
+        match validate_module(&module) {
+            Err(PreprocessingError::MemoryTooLarge {
+                initial_pages: 5, ..
+            }) => {}
+            other => panic!("should reject memory size but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_reject_floats() {
+        let module = with_function(
+            builder::module(),
+            vec![
+                Instruction::F32Const(0),
+                Instruction::Drop,
+                Instruction::End,
+            ],
+        )
+        .build();
+        match validate_module(&module) {
+            Err(PreprocessingError::FloatingPoint) => {}
+            other => panic!("should reject float instruction but got {:?}", other),
+        }
+
+        let module = builder::module()
+            .function()
+            .signature()
+            .with_param(ValueType::F64)
+            .build()
+            .body()
+            .build()
+            .build()
+            .build();
+        match validate_module(&module) {
+            Err(PreprocessingError::FloatingPoint) => {}
+            other => panic!("should reject float parameter but got {:?}", other),
+        }
+    }
+}
@@ -4,9 +4,16 @@ use pwasm_utils::rules::{InstructionType, Metering, Set};
 
 use types::bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH};
 
-const NUM_FIELDS: usize = 10;
+const NUM_FIELDS: usize = 16;
 pub const WASM_COSTS_SERIALIZED_LENGTH: usize = NUM_FIELDS * U32_SERIALIZED_LENGTH;
 
+const LEGACY_NUM_FIELDS: usize = 10;
+/// The serialized length of the wasm costs in the layout they had before any field was added after
+/// `opcodes_div`.
+pub const LEGACY_WASM_COSTS_SERIALIZED_LENGTH: usize = LEGACY_NUM_FIELDS * U32_SERIALIZED_LENGTH;
+/// The memory limit which applied before it was part of the wasm costs.
+const LEGACY_MAX_MEMORY_PAGES: u32 = 64;
+
 // Taken (partially) from parity-ethereum
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub struct WasmCosts {
@@ -33,6 +40,18 @@ pub struct WasmCosts {
     /// Cost of wasm opcode is calculated as TABLE_ENTRY_COST * `opcodes_mul` /
     /// `opcodes_div`
     pub opcodes_div: u32,
+    /// Global state storage cost, per byte written
+    pub storage_per_byte: u32,
+    /// Max size of a module in bytes, 0 means unlimited
+    pub max_module_size: u32,
+    /// Max number of functions defined by a module, 0 means unlimited
+    pub max_functions: u32,
+    /// Max number of globals defined by a module, 0 means unlimited
+    pub max_globals: u32,
+    /// Max number of table elements, 0 means unlimited
+    pub max_table_size: u32,
+    /// Max memory (in 64kb pages) each contract can use
+    pub max_memory_pages: u32,
 }
 
 impl WasmCosts {
@@ -49,6 +68,42 @@ impl WasmCosts {
             .with_grow_cost(self.grow_mem)
             .with_forbidden_floats()
     }
+
+    /// Deserializes wasm costs in the layout they had before any field was added after
+    /// `opcodes_div`.  The fields added since then take the values which applied back then.
+    pub fn legacy_from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (regular, rem): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (div, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (mul, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (mem, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (initial_mem, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (grow_mem, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (memcpy, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_stack_height, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (opcodes_mul, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (opcodes_div, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let wasm_costs = WasmCosts {
+            regular,
+            div,
+            mul,
+            mem,
+            initial_mem,
+            grow_mem,
+            memcpy,
+            max_stack_height,
+            opcodes_mul,
+            opcodes_div,
+            // Storage wasn't charged for by the byte
+            storage_per_byte: 0,
+            // Modules weren't limited in size
+            max_module_size: 0,
+            max_functions: 0,
+            max_globals: 0,
+            max_table_size: 0,
+            max_memory_pages: LEGACY_MAX_MEMORY_PAGES,
+        };
+        Ok((wasm_costs, rem))
+    }
 }
 
 impl ToBytes for WasmCosts {
@@ -64,6 +119,12 @@ impl ToBytes for WasmCosts {
         ret.append(&mut self.max_stack_height.to_bytes()?);
         ret.append(&mut self.opcodes_mul.to_bytes()?);
         ret.append(&mut self.opcodes_div.to_bytes()?);
+        ret.append(&mut self.storage_per_byte.to_bytes()?);
+        ret.append(&mut self.max_module_size.to_bytes()?);
+        ret.append(&mut self.max_functions.to_bytes()?);
+        ret.append(&mut self.max_globals.to_bytes()?);
+        ret.append(&mut self.max_table_size.to_bytes()?);
+        ret.append(&mut self.max_memory_pages.to_bytes()?);
         Ok(ret)
     }
 
@@ -84,6 +145,12 @@ impl FromBytes for WasmCosts {
         let (max_stack_height, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
         let (opcodes_mul, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
         let (opcodes_div, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (storage_per_byte, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_module_size, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_functions, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_globals, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_table_size, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_memory_pages, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
         let wasm_costs = WasmCosts {
             regular,
             div,
@@ -95,6 +162,12 @@ impl FromBytes for WasmCosts {
             max_stack_height,
             opcodes_mul,
             opcodes_div,
+            storage_per_byte,
+            max_module_size,
+            max_functions,
+            max_globals,
+            max_table_size,
+            max_memory_pages,
         };
         Ok((wasm_costs, rem))
     }
@@ -107,16 +180,47 @@ pub mod gens {
 
     prop_compose! {
         pub fn wasm_costs_arb()(
-            regular in num::u32::ANY,
-            div in num::u32::ANY,
-            mul in num::u32::ANY,
-            mem in num::u32::ANY,
-            initial_mem in num::u32::ANY,
-            grow_mem in num::u32::ANY,
-            memcpy in num::u32::ANY,
-            max_stack_height in num::u32::ANY,
-            opcodes_mul in num::u32::ANY,
-            opcodes_div in num::u32::ANY,
+            // Tuples of strategies only go up to 12 elements, so the fields are drawn in two.
+            (
+                regular,
+                div,
+                mul,
+                mem,
+                initial_mem,
+                grow_mem,
+                memcpy,
+                max_stack_height,
+                opcodes_mul,
+                opcodes_div,
+            ) in (
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+            ),
+            (
+                storage_per_byte,
+                max_module_size,
+                max_functions,
+                max_globals,
+                max_table_size,
+                max_memory_pages,
+            ) in (
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+                num::u32::ANY,
+            ),
         ) -> WasmCosts {
             WasmCosts {
                 regular,
@@ -129,6 +233,12 @@ pub mod gens {
                 max_stack_height,
                 opcodes_mul,
                 opcodes_div,
+                storage_per_byte,
+                max_module_size,
+                max_functions,
+                max_globals,
+                max_table_size,
+                max_memory_pages,
             }
         }
     }
@@ -155,6 +265,12 @@ mod tests {
             max_stack_height: 64 * 1024,
             opcodes_mul: 3,
             opcodes_div: 8,
+            storage_per_byte: 1,
+            max_module_size: 8 * 1024 * 1024,
+            max_functions: 10_000,
+            max_globals: 256,
+            max_table_size: 10_000,
+            max_memory_pages: 64,
         }
     }
 
@@ -170,6 +286,12 @@ mod tests {
             max_stack_height: 64 * 1024,
             opcodes_mul: 1,
             opcodes_div: 1,
+            storage_per_byte: 0,
+            max_module_size: 0,
+            max_functions: 0,
+            max_globals: 0,
+            max_table_size: 0,
+            max_memory_pages: 64,
         }
     }
 
@@ -0,0 +1,148 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use crate::{
+    bytesrepr::{self, Error, FromBytes, ToBytes},
+    CLType, CLValue,
+};
+
+/// The entry point run when a contract is called without naming one.
+pub const DEFAULT_ENTRY_POINT: &str = "call";
+
+/// The entry points of a stored contract, by name.
+pub type EntryPoints = BTreeMap<String, EntryPoint>;
+
+/// A named and typed parameter of an [`EntryPoint`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parameter {
+    name: String,
+    cl_type: CLType,
+}
+
+impl Parameter {
+    /// Constructs a new `Parameter`.
+    pub fn new<T: Into<String>>(name: T, cl_type: CLType) -> Self {
+        Parameter {
+            name: name.into(),
+            cl_type,
+        }
+    }
+
+    /// Returns the name of the parameter.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type of the parameter.
+    pub fn cl_type(&self) -> &CLType {
+        &self.cl_type
+    }
+}
+
+impl ToBytes for Parameter {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.name.to_bytes()?);
+        self.cl_type.append_bytes(&mut result);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.name.serialized_length() + self.cl_type.serialized_length()
+    }
+}
+
+impl FromBytes for Parameter {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (name, rem) = String::from_bytes(bytes)?;
+        let (cl_type, rem) = CLType::from_bytes(rem)?;
+        Ok((Parameter { name, cl_type }, rem))
+    }
+}
+
+/// A function a stored contract exports under the entry point's name, along with the parameters
+/// it takes as its positional arguments.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EntryPoint {
+    parameters: Vec<Parameter>,
+}
+
+impl EntryPoint {
+    /// Constructs a new `EntryPoint` taking `parameters`.
+    pub fn new(parameters: Vec<Parameter>) -> Self {
+        EntryPoint { parameters }
+    }
+
+    /// Returns the parameters of the entry point.
+    pub fn parameters(&self) -> &[Parameter] {
+        &self.parameters
+    }
+
+    /// Returns `true` if `args` match the parameters of the entry point in number and type.
+    pub fn accepts(&self, args: &[CLValue]) -> bool {
+        self.parameters.len() == args.len()
+            && self
+                .parameters
+                .iter()
+                .zip(args)
+                .all(|(parameter, arg)| parameter.cl_type() == arg.cl_type())
+    }
+}
+
+impl ToBytes for EntryPoint {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.parameters.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.parameters.serialized_length()
+    }
+}
+
+impl FromBytes for EntryPoint {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (parameters, rem) = Vec::<Parameter>::from_bytes(bytes)?;
+        Ok((EntryPoint { parameters }, rem))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::String, vec};
+
+    use super::{EntryPoint, Parameter};
+    use crate::{bytesrepr, CLType, CLValue, U512};
+
+    fn entry_point() -> EntryPoint {
+        EntryPoint::new(vec![
+            Parameter::new("amount", CLType::U512),
+            Parameter::new("memo", CLType::Option(Box::new(CLType::String))),
+        ])
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize() {
+        bytesrepr::test_serialization_roundtrip(&entry_point());
+        bytesrepr::test_serialization_roundtrip(&EntryPoint::default());
+    }
+
+    #[test]
+    fn should_accept_matching_args() {
+        let args = vec![
+            CLValue::from_t(U512::one()).unwrap(),
+            CLValue::from_t(Some(String::from("memo"))).unwrap(),
+        ];
+        assert!(entry_point().accepts(&args));
+    }
+
+    #[test]
+    fn should_reject_mismatched_args() {
+        let missing_arg = vec![CLValue::from_t(U512::one()).unwrap()];
+        assert!(!entry_point().accepts(&missing_arg));
+
+        let wrong_type = vec![
+            CLValue::from_t(1u64).unwrap(),
+            CLValue::from_t(Some(String::from("memo"))).unwrap(),
+        ];
+        assert!(!entry_point().accepts(&wrong_type));
+    }
+}
@@ -14,7 +14,8 @@ use proptest::{
 
 use crate::{
     account::{PublicKey, Weight},
-    AccessRights, CLType, CLValue, Key, Phase, ProtocolVersion, SemVer, URef, U128, U256, U512,
+    AccessRights, CLType, CLValue, EntryPoint, EntryPoints, Key, Parameter, Phase, ProtocolVersion,
+    SemVer, URef, U128, U256, U512,
 };
 
 pub fn u8_slice_32() -> impl Strategy<Value = [u8; 32]> {
@@ -153,6 +154,16 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
     ]
 }
 
+pub fn entry_points_arb(depth: usize) -> impl Strategy<Value = EntryPoints> {
+    let parameter_arb = ("\\PC*", cl_value_arb())
+        .prop_map(|(name, cl_value)| Parameter::new(name, cl_value.cl_type().clone()));
+    btree_map(
+        "\\PC*",
+        vec(parameter_arb, 0..5).prop_map(EntryPoint::new),
+        depth,
+    )
+}
+
 pub fn result_arb() -> impl Strategy<Value = Result<u32, u32>> {
     result::maybe_ok(any::<u32>(), any::<u32>())
 }
@@ -0,0 +1,106 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use crate::{
+    bytesrepr::{Error, FromBytes, ToBytes},
+    CLTyped, CLValue, CLValueError,
+};
+
+/// Arguments passed to a contract by name, which contracts read with
+/// `runtime::get_named_arg`.
+///
+/// Named arguments are passed alongside the positional arguments read with `runtime::get_arg`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RuntimeArgs(BTreeMap<String, CLValue>);
+
+impl RuntimeArgs {
+    /// Constructs an empty `RuntimeArgs`.
+    pub fn new() -> Self {
+        RuntimeArgs::default()
+    }
+
+    /// Inserts `value` under `name`, replacing any value already passed under that name.
+    pub fn insert<K: Into<String>, V: CLTyped + ToBytes>(
+        &mut self,
+        name: K,
+        value: V,
+    ) -> Result<(), CLValueError> {
+        let cl_value = CLValue::from_t(value)?;
+        self.insert_cl_value(name, cl_value);
+        Ok(())
+    }
+
+    /// Inserts `cl_value` under `name`, replacing any value already passed under that name.
+    pub fn insert_cl_value<K: Into<String>>(&mut self, name: K, cl_value: CLValue) {
+        self.0.insert(name.into(), cl_value);
+    }
+
+    /// Returns the value passed under `name`.
+    pub fn get(&self, name: &str) -> Option<&CLValue> {
+        self.0.get(name)
+    }
+
+    /// Returns the number of arguments.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no argument is passed.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the names and values of the arguments, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &CLValue)> {
+        self.0.iter()
+    }
+}
+
+impl From<BTreeMap<String, CLValue>> for RuntimeArgs {
+    fn from(args: BTreeMap<String, CLValue>) -> Self {
+        RuntimeArgs(args)
+    }
+}
+
+impl ToBytes for RuntimeArgs {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+impl FromBytes for RuntimeArgs {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (args, rem) = BTreeMap::<String, CLValue>::from_bytes(bytes)?;
+        Ok((RuntimeArgs(args), rem))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use super::RuntimeArgs;
+    use crate::{bytesrepr, CLValue, U512};
+
+    #[test]
+    fn should_serialize_and_deserialize() {
+        let mut args = RuntimeArgs::new();
+        args.insert("amount", U512::from(42)).unwrap();
+        args.insert("memo", String::from("memo")).unwrap();
+        bytesrepr::test_serialization_roundtrip(&args);
+        bytesrepr::test_serialization_roundtrip(&RuntimeArgs::new());
+    }
+
+    #[test]
+    fn should_get_args_by_name() {
+        let mut args = RuntimeArgs::new();
+        args.insert("amount", U512::from(42)).unwrap();
+        args.insert("amount", 1u64).unwrap();
+        assert_eq!(args.len(), 1);
+        assert_eq!(args.get("amount"), Some(&CLValue::from_t(1u64).unwrap()));
+        assert_eq!(args.get("memo"), None);
+    }
+}
@@ -38,6 +38,9 @@ pub enum Error {
     /// Purse not found while trying to get balance.
     #[fail(display = "Purse not found")]
     PurseNotFound = 7,
+    /// Tried to transfer funds which are still locked by the source purse's vesting schedule.
+    #[fail(display = "Vesting locked")]
+    VestingLocked = 8,
 }
 
 impl From<PurseError> for Error {
@@ -79,6 +82,7 @@ impl TryFrom<u8> for Error {
             d if d == Error::InvalidNonEmptyPurseCreation as u8 => {
                 Ok(Error::InvalidNonEmptyPurseCreation)
             }
+            d if d == Error::VestingLocked as u8 => Ok(Error::VestingLocked),
             _ => Err(TryFromU8ForError(())),
         }
     }